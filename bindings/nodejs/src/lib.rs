@@ -222,7 +222,11 @@ impl Operator {
             Either::A(buf) => buf.as_ref().to_owned(),
             Either::B(s) => s.into_bytes(),
         };
-        self.0.write(&path, c).await.map_err(format_napi_error)
+        self.0
+            .write(&path, c)
+            .await
+            .map(|_| ())
+            .map_err(format_napi_error)
     }
 
     /// Write multiple bytes into path.
@@ -284,6 +288,7 @@ impl Operator {
             .write_with(&path, c)
             .append(true)
             .await
+            .map(|_| ())
             .map_err(format_napi_error)
     }
 
@@ -761,7 +766,7 @@ impl Writer {
     /// ```
     #[napi]
     pub async unsafe fn close(&mut self) -> Result<()> {
-        self.0.close().await.map_err(format_napi_error)
+        self.0.close().await.map(|_| ()).map_err(format_napi_error)
     }
 }
 
@@ -291,7 +291,7 @@ impl AsyncOperator {
             if let Some(cache_control) = opwrite.cache_control() {
                 write = write.cache_control(cache_control);
             }
-            write.await.map_err(format_pyerr)
+            write.await.map(|_| ()).map_err(format_pyerr)
         })
     }
 
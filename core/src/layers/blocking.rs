@@ -150,6 +150,13 @@ impl BlockingLayer {
                 .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to get current handle"))?,
         })
     }
+
+    /// Create a new `BlockingLayer` with given runtime's handle.
+    ///
+    /// This is useful when the layer is created outside of any runtime context.
+    pub fn with_handle(handle: Handle) -> Self {
+        Self { handle }
+    }
 }
 
 impl<A: Accessor> Layer<A> for BlockingLayer {
@@ -268,6 +275,14 @@ impl<A: Accessor> LayeredAccessor for BlockingAccessor<A> {
         self.handle.block_on(self.inner.delete(path, args))
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.handle.block_on(self.inner.batch(args))
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.handle.block_on(self.inner.presign(path, args))
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         self.handle.block_on(async {
             let (rp, lister) = self.inner.list(path, args).await?;
@@ -322,6 +337,11 @@ impl<I: oio::List> oio::BlockingList for BlockingWrapper<I> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use http::HeaderMap;
+    use http::Method as HttpMethod;
     use once_cell::sync::Lazy;
 
     use super::*;
@@ -350,6 +370,97 @@ mod tests {
         assert!(layer.is_ok())
     }
 
+    #[test]
+    fn test_blocking_layer_with_handle() {
+        // create with handle in a blocking context should work.
+        let layer = BlockingLayer::with_handle(RUNTIME.handle().clone());
+
+        let mut builder = crate::services::Memory::default();
+        builder.root("/");
+        let op = Operator::new(builder)
+            .expect("operator must build")
+            .layer(layer)
+            .finish()
+            .blocking();
+        op.write("test", "hello").expect("write must succeed");
+        assert_eq!(op.read("test").expect("read must succeed"), b"hello");
+    }
+
+    #[derive(Debug)]
+    struct MockPresignService;
+
+    #[async_trait]
+    impl Accessor for MockPresignService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Lister = oio::Lister;
+        type BlockingLister = oio::BlockingLister;
+
+        fn info(&self) -> AccessorInfo {
+            let mut info = AccessorInfo::default();
+            info.set_native_capability(Capability {
+                presign: true,
+                presign_stat: true,
+                presign_read: true,
+                presign_write: true,
+                ..Default::default()
+            });
+
+            info
+        }
+
+        async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+            let method = match args.operation() {
+                PresignOperation::Stat(_) => HttpMethod::HEAD,
+                PresignOperation::Read(_) => HttpMethod::GET,
+                PresignOperation::Write(_) => HttpMethod::PUT,
+                _ => return Err(Error::new(ErrorKind::Unsupported, "not supported")),
+            };
+            let uri = format!(
+                "https://example.com/{path}?expires={}",
+                args.expire().as_secs()
+            );
+
+            Ok(RpPresign::new(PresignedRequest::new(
+                method,
+                uri.parse().expect("should parse"),
+                HeaderMap::new(),
+            )))
+        }
+    }
+
+    #[test]
+    fn test_blocking_layer_presign() {
+        let op = Operator::from_inner(Arc::new(MockPresignService))
+            .layer(BlockingLayer::with_handle(RUNTIME.handle().clone()));
+        let blocking_op = op.blocking();
+        let expire = Duration::from_secs(3600);
+
+        let cases = [
+            (
+                blocking_op.presign_stat("test", expire),
+                RUNTIME.block_on(op.presign_stat("test", expire)),
+            ),
+            (
+                blocking_op.presign_read("test", expire),
+                RUNTIME.block_on(op.presign_read("test", expire)),
+            ),
+            (
+                blocking_op.presign_write("test", expire),
+                RUNTIME.block_on(op.presign_write("test", expire)),
+            ),
+        ];
+        for (actual, expected) in cases {
+            let actual = actual.expect("blocking presign must succeed");
+            let expected = expected.expect("presign must succeed");
+            assert_eq!(actual.method(), expected.method());
+            assert_eq!(actual.uri(), expected.uri());
+            assert_eq!(actual.header(), expected.header());
+        }
+    }
+
     #[test]
     fn test_blocking_layer_in_async_context() {
         // create and drop in an async context
@@ -150,13 +150,28 @@ impl<A: Accessor> CompleteAccessor<A> {
         .with_operation(op)
     }
 
+    /// Dir metadata simulated via `list` doesn't have last modified, so
+    /// `if_modified_since` and `if_unmodified_since` can't be evaluated.
+    fn check_simulated_dir_stat(&self, args: &OpStat) -> Result<()> {
+        if args.if_modified_since().is_some() || args.if_unmodified_since().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat dir with if modified since or if unmodified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
     async fn complete_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let capability = self.meta.full_capability();
         if capability.create_dir {
             return self.inner().create_dir(path, args).await;
         }
         if capability.write_can_empty && capability.list {
-            let (_, mut w) = self.inner.write(path, OpWrite::default()).await?;
+            let (_, mut w) = self.inner.write(path, write_args_for(&args)).await?;
             oio::WriteExt::close(&mut w).await?;
             return Ok(RpCreateDir::default());
         }
@@ -170,7 +185,7 @@ impl<A: Accessor> CompleteAccessor<A> {
             return self.inner().blocking_create_dir(path, args);
         }
         if capability.write_can_empty && capability.list && capability.blocking {
-            let (_, mut w) = self.inner.blocking_write(path, OpWrite::default())?;
+            let (_, mut w) = self.inner.blocking_write(path, write_args_for(&args))?;
             oio::BlockingWrite::close(&mut w)?;
             return Ok(RpCreateDir::default());
         }
@@ -183,6 +198,33 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !capability.stat {
             return Err(self.new_unsupported_error(Operation::Stat));
         }
+        if args.raw_headers() && !capability.stat_with_raw_headers {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with raw headers",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_modified_since().is_some() && !capability.stat_with_if_modified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with if modified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_unmodified_since().is_some() && !capability.stat_with_if_unmodified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with if unmodified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         if path == "/" {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
@@ -190,7 +232,7 @@ impl<A: Accessor> CompleteAccessor<A> {
 
         // Forward to inner if create_dir is supported.
         if path.ends_with('/') && capability.create_dir {
-            let meta = self.inner.stat(path, args).await?.into_metadata();
+            let (meta, headers) = self.inner.stat(path, args).await?.into_parts();
 
             if meta.is_file() {
                 return Err(Error::new(
@@ -199,11 +241,22 @@ impl<A: Accessor> CompleteAccessor<A> {
                 ));
             }
 
-            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+            // Keep the metadata of dir like content type set by `create_dir_with`.
+            let bit = meta.metakey();
+            let mut rp = RpStat::new(
+                meta.with_mode(EntryMode::DIR)
+                    .with_metakey(bit | Metakey::Complete),
+            );
+            if let Some(headers) = headers {
+                rp = rp.with_headers(headers);
+            }
+            return Ok(rp);
         }
 
         // Otherwise, we can simulate stat dir via `list`.
         if path.ends_with('/') && capability.list_with_recursive {
+            self.check_simulated_dir_stat(&args)?;
+
             let (_, mut l) = self
                 .inner
                 .list(
@@ -236,6 +289,33 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !capability.stat {
             return Err(self.new_unsupported_error(Operation::Stat));
         }
+        if args.raw_headers() && !capability.stat_with_raw_headers {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with raw headers",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_modified_since().is_some() && !capability.stat_with_if_modified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with if modified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_unmodified_since().is_some() && !capability.stat_with_if_unmodified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation stat with if unmodified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         if path == "/" {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
@@ -243,7 +323,7 @@ impl<A: Accessor> CompleteAccessor<A> {
 
         // Forward to inner if create dir is supported.
         if path.ends_with('/') && capability.create_dir {
-            let meta = self.inner.blocking_stat(path, args)?.into_metadata();
+            let (meta, headers) = self.inner.blocking_stat(path, args)?.into_parts();
 
             if meta.is_file() {
                 return Err(Error::new(
@@ -252,11 +332,22 @@ impl<A: Accessor> CompleteAccessor<A> {
                 ));
             }
 
-            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+            // Keep the metadata of dir like content type set by `create_dir_with`.
+            let bit = meta.metakey();
+            let mut rp = RpStat::new(
+                meta.with_mode(EntryMode::DIR)
+                    .with_metakey(bit | Metakey::Complete),
+            );
+            if let Some(headers) = headers {
+                rp = rp.with_headers(headers);
+            }
+            return Ok(rp);
         }
 
         // Otherwise, we can simulate stat a dir path via `list`.
         if path.ends_with('/') && capability.list_with_recursive {
+            self.check_simulated_dir_stat(&args)?;
+
             let (_, mut l) = self.inner.blocking_list(
                 path.trim_end_matches('/'),
                 OpList::default().with_recursive(true).with_limit(1),
@@ -290,6 +381,36 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !capability.read {
             return Err(self.new_unsupported_error(Operation::Read));
         }
+        // Empty range can't be represented by HTTP range, callers should return
+        // empty content directly instead of sending requests.
+        if args.range().size() == Some(0) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "range to read from services must not be empty",
+            )
+            .with_context(
+                "offset",
+                args.range().offset().unwrap_or_default().to_string(),
+            ));
+        }
+        if args.if_modified_since().is_some() && !capability.read_with_if_modified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation read with if modified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_unmodified_since().is_some() && !capability.read_with_if_unmodified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation read with if unmodified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         let seekable = capability.read_can_seek;
         let streamable = capability.read_can_next;
@@ -326,6 +447,46 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !capability.read || !capability.blocking {
             return Err(self.new_unsupported_error(Operation::BlockingRead));
         }
+        // Empty range can't be represented by HTTP range, callers should return
+        // empty content directly instead of sending requests.
+        if args.range().size() == Some(0) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "range to read from services must not be empty",
+            )
+            .with_context(
+                "offset",
+                args.range().offset().unwrap_or_default().to_string(),
+            ));
+        }
+        // Transform is only applied by the async read path.
+        if args.transform().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation blocking read with transform",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_modified_since().is_some() && !capability.read_with_if_modified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation read with if modified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_unmodified_since().is_some() && !capability.read_with_if_unmodified_since {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation read with if unmodified since",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         let seekable = capability.read_can_seek;
         let streamable = capability.read_can_next;
@@ -361,6 +522,24 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !cap.list {
             return Err(self.new_unsupported_error(Operation::List));
         }
+        if args.versions() && !cap.list_with_versions {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation list with versions",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.delimiter().is_some() && !cap.list_with_delimiter {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation list with delimiter",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         let recursive = args.recursive();
 
@@ -401,6 +580,24 @@ impl<A: Accessor> CompleteAccessor<A> {
         if !cap.list {
             return Err(self.new_unsupported_error(Operation::BlockingList));
         }
+        if args.versions() && !cap.list_with_versions {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation list with versions",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.delimiter().is_some() && !cap.list_with_delimiter {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation list with delimiter",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         let recursive = args.recursive();
 
@@ -433,6 +630,18 @@ impl<A: Accessor> CompleteAccessor<A> {
     }
 }
 
+/// Build the write args for the directory marker of services without native create_dir.
+fn write_args_for(args: &OpCreateDir) -> OpWrite {
+    let mut op = OpWrite::default();
+    if let Some(v) = args.content_type() {
+        op = op.with_content_type(v);
+    }
+    if let Some(v) = args.user_metadata() {
+        op = op.with_user_metadata(v.clone());
+    }
+    op
+}
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
@@ -486,6 +695,66 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if not exists",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_match().is_some() && !capability.write_with_if_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_none_match().is_some() && !capability.write_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if none match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_not_exists() && (args.if_match().is_some() || args.if_none_match().is_some()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "if not exists can't be used together with if match or if none match",
+            ));
+        }
+        if args.resume().is_some() && (args.append() || !capability.write_with_resume) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with resume",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.verify_parts() && !capability.write_with_verify_parts {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with verify_parts",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.expires().is_some() && !args.allow_unsupported() && !capability.write_with_expires {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with expires",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         // Calculate buffer size.
         let buffer_size = args.buffer().map(|mut size| {
@@ -503,13 +772,41 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
 
             size
         });
+        // Buffer the whole content to upload it in one request if it's too small for
+        // multipart uploads.
+        let buffer_size = match (buffer_size, args.content_length()) {
+            (None, Some(size)) if capability.write_can_multi => capability
+                .write_multi_min_size
+                .filter(|min| size > 0 && size < *min as u64)
+                .map(|_| size as usize),
+            (v, _) => v,
+        };
 
         let (rp, w) = self.inner.write(path, args.clone()).await?;
-        let w = CompleteWriter::new(w);
+        let w = CompleteWriter::new(w, &args);
 
         let w = match buffer_size {
             None => oio::TwoWaysWriter::One(w),
-            Some(size) => oio::TwoWaysWriter::Two(oio::ExactBufWriter::new(w, size)),
+            Some(size) => {
+                let mut w = oio::ExactBufWriter::new(w, size);
+                if let Some(interval) = args.flush_interval() {
+                    // Flush early only if the service accepts the buffered data as is:
+                    // appendable services accept any size, while multipart uploads
+                    // require parts to be at least `write_multi_min_size` and can't
+                    // accept unaligned parts.
+                    if args.append() && capability.write_can_append {
+                        w = w.with_flush_interval(interval, 0);
+                    } else if capability.write_can_multi
+                        && capability.write_multi_align_size.is_none()
+                    {
+                        w = w.with_flush_interval(
+                            interval,
+                            capability.write_multi_min_size.unwrap_or_default(),
+                        );
+                    }
+                }
+                oio::TwoWaysWriter::Two(w)
+            }
         };
 
         Ok((rp, w))
@@ -520,6 +817,35 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.copy {
             return Err(self.new_unsupported_error(Operation::Copy));
         }
+        if args.if_none_match().is_some() && !capability.copy_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with if none match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if (args.metadata_directive().is_some() || args.content_type().is_some())
+            && !capability.copy_with_metadata_directive
+        {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with metadata directive",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.preserve_last_modified() && !capability.copy_with_preserve_last_modified {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with preserve last modified",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner().copy(from, to, args).await
     }
@@ -542,6 +868,15 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.delete {
             return Err(self.new_unsupported_error(Operation::Delete));
         }
+        if args.if_match().is_some() && !capability.delete_with_if_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation delete with if match",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner().delete(path, args).await
     }
@@ -573,6 +908,15 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         self.inner.presign(path, args).await
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let capability = self.meta.full_capability();
+        if !capability.restore {
+            return Err(self.new_unsupported_error(Operation::Restore));
+        }
+
+        self.inner.restore(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.complete_blocking_create_dir(path, args)
     }
@@ -586,6 +930,16 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.write || !capability.blocking {
             return Err(self.new_unsupported_error(Operation::BlockingWrite));
         }
+        // Transform is only applied by the async write path.
+        if args.transform().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation blocking write with transform",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         if args.append() && !capability.write_can_append {
             return Err(Error::new(
@@ -596,10 +950,52 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if not exists",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_match().is_some() && !capability.write_with_if_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_none_match().is_some() && !capability.write_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if none match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_not_exists() && (args.if_match().is_some() || args.if_none_match().is_some()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "if not exists can't be used together with if match or if none match",
+            ));
+        }
+        if args.expires().is_some() && !args.allow_unsupported() && !capability.write_with_expires {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with expires",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
-        self.inner
-            .blocking_write(path, args)
-            .map(|(rp, w)| (rp, CompleteWriter::new(w)))
+        let (rp, w) = self.inner.blocking_write(path, args.clone())?;
+        let w = CompleteWriter::new(w, &args);
+        Ok((rp, w))
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
@@ -607,6 +1003,35 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.copy || !capability.blocking {
             return Err(self.new_unsupported_error(Operation::BlockingCopy));
         }
+        if args.if_none_match().is_some() && !capability.copy_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with if none match",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if (args.metadata_directive().is_some() || args.content_type().is_some())
+            && !capability.copy_with_metadata_directive
+        {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with metadata directive",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.preserve_last_modified() && !capability.copy_with_preserve_last_modified {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation copy with preserve last modified",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner().blocking_copy(from, to, args)
     }
@@ -629,10 +1054,37 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.delete || !capability.blocking {
             return Err(self.new_unsupported_error(Operation::BlockingDelete));
         }
+        if args.if_match().is_some() && !capability.delete_with_if_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation delete with if match",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner().blocking_delete(path, args)
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let capability = self.meta.full_capability();
+        if !capability.batch || !capability.blocking {
+            return Err(self.new_unsupported_error(Operation::BlockingBatch));
+        }
+
+        self.inner().blocking_batch(args)
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let capability = self.meta.full_capability();
+        if !capability.presign || !capability.blocking {
+            return Err(self.new_unsupported_error(Operation::BlockingPresign));
+        }
+
+        self.inner().blocking_presign(path, args)
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         let capability = self.meta.full_capability();
         if !capability.list || !capability.blocking {
@@ -769,11 +1221,49 @@ where
 
 pub struct CompleteWriter<W> {
     inner: Option<W>,
+    size: Option<u64>,
+    written: u64,
+    /// Whether this writer writes the whole file, which is false for appending
+    /// or resumed writes. Only in this case, `written` is the size of the file.
+    whole: bool,
+    metadata: Option<Metadata>,
 }
 
 impl<W> CompleteWriter<W> {
-    pub fn new(inner: W) -> CompleteWriter<W> {
-        CompleteWriter { inner: Some(inner) }
+    pub fn new(inner: W, args: &OpWrite) -> CompleteWriter<W> {
+        CompleteWriter {
+            inner: Some(inner),
+            size: args.content_length(),
+            written: 0,
+            whole: !args.append() && args.resume().is_none(),
+            metadata: None,
+        }
+    }
+
+    fn check_write(&self, n: usize) -> Result<()> {
+        match self.size {
+            Some(expect) if self.written + n as u64 > expect => {
+                let actual = self.written + n as u64;
+                Err(Error::new(
+                    ErrorKind::ContentTruncated,
+                    &format!("writer got too much data, expect: {expect}, actual: {actual}"),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_close(&self) -> Result<()> {
+        match self.size {
+            Some(expect) if self.written < expect => {
+                let actual = self.written;
+                Err(Error::new(
+                    ErrorKind::ContentIncomplete,
+                    &format!("writer got too less data, expect: {expect}, actual: {actual}"),
+                ))
+            }
+            _ => Ok(()),
+        }
     }
 }
 
@@ -794,20 +1284,32 @@ where
     W: oio::Write,
 {
     fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
+        self.check_write(bs.remaining())?;
+
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
         })?;
         let n = ready!(w.poll_write(cx, bs))?;
+        self.written += n as u64;
 
         Poll::Ready(Ok(n))
     }
 
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.check_close()?;
+
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
         })?;
 
         ready!(w.poll_close(cx))?;
+        // Keep the metadata reported by inner writer before dropping it.
+        let mut meta = w.metadata();
+        // Not all services report the content length, fill it by ourselves.
+        if self.whole && !meta.contains_metakey(Metakey::ContentLength) {
+            meta.set_content_length(self.written);
+        }
+        self.metadata = Some(meta);
         self.inner = None;
 
         Poll::Ready(Ok(()))
@@ -823,6 +1325,18 @@ where
 
         Poll::Ready(Ok(()))
     }
+
+    fn metadata(&self) -> Metadata {
+        match (&self.metadata, &self.inner) {
+            (Some(meta), _) => meta.clone(),
+            (None, Some(w)) => w.metadata(),
+            (None, None) => Metadata::new(EntryMode::FILE),
+        }
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.as_ref().and_then(|w| w.upload_state())
+    }
 }
 
 impl<W> oio::BlockingWrite for CompleteWriter<W>
@@ -830,15 +1344,20 @@ where
     W: oio::BlockingWrite,
 {
     fn write(&mut self, bs: &dyn oio::WriteBuf) -> Result<usize> {
+        self.check_write(bs.remaining())?;
+
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
         })?;
         let n = w.write(bs)?;
+        self.written += n as u64;
 
         Ok(n)
     }
 
     fn close(&mut self) -> Result<()> {
+        self.check_close()?;
+
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
         })?;
@@ -852,6 +1371,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
+    use std::time::SystemTime;
 
     use async_trait::async_trait;
     use http::HeaderMap;
@@ -944,6 +1464,28 @@ mod tests {
         assert!(res.is_ok())
     }
 
+    #[tokio::test]
+    async fn test_read_with_empty_range() {
+        let capability = Capability {
+            read: true,
+            stat: true,
+            ..Default::default()
+        };
+
+        // Empty range must not be sent to services.
+        let acc = CompleteLayer.layer(MockService { capability });
+        let args = OpRead::new().with_range(BytesRange::new(Some(10), Some(0)));
+        let res = acc.read("path", args).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+        // Reader will return empty content without reading.
+        let op = new_test_operator(capability);
+        let mut r = op.reader_with("path").range(10..10).await.unwrap();
+        let mut bs = Vec::new();
+        oio::ReadExt::read_to_end(&mut r, &mut bs).await.unwrap();
+        assert!(bs.is_empty());
+    }
+
     #[tokio::test]
     async fn test_stat() {
         let op = new_test_operator(Capability::default());
@@ -959,6 +1501,30 @@ mod tests {
         assert!(res.is_ok())
     }
 
+    #[tokio::test]
+    async fn test_stat_dir_with_time_condition() {
+        let op = new_test_operator(Capability {
+            stat: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
+            list: true,
+            list_with_recursive: true,
+            ..Default::default()
+        });
+
+        // Dir simulated via list can't evaluate the time conditions.
+        let res = op
+            .stat_with("dir/")
+            .if_modified_since(SystemTime::now())
+            .await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        let res = op
+            .stat_with("dir/")
+            .if_unmodified_since(SystemTime::now())
+            .await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+    }
+
     #[tokio::test]
     async fn test_writer() {
         let op = new_test_operator(Capability::default());
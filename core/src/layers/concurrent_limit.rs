@@ -296,6 +296,14 @@ impl<R: oio::Write> oio::Write for ConcurrentLimitWrapper<R> {
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_abort(cx)
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::BlockingWrite> oio::BlockingWrite for ConcurrentLimitWrapper<R> {
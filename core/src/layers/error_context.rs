@@ -239,6 +239,14 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
         })
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Restore)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreateDir)
@@ -321,6 +329,39 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
         })
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.inner
+            .blocking_batch(args)
+            .map(|v| {
+                let res = v
+                    .into_results()
+                    .into_iter()
+                    .map(|(path, res)| {
+                        let res = res.map_err(|err| {
+                            err.with_operation(Operation::BlockingDelete)
+                                .with_context("service", self.meta.scheme())
+                                .with_context("path", &path)
+                        });
+                        (path, res)
+                    })
+                    .collect();
+
+                RpBatch::new(res)
+            })
+            .map_err(|err| {
+                err.with_operation(Operation::BlockingBatch)
+                    .with_context("service", self.meta.scheme())
+            })
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(path, args).map_err(|err| {
+            err.with_operation(Operation::BlockingPresign)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         self.inner
             .blocking_list(path, args)
@@ -431,6 +472,14 @@ impl<T: oio::Write> oio::Write for ErrorContextWrapper<T> {
                 .with_context("path", &self.path)
         })
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<T: oio::BlockingWrite> oio::BlockingWrite for ErrorContextWrapper<T> {
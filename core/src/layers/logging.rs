@@ -526,6 +526,44 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
             .await
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::Restore,
+            path
+        );
+
+        self.inner
+            .restore(path, args)
+            .inspect(|v| match v {
+                Ok(_) => {
+                    debug!(
+                        target: LOGGING_TARGET,
+                        "service={} operation={} path={} -> finished",
+                        self.ctx.scheme,
+                        Operation::Restore,
+                        path
+                    );
+                }
+                Err(err) => {
+                    if let Some(lvl) = self.ctx.error_level(err) {
+                        log!(
+                            target: LOGGING_TARGET,
+                            lvl,
+                            "service={} operation={} path={} -> {}",
+                            self.ctx.scheme,
+                            Operation::Restore,
+                            path,
+                            self.ctx.error_print(err)
+                        );
+                    }
+                }
+            })
+            .await
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         debug!(
             target: LOGGING_TARGET,
@@ -915,6 +953,82 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
             })
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let (op, count) = (args.operation()[0].1.operation(), args.operation().len());
+
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={}-{op} count={count} -> started",
+            self.ctx.scheme,
+            Operation::BlockingBatch,
+        );
+
+        self.inner
+            .blocking_batch(args)
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={}-{op} count={count} -> finished: {}, succeed: {}, failed: {}",
+                    self.ctx.scheme,
+                    Operation::BlockingBatch,
+                    v.results().len(),
+                    v.results().iter().filter(|(_, v)|v.is_ok()).count(),
+                    v.results().iter().filter(|(_, v)|v.is_err()).count(),
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={}-{op} count={count} -> {}",
+                        self.ctx.scheme,
+                        Operation::BlockingBatch,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::BlockingPresign,
+            path
+        );
+
+        self.inner
+            .blocking_presign(path, args)
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::BlockingPresign,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::BlockingPresign,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         debug!(
             target: LOGGING_TARGET,
@@ -1362,6 +1476,14 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
             }
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<W: oio::BlockingWrite> oio::BlockingWrite for LoggingWriter<W> {
@@ -165,6 +165,9 @@ struct MetricsHandler {
     requests_total_batch: Counter,
     requests_duration_seconds_batch: Histogram,
 
+    requests_total_restore: Counter,
+    requests_duration_seconds_restore: Histogram,
+
     requests_total_blocking_create: Counter,
     requests_duration_seconds_blocking_create: Histogram,
 
@@ -183,6 +186,12 @@ struct MetricsHandler {
     requests_total_blocking_delete: Counter,
     requests_duration_seconds_blocking_delete: Histogram,
 
+    requests_total_blocking_batch: Counter,
+    requests_duration_seconds_blocking_batch: Histogram,
+
+    requests_total_blocking_presign: Counter,
+    requests_duration_seconds_blocking_presign: Histogram,
+
     requests_total_blocking_list: Counter,
     requests_duration_seconds_blocking_list: Histogram,
 }
@@ -301,6 +310,17 @@ impl MetricsHandler {
                 LABEL_OPERATION => Operation::Batch.into_static(),
             ),
 
+            requests_total_restore: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Restore.into_static(),
+            ),
+            requests_duration_seconds_restore: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Restore.into_static(),
+            ),
+
             requests_total_blocking_create: register_counter!(
                 METRIC_REQUESTS_TOTAL,
                 LABEL_SERVICE => service,
@@ -366,6 +386,28 @@ impl MetricsHandler {
                 LABEL_OPERATION => Operation::BlockingDelete.into_static(),
             ),
 
+            requests_total_blocking_batch: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingBatch.into_static(),
+            ),
+            requests_duration_seconds_blocking_batch: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingBatch.into_static(),
+            ),
+
+            requests_total_blocking_presign: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingPresign.into_static(),
+            ),
+            requests_duration_seconds_blocking_presign: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingPresign.into_static(),
+            ),
+
             requests_total_blocking_list: register_counter!(
                 METRIC_REQUESTS_TOTAL,
                 LABEL_SERVICE => service,
@@ -599,6 +641,25 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
         })
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.handle.requests_total_restore.increment(1);
+
+        let start = Instant::now();
+
+        self.inner
+            .restore(path, args)
+            .inspect_ok(|_| {
+                let dur = start.elapsed().as_secs_f64();
+
+                self.handle.requests_duration_seconds_restore.record(dur);
+            })
+            .inspect_err(|e| {
+                self.handle
+                    .increment_errors_total(Operation::Restore, e.kind());
+            })
+            .await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.handle.requests_total_blocking_create.increment(1);
 
@@ -710,6 +771,42 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
         })
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.handle.requests_total_blocking_batch.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_batch(args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_batch
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingBatch, e.kind());
+            e
+        })
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.handle.requests_total_blocking_presign.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_presign(path, args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_presign
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingPresign, e.kind());
+            e
+        })
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         self.handle.requests_total_blocking_list.increment(1);
 
@@ -873,6 +970,14 @@ impl<R: oio::Write> oio::Write for MetricWrapper<R> {
             err
         })
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::BlockingWrite> oio::BlockingWrite for MetricWrapper<R> {
@@ -354,6 +354,14 @@ impl<R: oio::Write> oio::Write for MinitraceWrapper<R> {
         let _span = LocalSpan::enter_with_local_parent(WriteOperation::Close.into_static());
         self.inner.poll_close(cx)
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::BlockingWrite> oio::BlockingWrite for MinitraceWrapper<R> {
@@ -395,6 +395,24 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
             .await
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        { || self.inner.restore(path, args.clone()) }
+            .retry(&self.builder)
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                self.notify.intercept(
+                    err,
+                    dur,
+                    &[
+                        ("operation", Operation::Restore.into_static()),
+                        ("path", path),
+                    ],
+                )
+            })
+            .map(|v| v.map_err(|e| e.set_persistent()))
+            .await
+    }
+
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         { || self.inner.copy(from, to, args.clone()) }
             .retry(&self.builder)
@@ -585,6 +603,52 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
             .map_err(|e| e.set_persistent())
     }
 
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        {
+            || {
+                let rp = self.inner.blocking_batch(args.clone())?;
+                let mut nrp = Vec::with_capacity(rp.results().len());
+                for (path, result) in rp.into_results() {
+                    let result = result?;
+                    nrp.push((path, Ok(result)))
+                }
+                Ok(RpBatch::new(nrp))
+            }
+        }
+        .retry(&self.builder)
+        .when(|e: &Error| e.is_temporary())
+        .notify(|err, dur| {
+            self.notify.intercept(
+                err,
+                dur,
+                &[
+                    ("operation", Operation::BlockingBatch.into_static()),
+                    ("count", &args.operation().len().to_string()),
+                ],
+            )
+        })
+        .call()
+        .map_err(|e| e.set_persistent())
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        { || self.inner.blocking_presign(path, args.clone()) }
+            .retry(&self.builder)
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                self.notify.intercept(
+                    err,
+                    dur,
+                    &[
+                        ("operation", Operation::BlockingPresign.into_static()),
+                        ("path", path),
+                    ],
+                )
+            })
+            .call()
+            .map_err(|e| e.set_persistent())
+    }
+
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         { || self.inner.blocking_copy(from, to, args.clone()) }
             .retry(&self.builder)
@@ -1009,6 +1073,14 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
             }
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::BlockingWrite, I: RetryInterceptor> oio::BlockingWrite for RetryWrapper<R, I> {
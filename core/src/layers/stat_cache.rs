@@ -0,0 +1,427 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+
+use crate::raw::oio::WriteBuf;
+use crate::raw::*;
+use crate::*;
+
+/// Cache the result of `stat` in memory with TTL.
+///
+/// This layer is used by [`Operator::with_stat_cache`] and not exposed to users.
+///
+/// - Only `stat` without any condition or version will be cached.
+/// - `write`, `delete`, `copy`, `rename`, `restore` and `batch` will invalidate the cached paths.
+/// - `read` and `list` will bypass the cache.
+#[derive(Clone)]
+pub(crate) struct StatCacheLayer {
+    cache: Arc<StatCache>,
+}
+
+impl StatCacheLayer {
+    /// Create a new StatCacheLayer with given capacity and ttl.
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            cache: Arc::new(StatCache::new(capacity, ttl)),
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for StatCacheLayer {
+    type LayeredAccessor = StatCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        StatCacheAccessor {
+            inner,
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+/// A LRU cache of metadata with TTL.
+struct StatCache {
+    capacity: usize,
+    ttl: Duration,
+    inner: Mutex<StatCacheInner>,
+}
+
+#[derive(Default)]
+struct StatCacheInner {
+    /// The map from path to (metadata, expire_at, last_used).
+    entries: HashMap<String, (Metadata, Instant, u64)>,
+    tick: u64,
+}
+
+impl StatCache {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            inner: Mutex::new(StatCacheInner::default()),
+        }
+    }
+
+    fn get(&self, path: &str) -> Option<Metadata> {
+        let mut inner = self.inner.lock();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        match inner.entries.get_mut(path) {
+            Some((meta, expire_at, last_used)) if *expire_at > Instant::now() => {
+                *last_used = tick;
+                Some(meta.clone())
+            }
+            Some(_) => {
+                inner.entries.remove(path);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, path: &str, meta: Metadata) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        if !inner.entries.contains_key(path) && inner.entries.len() >= self.capacity {
+            // Evict the least recently used entry.
+            let lru = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, _, last_used))| *last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(k) = lru {
+                inner.entries.remove(&k);
+            }
+        }
+
+        let expire_at = Instant::now() + self.ttl;
+        inner
+            .entries
+            .insert(path.to_string(), (meta, expire_at, tick));
+    }
+
+    fn invalidate(&self, path: &str) {
+        self.inner.lock().entries.remove(path);
+    }
+}
+
+/// Only plain stat without any condition, override, version or raw headers can be cached.
+fn is_cacheable(args: &OpStat) -> bool {
+    !args.raw_headers()
+        && args.if_match().is_none()
+        && args.if_none_match().is_none()
+        && args.if_modified_since().is_none()
+        && args.if_unmodified_since().is_none()
+        && args.override_content_type().is_none()
+        && args.override_cache_control().is_none()
+        && args.override_content_disposition().is_none()
+        && args.version().is_none()
+}
+
+pub struct StatCacheAccessor<A: Accessor> {
+    inner: A,
+    cache: Arc<StatCache>,
+}
+
+impl<A: Accessor> Debug for StatCacheAccessor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatCacheAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<A: Accessor> LayeredAccessor for StatCacheAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = StatCacheWrapper<A::Writer>;
+    type BlockingWriter = StatCacheWrapper<A::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.cache.invalidate(path);
+        self.inner.write(path, args).await.map(|(rp, w)| {
+            let w = StatCacheWrapper::new(w, self.cache.clone(), path);
+            (rp, w)
+        })
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let res = self.inner.copy(from, to, args).await;
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let res = self.inner.rename(from, to, args).await;
+        self.cache.invalidate(from);
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.stat(path, args).await;
+        }
+
+        if let Some(meta) = self.cache.get(path) {
+            return Ok(RpStat::new(meta));
+        }
+
+        let rp = self.inner.stat(path, args).await?;
+        self.cache.insert(path, rp.clone().into_metadata());
+        Ok(rp)
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let res = self.inner.delete(path, args).await;
+        self.cache.invalidate(path);
+        res
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let paths: Vec<_> = args.operation().iter().map(|(p, _)| p.clone()).collect();
+        let res = self.inner.batch(args).await;
+        for path in paths {
+            self.cache.invalidate(&path);
+        }
+        res
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let res = self.inner.restore(path, args).await;
+        self.cache.invalidate(path);
+        res
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.cache.invalidate(path);
+        self.inner.blocking_write(path, args).map(|(rp, w)| {
+            let w = StatCacheWrapper::new(w, self.cache.clone(), path);
+            (rp, w)
+        })
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let res = self.inner.blocking_copy(from, to, args);
+        self.cache.invalidate(to);
+        res
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let res = self.inner.blocking_rename(from, to, args);
+        self.cache.invalidate(from);
+        self.cache.invalidate(to);
+        res
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.blocking_stat(path, args);
+        }
+
+        if let Some(meta) = self.cache.get(path) {
+            return Ok(RpStat::new(meta));
+        }
+
+        let rp = self.inner.blocking_stat(path, args)?;
+        self.cache.insert(path, rp.clone().into_metadata());
+        Ok(rp)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let res = self.inner.blocking_delete(path, args);
+        self.cache.invalidate(path);
+        res
+    }
+
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let paths: Vec<_> = args.operation().iter().map(|(p, _)| p.clone()).collect();
+        let res = self.inner.blocking_batch(args);
+        for path in paths {
+            self.cache.invalidate(&path);
+        }
+        res
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// StatCacheWrapper will invalidate the cached metadata after the writer closed.
+pub struct StatCacheWrapper<W> {
+    inner: W,
+    cache: Arc<StatCache>,
+    path: String,
+}
+
+impl<W> StatCacheWrapper<W> {
+    fn new(inner: W, cache: Arc<StatCache>, path: &str) -> Self {
+        Self {
+            inner,
+            cache,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl<W: oio::Write> oio::Write for StatCacheWrapper<W> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+        self.inner.poll_write(cx, bs)
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let res = ready!(self.inner.poll_close(cx));
+        self.cache.invalidate(&self.path);
+        Poll::Ready(res)
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_abort(cx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for StatCacheWrapper<W> {
+    fn write(&mut self, bs: &dyn WriteBuf) -> Result<usize> {
+        self.inner.write(bs)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let res = self.inner.close();
+        self.cache.invalidate(&self.path);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stat_cache() {
+        let op = Operator::new(crate::services::Memory::default())
+            .expect("operator must build")
+            .finish();
+        let cached = op.with_stat_cache(16, Duration::from_secs(60));
+
+        cached
+            .write("test", "hello")
+            .await
+            .expect("write must succeed");
+        let meta = cached.stat("test").await.expect("stat must succeed");
+        assert_eq!(meta.content_length(), 5);
+
+        // Changes made by others are invisible before ttl.
+        op.write("test", "hello, world")
+            .await
+            .expect("write must succeed");
+        let meta = cached.stat("test").await.expect("stat must succeed");
+        assert_eq!(meta.content_length(), 5);
+
+        // Changes made via cached operator will invalidate the cache.
+        cached
+            .write("test", "hello, world!")
+            .await
+            .expect("write must succeed");
+        let meta = cached.stat("test").await.expect("stat must succeed");
+        assert_eq!(meta.content_length(), 13);
+
+        cached.delete("test").await.expect("delete must succeed");
+        assert!(!cached
+            .is_exist("test")
+            .await
+            .expect("is_exist must succeed"));
+    }
+
+    #[test]
+    fn test_stat_cache_evict_lru() {
+        let cache = StatCache::new(2, Duration::from_secs(60));
+        cache.insert("a", Metadata::new(EntryMode::FILE));
+        cache.insert("b", Metadata::new(EntryMode::FILE));
+
+        // Touch `a` so that `b` will be evicted.
+        assert!(cache.get("a").is_some());
+        cache.insert("c", Metadata::new(EntryMode::FILE));
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn test_stat_cache_expire() {
+        let cache = StatCache::new(2, Duration::ZERO);
+        cache.insert("a", Metadata::new(EntryMode::FILE));
+        assert!(cache.get("a").is_none());
+    }
+}
@@ -158,7 +158,7 @@ impl<A: Accessor> LayeredAccessor for TimeoutAccessor<A> {
         tokio::time::timeout(self.timeout, self.inner.read(path, args))
             .await
             .map_err(|_| {
-                Error::new(ErrorKind::Unexpected, "operation timeout")
+                Error::new(ErrorKind::Timeout, "operation timeout")
                     .with_operation(Operation::Read)
                     .with_context("timeout", self.timeout.as_secs_f64().to_string())
                     .set_temporary()
@@ -170,7 +170,7 @@ impl<A: Accessor> LayeredAccessor for TimeoutAccessor<A> {
         tokio::time::timeout(self.timeout, self.inner.write(path, args))
             .await
             .map_err(|_| {
-                Error::new(ErrorKind::Unexpected, "operation timeout")
+                Error::new(ErrorKind::Timeout, "operation timeout")
                     .with_operation(Operation::Write)
                     .with_context("timeout", self.timeout.as_secs_f64().to_string())
                     .set_temporary()
@@ -182,7 +182,7 @@ impl<A: Accessor> LayeredAccessor for TimeoutAccessor<A> {
         tokio::time::timeout(self.timeout, self.inner.list(path, args))
             .await
             .map_err(|_| {
-                Error::new(ErrorKind::Unexpected, "operation timeout")
+                Error::new(ErrorKind::Timeout, "operation timeout")
                     .with_operation(Operation::List)
                     .with_context("timeout", self.timeout.as_secs_f64().to_string())
                     .set_temporary()
@@ -239,13 +239,10 @@ impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(ReadOperation::Read)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(ReadOperation::Read)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
@@ -269,13 +266,10 @@ impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(ReadOperation::Seek)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(ReadOperation::Seek)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
@@ -300,7 +294,7 @@ impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
                     self.start = None;
 
                     return Poll::Ready(Some(Err(Error::new(
-                        ErrorKind::Unexpected,
+                        ErrorKind::Timeout,
                         "operation timeout",
                     )
                     .with_operation(ReadOperation::Next)
@@ -331,13 +325,10 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(WriteOperation::Write)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(WriteOperation::Write)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
@@ -361,13 +352,10 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(WriteOperation::Abort)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(WriteOperation::Abort)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
@@ -391,13 +379,10 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(WriteOperation::Close)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(WriteOperation::Close)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
@@ -413,6 +398,14 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
             }
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::List> oio::List for TimeoutWrapper<R> {
@@ -423,13 +416,10 @@ impl<R: oio::List> oio::List for TimeoutWrapper<R> {
                     // Clean up the start time before return ready.
                     self.start = None;
 
-                    return Poll::Ready(Err(Error::new(
-                        ErrorKind::Unexpected,
-                        "operation timeout",
-                    )
-                    .with_operation(ListOperation::Next)
-                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                    .set_temporary()));
+                    return Poll::Ready(Err(Error::new(ErrorKind::Timeout, "operation timeout")
+                        .with_operation(ListOperation::Next)
+                        .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                        .set_temporary()));
                 }
             }
             None => {
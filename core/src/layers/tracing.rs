@@ -209,6 +209,11 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.batch(args).await
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args)
@@ -248,6 +253,16 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.blocking_delete(path, args)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.inner.blocking_batch(args)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(path, args)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         self.inner
@@ -343,6 +358,14 @@ impl<R: oio::Write> oio::Write for TracingWrapper<R> {
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_close(cx)
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 impl<R: oio::BlockingWrite> oio::BlockingWrite for TracingWrapper<R> {
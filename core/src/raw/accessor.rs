@@ -235,6 +235,24 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `restore` operation on the specified path.
+    ///
+    /// Require [`Capability::restore`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - `restore` SHOULD return as soon as the restore request has been accepted
+    ///   by service instead of waiting for the restore to finish.
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `batch` operations.
     ///
     /// Require [`Capability::batch`]
@@ -345,6 +363,34 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `blocking_batch` operations.
+    ///
+    /// This operation is the blocking version of [`Accessor::batch`]
+    ///
+    /// Require [`Capability::batch`] and [`Capability::blocking`]
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `blocking_presign` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::presign`]
+    ///
+    /// Require [`Capability::presign`] and [`Capability::blocking`]
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `blocking_list` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::list`]
@@ -439,6 +485,10 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().presign(path, args).await
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.as_ref().restore(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.as_ref().blocking_create_dir(path, args)
     }
@@ -463,6 +513,12 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.as_ref().blocking_delete(path, args)
     }
+    fn blocking_batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.as_ref().blocking_batch(args)
+    }
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.as_ref().blocking_presign(path, args)
+    }
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         self.as_ref().blocking_list(path, args)
     }
@@ -370,6 +370,13 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
         self.buf = None;
         Poll::Ready(Ok(()))
     }
+
+    fn metadata(&self) -> Metadata {
+        match &self.value {
+            Some(value) => value.metadata.clone(),
+            None => Metadata::new(EntryMode::FILE),
+        }
+    }
 }
 
 impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
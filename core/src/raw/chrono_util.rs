@@ -45,6 +45,14 @@ pub fn parse_datetime_from_rfc3339(s: &str) -> Result<DateTime<Utc>> {
         })
 }
 
+/// Format datetime into http date, this format is required by:
+/// <https://httpwg.org/specs/rfc9110.html#field.if-modified-since>
+///
+/// For example: `Fri, 28 Nov 2014 12:00:09 GMT`
+pub fn format_datetime_into_http_date(s: DateTime<Utc>) -> String {
+    s.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
 /// parse datetime from given timestamp_millis
 pub fn parse_datetime_from_from_timestamp_millis(s: i64) -> Result<DateTime<Utc>> {
     let st = UNIX_EPOCH
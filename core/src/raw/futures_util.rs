@@ -122,6 +122,15 @@ where
         self.remaining() > 0
     }
 
+    /// Drop all futures in the queue.
+    pub fn clear(&mut self) {
+        match &mut self.tasks {
+            Tasks::Once(fut) => *fut = None,
+            Tasks::Small(v) => v.clear(),
+            Tasks::Large(v) => *v = FuturesOrdered::new(),
+        }
+    }
+
     /// Push new future into the queue.
     pub fn push(&mut self, f: F) {
         debug_assert!(
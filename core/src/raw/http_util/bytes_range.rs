@@ -95,6 +95,8 @@ impl BytesRange {
     ///
     /// - `bytes=-1023` means get the suffix of the file.
     /// - `bytes=0-1023` means get the first 1024 bytes, we must set the end to 1023.
+    /// - Empty range whose size is `0` can't be represented, callers must not
+    ///   render it. `CompleteLayer` will reject reading empty ranges.
     pub fn to_header(&self) -> String {
         format!("bytes={self}")
     }
@@ -107,22 +109,81 @@ impl BytesRange {
                 None => Bound::Unbounded,
             },
             match self.1 {
-                Some(size) => Bound::Excluded(self.0.unwrap_or_default() + size),
+                Some(size) => Bound::Excluded(self.0.unwrap_or_default().saturating_add(size)),
                 None => Bound::Unbounded,
             },
         )
     }
 
     /// Complete range with total size.
+    ///
+    /// The completion is saturating, please use [`BytesRange::validate`] to reject
+    /// ranges that are not satisfiable.
     pub fn complete(&self, total_size: u64) -> Self {
         match (self.offset(), self.size()) {
             (Some(_), Some(_)) => *self,
-            (Some(offset), None) => Self(Some(offset), Some(total_size - offset)),
-            (None, Some(size)) => Self(Some(total_size - size), Some(size)),
+            (Some(offset), None) => Self(Some(offset), Some(total_size.saturating_sub(offset))),
+            (None, Some(size)) => Self(Some(total_size.saturating_sub(size)), Some(size)),
             (None, None) => Self(Some(0), Some(total_size)),
         }
     }
 
+    /// Build range from rust range bounds, inverted ranges will be rejected.
+    ///
+    /// Unlike `From<RangeBounds>` which saturates inverted ranges into empty ones,
+    /// this function returns [`ErrorKind::RangeNotSatisfied`] if the end of range
+    /// is before the start, e.g. `20..10`.
+    pub fn try_from_range(range: impl RangeBounds<u64>) -> Result<Self> {
+        let start = match range.start_bound().cloned() {
+            Bound::Included(n) => n as u128,
+            Bound::Excluded(n) => n as u128 + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound().cloned() {
+            Bound::Included(n) => Some(n as u128 + 1),
+            Bound::Excluded(n) => Some(n as u128),
+            Bound::Unbounded => None,
+        };
+        if matches!(end, Some(end) if end < start) {
+            return Err(Error::new(
+                ErrorKind::RangeNotSatisfied,
+                "range end is before the start",
+            )
+            .with_operation("BytesRange::try_from_range")
+            .with_context("start", start.to_string())
+            .with_context("end", end.unwrap_or_default().to_string()));
+        }
+
+        Ok(Self::from(range))
+    }
+
+    /// Validate range against total size and complete it.
+    ///
+    /// - Returns [`ErrorKind::RangeNotSatisfied`] if the offset is beyond the end.
+    /// - Ranges that end beyond the end will be clamped to the total size, for
+    ///   example, `1024..` and `-2048` on a file of 1024 bytes will both be
+    ///   completed as an empty range and the whole file respectively.
+    pub fn validate(&self, total_size: u64) -> Result<Self> {
+        let range = match (self.offset(), self.size()) {
+            (Some(offset), _) if offset > total_size => {
+                return Err(Error::new(
+                    ErrorKind::RangeNotSatisfied,
+                    "range offset is beyond the end of content",
+                )
+                .with_operation("BytesRange::validate")
+                .with_context("range", self.to_string())
+                .with_context("size", total_size.to_string()))
+            }
+            (Some(offset), Some(size)) => Self(Some(offset), Some(size.min(total_size - offset))),
+            (None, Some(size)) => {
+                let size = size.min(total_size);
+                Self(Some(total_size - size), Some(size))
+            }
+            _ => self.complete(total_size),
+        };
+        Ok(range)
+    }
+
     /// apply_on_bytes will apply range on bytes.
     pub fn apply_on_bytes(&self, mut bs: Bytes) -> Bytes {
         match (self.0, self.1) {
@@ -150,41 +211,47 @@ impl Display for BytesRange {
         match (self.0, self.1) {
             (Some(offset), None) => write!(f, "{offset}-"),
             (None, Some(size)) => write!(f, "-{size}"),
-            (Some(offset), Some(size)) => write!(f, "{}-{}", offset, offset + size - 1),
+            (Some(offset), Some(size)) => write!(
+                f,
+                "{}-{}",
+                offset,
+                offset.saturating_add(size.saturating_sub(1))
+            ),
             (None, None) => write!(f, "0-"),
         }
     }
 }
 
+/// Parse the value of `Range` header.
+///
+/// Only a single range is supported, the unit `bytes` is matched case-insensitively.
+/// Returns [`ErrorKind::InvalidInput`] if the value is not a valid range.
 impl FromStr for BytesRange {
     type Err = Error;
 
     fn from_str(value: &str) -> Result<Self> {
-        let s = value.strip_prefix("bytes=").ok_or_else(|| {
-            Error::new(ErrorKind::Unexpected, "header range is invalid")
+        let invalid_error = || {
+            Error::new(ErrorKind::InvalidInput, "header range is invalid")
                 .with_operation("BytesRange::from_str")
                 .with_context("value", value)
-        })?;
+        };
+
+        let (unit, s) = value.trim().split_once('=').ok_or_else(invalid_error)?;
+        if !unit.trim().eq_ignore_ascii_case("bytes") {
+            return Err(invalid_error());
+        }
 
+        let s = s.trim();
         if s.contains(',') {
-            return Err(Error::new(ErrorKind::Unexpected, "header range is invalid")
-                .with_operation("BytesRange::from_str")
-                .with_context("value", value));
+            return Err(invalid_error());
         }
 
         let v = s.split('-').collect::<Vec<_>>();
-        if v.len() != 2 {
-            return Err(Error::new(ErrorKind::Unexpected, "header range is invalid")
-                .with_operation("BytesRange::from_str")
-                .with_context("value", value));
+        if v.len() != 2 || (v[0].is_empty() && v[1].is_empty()) {
+            return Err(invalid_error());
         }
 
-        let parse_int_error = |e: std::num::ParseIntError| {
-            Error::new(ErrorKind::Unexpected, "header range is invalid")
-                .with_operation("BytesRange::from_str")
-                .with_context("value", value)
-                .set_source(e)
-        };
+        let parse_int_error = |e: std::num::ParseIntError| invalid_error().set_source(e);
 
         if v[1].is_empty() {
             // <range-start>-
@@ -196,17 +263,25 @@ impl FromStr for BytesRange {
             // -<suffix-length>
             Ok(BytesRange::new(
                 None,
-                Some(v[1].parse::<u64>().map_err(parse_int_error)? + 1),
+                Some(v[1].parse().map_err(parse_int_error)?),
             ))
         } else {
             // <range-start>-<range-end>
             let start: u64 = v[0].parse().map_err(parse_int_error)?;
             let end: u64 = v[1].parse().map_err(parse_int_error)?;
+            if end < start {
+                return Err(invalid_error());
+            }
             Ok(BytesRange::new(Some(start), Some(end - start + 1)))
         }
     }
 }
 
+/// Convert rust range bounds into BytesRange.
+///
+/// The conversion is saturating and never panics: bounds near `u64::MAX` are
+/// clamped and inverted ranges like `20..10` become empty. Please use
+/// [`BytesRange::try_from_range`] to reject inverted ranges instead.
 impl<T> From<T> for BytesRange
 where
     T: RangeBounds<u64>,
@@ -214,12 +289,15 @@ where
     fn from(range: T) -> Self {
         let offset = match range.start_bound().cloned() {
             Bound::Included(n) => Some(n),
-            Bound::Excluded(n) => Some(n + 1),
+            Bound::Excluded(n) => Some(n.saturating_add(1)),
             Bound::Unbounded => None,
         };
         let size = match range.end_bound().cloned() {
-            Bound::Included(n) => Some(n + 1 - offset.unwrap_or_default()),
-            Bound::Excluded(n) => Some(n - offset.unwrap_or_default()),
+            Bound::Included(n) => Some(
+                n.saturating_add(1)
+                    .saturating_sub(offset.unwrap_or_default()),
+            ),
+            Bound::Excluded(n) => Some(n.saturating_sub(offset.unwrap_or_default())),
             Bound::Unbounded => None,
         };
 
@@ -285,7 +363,7 @@ mod tests {
                 "bytes=123-",
                 BytesRange::new(Some(123), None),
             ),
-            ("suffix", "bytes=-123", BytesRange::new(None, Some(124))),
+            ("suffix", "bytes=-123", BytesRange::new(None, Some(123))),
             (
                 "range",
                 "bytes=123-124",
@@ -297,6 +375,16 @@ mod tests {
                 "bytes=0-0",
                 BytesRange::new(Some(0), Some(1)),
             ),
+            (
+                "upper case unit",
+                "Bytes=0-99",
+                BytesRange::new(Some(0), Some(100)),
+            ),
+            (
+                "with spaces",
+                " bytes = 1-2 ",
+                BytesRange::new(Some(1), Some(2)),
+            ),
         ];
 
         for (name, input, expected) in cases {
@@ -308,6 +396,176 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bytes_range_from_str_roundtrip() -> Result<()> {
+        for range in [
+            BytesRange::new(Some(123), None),
+            BytesRange::new(None, Some(123)),
+            BytesRange::new(Some(123), Some(2)),
+        ] {
+            assert_eq!(range, range.to_header().parse()?, "{range}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_range_from_str_invalid() {
+        let cases = vec![
+            ("no unit", "0-99"),
+            ("other unit", "items=0-99"),
+            ("multiple ranges", "bytes=0-1,3-4"),
+            ("empty range", "bytes=-"),
+            ("not a number", "bytes=a-b"),
+            ("end before start", "bytes=99-0"),
+        ];
+
+        for (name, input) in cases {
+            let actual = input.parse::<BytesRange>();
+
+            assert_eq!(
+                actual.err().map(|e| e.kind()),
+                Some(ErrorKind::InvalidInput),
+                "{name}"
+            )
+        }
+    }
+
+    #[test]
+    fn test_bytes_range_validate() -> Result<()> {
+        let cases = vec![
+            (
+                "full",
+                BytesRange::new(None, None),
+                BytesRange::new(Some(0), Some(1024)),
+            ),
+            (
+                "offset",
+                BytesRange::new(Some(1000), None),
+                BytesRange::new(Some(1000), Some(24)),
+            ),
+            (
+                "offset at end",
+                BytesRange::new(Some(1024), None),
+                BytesRange::new(Some(1024), Some(0)),
+            ),
+            (
+                "size beyond end",
+                BytesRange::new(Some(1000), Some(100)),
+                BytesRange::new(Some(1000), Some(24)),
+            ),
+            (
+                "suffix beyond end",
+                BytesRange::new(None, Some(2048)),
+                BytesRange::new(Some(0), Some(1024)),
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            assert_eq!(input.validate(1024)?, expected, "{name}");
+        }
+
+        let err = BytesRange::new(Some(1025), Some(10))
+            .validate(1024)
+            .expect_err("offset beyond end must fail");
+        assert_eq!(err.kind(), ErrorKind::RangeNotSatisfied);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_range_validate_boundary() -> Result<()> {
+        let cases = vec![
+            (
+                "start at size",
+                BytesRange::from(1024..),
+                BytesRange::new(Some(1024), Some(0)),
+            ),
+            (
+                "start at size with end",
+                BytesRange::from(1024..2048),
+                BytesRange::new(Some(1024), Some(0)),
+            ),
+            (
+                "unbounded upper near size",
+                BytesRange::from(1023..),
+                BytesRange::new(Some(1023), Some(1)),
+            ),
+            (
+                "upper at u64::MAX",
+                BytesRange::from(1000..u64::MAX),
+                BytesRange::new(Some(1000), Some(24)),
+            ),
+            (
+                "inclusive upper at u64::MAX",
+                BytesRange::from(1000..=u64::MAX),
+                BytesRange::new(Some(1000), Some(24)),
+            ),
+            (
+                "suffix at u64::MAX",
+                BytesRange::from(..=u64::MAX),
+                BytesRange::new(Some(0), Some(1024)),
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            assert_eq!(input.validate(1024)?, expected, "{name}");
+        }
+
+        for (name, input) in [
+            ("start beyond size", BytesRange::from(1025..)),
+            ("start at u64::MAX", BytesRange::from(u64::MAX..)),
+            (
+                "excluded start at u64::MAX",
+                BytesRange::from((Bound::Excluded(u64::MAX), Bound::Unbounded)),
+            ),
+        ] {
+            assert_eq!(
+                input.validate(1024).err().map(|e| e.kind()),
+                Some(ErrorKind::RangeNotSatisfied),
+                "{name}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_range_try_from_range() -> Result<()> {
+        assert_eq!(
+            BytesRange::try_from_range(10..20)?,
+            BytesRange::new(Some(10), Some(10))
+        );
+        assert_eq!(
+            BytesRange::try_from_range(10..10)?,
+            BytesRange::new(Some(10), Some(0))
+        );
+        assert_eq!(
+            BytesRange::try_from_range(0..=u64::MAX)?,
+            BytesRange::new(Some(0), Some(u64::MAX))
+        );
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let res = BytesRange::try_from_range(20..10);
+        assert_eq!(
+            res.err().map(|e| e.kind()),
+            Some(ErrorKind::RangeNotSatisfied)
+        );
+
+        // Inverted ranges will be saturated into empty ones while converting,
+        // which should never be rendered into headers.
+        #[allow(clippy::reversed_empty_ranges)]
+        let range = BytesRange::from(20..10);
+        assert_eq!(range, BytesRange::new(Some(20), Some(0)));
+        assert_eq!(range.size(), Some(0));
+        assert_eq!(
+            BytesRange::new(Some(u64::MAX), Some(10)).to_string(),
+            format!("{}-{}", u64::MAX, u64::MAX)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_apply_on_bytes() -> Result<()> {
         let bs = Bytes::from_static("Hello, World!".as_bytes());
@@ -155,7 +155,13 @@ impl HttpClient {
                 err.is_status()
             );
 
-            let mut oerr = Error::new(ErrorKind::Unexpected, "send async request")
+            let kind = if err.is_timeout() {
+                ErrorKind::Timeout
+            } else {
+                ErrorKind::Unexpected
+            };
+
+            let mut oerr = Error::new(kind, "send async request")
                 .with_operation("http_util::Client::send_async")
                 .with_context("url", uri.to_string())
                 .set_source(err);
@@ -192,8 +198,20 @@ impl HttpClient {
         let stream = resp.bytes_stream().map_err(move |err| {
             // If stream returns a body related error, we can convert
             // it to interrupt so we can retry it.
-            Error::new(ErrorKind::Unexpected, "read data from http stream")
-                .map(|v| if err.is_body() { v.set_temporary() } else { v })
+            let kind = if err.is_timeout() {
+                ErrorKind::Timeout
+            } else {
+                ErrorKind::Unexpected
+            };
+
+            Error::new(kind, "read data from http stream")
+                .map(|v| {
+                    if err.is_body() || err.is_timeout() {
+                        v.set_temporary()
+                    } else {
+                        v
+                    }
+                })
                 .with_context("url", uri.to_string())
                 .set_source(err)
         });
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::IoSlice;
+
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::DateTime;
@@ -22,10 +24,12 @@ use chrono::Utc;
 use http::header::HeaderName;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
 use http::header::ETAG;
+use http::header::EXPIRES;
 use http::header::LAST_MODIFIED;
 use http::header::LOCATION;
 use http::HeaderMap;
@@ -169,6 +173,31 @@ pub fn parse_last_modified(headers: &HeaderMap) -> Result<Option<DateTime<Utc>>>
     }
 }
 
+/// Parse expires from header map.
+///
+/// # Notes
+///
+/// Invalid values like `0` are allowed by [RFC 7234](https://httpwg.org/specs/rfc7234.html#header.expires)
+/// to represent a time in the past, so they will be ignored instead of
+/// returning an error.
+pub fn parse_expires(headers: &HeaderMap) -> Result<Option<DateTime<Utc>>> {
+    match headers.get(EXPIRES) {
+        None => Ok(None),
+        Some(v) => {
+            let v = v.to_str().map_err(|e| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "header value is not valid utf-8 string",
+                )
+                .with_operation("http_util::parse_expires")
+                .set_source(e)
+            })?;
+
+            Ok(parse_datetime_from_rfc2822(v).ok())
+        }
+    }
+}
+
 /// Parse etag from header map.
 pub fn parse_etag(headers: &HeaderMap) -> Result<Option<&str>> {
     match headers.get(ETAG) {
@@ -199,6 +228,39 @@ pub fn parse_content_disposition(headers: &HeaderMap) -> Result<Option<&str>> {
     }
 }
 
+/// Parse Content-Encoding for header map
+pub fn parse_content_encoding(headers: &HeaderMap) -> Result<Option<&str>> {
+    match headers.get(CONTENT_ENCODING) {
+        None => Ok(None),
+        Some(v) => Ok(Some(v.to_str().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "header value has to be valid utf-8 string",
+            )
+            .with_operation("http_util::parse_content_encoding")
+            .set_source(e)
+        })?)),
+    }
+}
+
+/// Parse header value to string according to name.
+///
+/// This is used by services to parse their own headers like `x-amz-version-id`.
+pub fn parse_header_to_str<'a>(headers: &'a HeaderMap, name: &str) -> Result<Option<&'a str>> {
+    match headers.get(name) {
+        None => Ok(None),
+        Some(v) => Ok(Some(v.to_str().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "header value has to be valid utf-8 string",
+            )
+            .with_operation("http_util::parse_header_to_str")
+            .with_context("header_name", name)
+            .set_source(e)
+        })?)),
+    }
+}
+
 /// parse_into_metadata will parse standards http headers into Metadata.
 ///
 /// # Notes
@@ -242,10 +304,40 @@ pub fn parse_into_metadata(path: &str, headers: &HeaderMap) -> Result<Metadata>
         m.set_last_modified(v);
     }
 
+    if let Some(v) = parse_expires(headers)? {
+        m.set_expires(v);
+    }
+
     if let Some(v) = parse_content_disposition(headers)? {
         m.set_content_disposition(v);
     }
 
+    if let Some(v) = parse_content_encoding(headers)? {
+        m.set_content_encoding(v);
+    }
+
+    Ok(m)
+}
+
+/// parse_write_metadata will parse the response headers of a write
+/// request into Metadata.
+///
+/// # Notes
+///
+/// Only `ETag` and `Last-Modified` will be parsed. Headers like
+/// `Content-Length` describe the response itself instead of the written
+/// file, so they are ignored here.
+pub fn parse_write_metadata(headers: &HeaderMap) -> Result<Metadata> {
+    let mut m = Metadata::new(EntryMode::FILE);
+
+    if let Some(v) = parse_etag(headers)? {
+        m.set_etag(v);
+    }
+
+    if let Some(v) = parse_last_modified(headers)? {
+        m.set_last_modified(v);
+    }
+
     Ok(m)
 }
 
@@ -257,6 +349,19 @@ pub fn format_content_md5(bs: &[u8]) -> String {
     general_purpose::STANDARD.encode(hasher.finalize())
 }
 
+/// format content md5 header by given chunks of input.
+///
+/// It's the same as [`format_content_md5`] on the concatenated chunks, but
+/// avoids the extra copy.
+pub fn format_content_md5_vectored(bs: &[IoSlice]) -> String {
+    let mut hasher = md5::Md5::new();
+    for b in bs {
+        hasher.update(b);
+    }
+
+    general_purpose::STANDARD.encode(hasher.finalize())
+}
+
 /// format authorization header by basic auth.
 ///
 /// # Errors
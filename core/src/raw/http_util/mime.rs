@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// Guess the content type of given path by its extension.
+///
+/// Only common extensions are covered by the built-in map, `None` will be
+/// returned for unknown extensions or paths without extension.
+#[cfg(feature = "content-type-guess")]
+pub fn guess_content_type(path: &str) -> Option<&'static str> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let (stem, ext) = name.rsplit_once('.')?;
+    // Hidden files like `.gitignore` don't have an extension.
+    if stem.is_empty() {
+        return None;
+    }
+
+    let v = match ext.to_ascii_lowercase().as_str() {
+        "aac" => "audio/aac",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "gif" => "image/gif",
+        "gz" => "application/gzip",
+        "htm" | "html" => "text/html",
+        "ico" => "image/vnd.microsoft.icon",
+        "jpeg" | "jpg" => "image/jpeg",
+        "js" | "mjs" => "text/javascript",
+        "json" => "application/json",
+        "md" => "text/markdown",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        "ogg" => "audio/ogg",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        "tar" => "application/x-tar",
+        "tif" | "tiff" => "image/tiff",
+        "txt" => "text/plain",
+        "wasm" => "application/wasm",
+        "wav" => "audio/wav",
+        "webm" => "video/webm",
+        "webp" => "image/webp",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "xml" => "application/xml",
+        "yaml" | "yml" => "application/yaml",
+        "zip" => "application/zip",
+        "zst" => "application/zstd",
+        _ => return None,
+    };
+    Some(v)
+}
+
+/// Guess the content type of given path by its extension.
+///
+/// Always returns `None` since the `content-type-guess` feature is disabled.
+#[cfg(not(feature = "content-type-guess"))]
+pub fn guess_content_type(_: &str) -> Option<&'static str> {
+    None
+}
+
+#[cfg(all(test, feature = "content-type-guess"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_content_type() {
+        let cases = vec![
+            ("index.html", Some("text/html")),
+            ("dir/photo.JPG", Some("image/jpeg")),
+            ("dir.json/data", None),
+            ("archive.tar.gz", Some("application/gzip")),
+            ("dir/.gitignore", None),
+            ("README", None),
+            ("data.unknown", None),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(guess_content_type(path), expected, "{path}");
+        }
+    }
+}
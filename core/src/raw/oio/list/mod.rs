@@ -36,3 +36,12 @@ pub use hierarchy_list::HierarchyLister;
 
 mod prefix_list;
 pub use prefix_list::PrefixLister;
+
+mod sorted_list;
+pub use sorted_list::SortedLister;
+
+mod start_before_list;
+pub use start_before_list::StartBeforeLister;
+
+mod only_dirs_list;
+pub use only_dirs_list::OnlyDirsLister;
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use crate::raw::*;
+use crate::*;
+
+/// OnlyDirsLister is used to only return dirs under the listing path.
+///
+/// # Notes
+///
+/// Services like s3 don't return dir entries while listing recursively, so
+/// OnlyDirsLister will derive dirs from the parent paths of all entries. Every
+/// dir will be returned only once, all visited dirs will be kept in memory.
+pub struct OnlyDirsLister<L> {
+    lister: L,
+    path: String,
+    visited: HashSet<String>,
+    entries: VecDeque<oio::Entry>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for OnlyDirsLister.
+unsafe impl<L> Sync for OnlyDirsLister<L> {}
+
+impl<L> OnlyDirsLister<L> {
+    /// Create a new only dirs lister
+    pub fn new(lister: L, path: &str) -> OnlyDirsLister<L> {
+        let path = if path == "/" {
+            "".to_string()
+        } else {
+            path.to_string()
+        };
+
+        OnlyDirsLister {
+            lister,
+            path,
+            visited: HashSet::default(),
+            entries: VecDeque::default(),
+        }
+    }
+
+    /// Push the dirs derived from given entry and the entry itself if it's a dir.
+    ///
+    /// Parent dirs are pushed before their children.
+    fn push_entry(&mut self, e: oio::Entry) {
+        // If path is not started with prefix, drop it.
+        //
+        // Ideally, it should never happen. But we just tolerate
+        // this state.
+        let Some(rel) = e.path().strip_prefix(self.path.as_str()) else {
+            return;
+        };
+
+        let prefix_len = self.path.len();
+        for (idx, _) in rel.match_indices('/') {
+            let dir = &e.path()[..prefix_len + idx + 1];
+            // The entry itself will be handled later.
+            if dir == e.path() {
+                break;
+            }
+            if self.visited.insert(dir.to_string()) {
+                self.entries
+                    .push_back(oio::Entry::new(dir, Metadata::new(EntryMode::DIR)));
+            }
+        }
+
+        if e.mode().is_dir() && self.visited.insert(e.path().to_string()) {
+            self.entries.push_back(e);
+        }
+    }
+}
+
+impl<L> oio::List for OnlyDirsLister<L>
+where
+    L: oio::List,
+{
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<oio::Entry>>> {
+        loop {
+            if let Some(e) = self.entries.pop_front() {
+                return Poll::Ready(Ok(Some(e)));
+            }
+
+            match ready!(self.lister.poll_next(cx))? {
+                Some(e) => self.push_entry(e),
+                None => return Poll::Ready(Ok(None)),
+            }
+        }
+    }
+}
+
+impl<L> oio::BlockingList for OnlyDirsLister<L>
+where
+    L: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            if let Some(e) = self.entries.pop_front() {
+                return Ok(Some(e));
+            }
+
+            match self.lister.next()? {
+                Some(e) => self.push_entry(e),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockLister {
+        entries: VecDeque<oio::Entry>,
+    }
+
+    impl MockLister {
+        fn new(paths: &[&str]) -> Self {
+            let entries = paths
+                .iter()
+                .map(|p| oio::Entry::new(p, Metadata::new(EntryMode::from_path(p))))
+                .collect();
+            Self { entries }
+        }
+    }
+
+    impl oio::BlockingList for MockLister {
+        fn next(&mut self) -> Result<Option<oio::Entry>> {
+            Ok(self.entries.pop_front())
+        }
+    }
+
+    #[test]
+    fn test_only_dirs_lister() -> Result<()> {
+        let lister = MockLister::new(&[
+            "dir/a",
+            "dir/x/b",
+            "dir/x/y/c",
+            "dir/x/",
+            "dir/z/",
+            "dir/x/y/d",
+        ]);
+        let mut lister = OnlyDirsLister::new(lister, "dir/");
+
+        let mut actual = vec![];
+        while let Some(e) = oio::BlockingList::next(&mut lister)? {
+            assert!(e.mode().is_dir());
+            actual.push(e.path().to_string());
+        }
+        assert_eq!(actual, vec!["dir/x/", "dir/x/y/", "dir/z/"]);
+
+        Ok(())
+    }
+}
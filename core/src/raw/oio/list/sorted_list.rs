@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::vec::IntoIter;
+
+use crate::raw::*;
+use crate::*;
+
+/// SortedLister is used to return entries in sorted path order.
+///
+/// Most services return entries in lexicographical order, but it's not guaranteed
+/// for all services, especially for recursive listing. SortedLister will buffer
+/// all entries returned by the inner lister and sort them by path before returning.
+///
+/// # Notes
+///
+/// The memory usage of SortedLister is `O(n)` of the number of entries under
+/// the listing path, and no entry will be returned before the inner lister is
+/// drained, since we can't know if a smaller path will be returned later.
+pub struct SortedLister<L> {
+    state: State<L>,
+}
+
+enum State<L> {
+    Buffering(L, Vec<oio::Entry>),
+    Sorted(IntoIter<oio::Entry>),
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for SortedLister.
+unsafe impl<L> Sync for SortedLister<L> {}
+
+impl<L> SortedLister<L> {
+    /// Create a new sorted lister
+    pub fn new(lister: L) -> SortedLister<L> {
+        SortedLister {
+            state: State::Buffering(lister, Vec::new()),
+        }
+    }
+}
+
+fn sort_entries(mut entries: Vec<oio::Entry>) -> IntoIter<oio::Entry> {
+    entries.sort_by(|a, b| a.path().cmp(b.path()));
+    entries.into_iter()
+}
+
+impl<L> oio::List for SortedLister<L>
+where
+    L: oio::List,
+{
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<oio::Entry>>> {
+        loop {
+            match &mut self.state {
+                State::Buffering(lister, entries) => match ready!(lister.poll_next(cx))? {
+                    Some(e) => entries.push(e),
+                    None => {
+                        let entries = std::mem::take(entries);
+                        self.state = State::Sorted(sort_entries(entries));
+                    }
+                },
+                State::Sorted(entries) => return Poll::Ready(Ok(entries.next())),
+            }
+        }
+    }
+}
+
+impl<L> oio::BlockingList for SortedLister<L>
+where
+    L: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match &mut self.state {
+                State::Buffering(lister, entries) => match lister.next()? {
+                    Some(e) => entries.push(e),
+                    None => {
+                        let entries = std::mem::take(entries);
+                        self.state = State::Sorted(sort_entries(entries));
+                    }
+                },
+                State::Sorted(entries) => return Ok(entries.next()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oio::BlockingList;
+
+    use super::*;
+
+    struct MockLister {
+        inner: IntoIter<&'static str>,
+    }
+
+    impl oio::BlockingList for MockLister {
+        fn next(&mut self) -> Result<Option<oio::Entry>> {
+            Ok(self
+                .inner
+                .next()
+                .map(|path| oio::Entry::new(path, Metadata::new(EntryMode::FILE))))
+        }
+    }
+
+    #[test]
+    fn test_sorted_list() -> Result<()> {
+        let inner = MockLister {
+            inner: vec!["x/b", "x/a/c", "x/a", "x/c/a", "x/a/b"].into_iter(),
+        };
+        let mut lister = SortedLister::new(inner);
+
+        let mut paths = Vec::new();
+        while let Some(e) = lister.next()? {
+            paths.push(e.path().to_string());
+        }
+
+        assert_eq!(paths, vec!["x/a", "x/a/b", "x/a/c", "x/b", "x/c/a"]);
+        Ok(())
+    }
+}
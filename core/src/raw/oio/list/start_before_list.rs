@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use crate::raw::*;
+use crate::*;
+
+/// StartBeforeLister is used to filter entries whose path is lexicographically
+/// before the given key.
+///
+/// # Notes
+///
+/// Services don't support listing before a key natively, so all entries under
+/// the listing path will still be fetched and filtered at client side. The
+/// order of entries is kept as is, and the lister won't stop early even if
+/// the key has been passed since the underlying order is not guaranteed.
+pub struct StartBeforeLister<L> {
+    lister: L,
+    start_before: String,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for StartBeforeLister.
+unsafe impl<L> Sync for StartBeforeLister<L> {}
+
+impl<L> StartBeforeLister<L> {
+    /// Create a new start before lister
+    pub fn new(lister: L, start_before: &str) -> StartBeforeLister<L> {
+        StartBeforeLister {
+            lister,
+            start_before: start_before.to_string(),
+        }
+    }
+}
+
+impl<L> oio::List for StartBeforeLister<L>
+where
+    L: oio::List,
+{
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<oio::Entry>>> {
+        loop {
+            match ready!(self.lister.poll_next(cx)) {
+                Ok(Some(e)) if e.path() >= self.start_before.as_str() => continue,
+                v => return Poll::Ready(v),
+            }
+        }
+    }
+}
+
+impl<L> oio::BlockingList for StartBeforeLister<L>
+where
+    L: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.lister.next() {
+                Ok(Some(e)) if e.path() >= self.start_before.as_str() => continue,
+                v => return v,
+            }
+        }
+    }
+}
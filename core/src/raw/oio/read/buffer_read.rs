@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::min;
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// BufferReader will read ahead up to `cap` bytes from the inner reader and
+/// serve following reads and seeks inside the buffered window from memory.
+///
+/// Only reads that go beyond the buffered window will reach the inner reader,
+/// which coalesces many small reads into fewer larger ones.
+pub struct BufferReader<R> {
+    r: R,
+    cap: usize,
+
+    buf: Vec<u8>,
+    /// The valid bytes in `buf`.
+    filled: usize,
+    /// The cursor of next read in `buf`.
+    cursor: usize,
+    /// The position of inner reader, which is the end of buffered window.
+    pos: u64,
+}
+
+impl<R> BufferReader<R> {
+    /// Create a new BufferReader with given capacity.
+    pub fn new(r: R, cap: usize) -> Self {
+        Self {
+            r,
+            cap,
+            buf: Vec::new(),
+            filled: 0,
+            cursor: 0,
+            pos: 0,
+        }
+    }
+
+    /// The logical position of this reader.
+    fn current(&self) -> u64 {
+        self.pos - (self.filled - self.cursor) as u64
+    }
+
+    /// Try to seek inside the buffered window, return `None` if not possible.
+    fn seek_in_buffer(&mut self, pos: SeekFrom) -> Option<Result<u64>> {
+        let target = match pos {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::Current(n) => self.current() as i64 + n,
+            // We don't know the total size, let inner reader handle it.
+            SeekFrom::End(_) => return None,
+        };
+        if target < 0 {
+            return Some(Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )));
+        }
+
+        let start = self.pos - self.filled as u64;
+        let target = target as u64;
+        if target >= start && target <= self.pos {
+            self.cursor = (target - start) as usize;
+            return Some(Ok(target));
+        }
+        None
+    }
+
+    /// Convert the seek position into the one that can be sent to inner reader.
+    fn inner_seek_pos(&self, pos: SeekFrom) -> SeekFrom {
+        match pos {
+            // Inner reader's position is not the same as logical position.
+            SeekFrom::Current(n) => SeekFrom::Start((self.current() as i64 + n) as u64),
+            _ => pos,
+        }
+    }
+
+    fn reset(&mut self, pos: u64) {
+        self.filled = 0;
+        self.cursor = 0;
+        self.pos = pos;
+    }
+
+    fn consume_buffer(&mut self, buf: &mut [u8]) -> usize {
+        let n = min(buf.len(), self.filled - self.cursor);
+        buf[..n].copy_from_slice(&self.buf[self.cursor..self.cursor + n]);
+        self.cursor += n;
+        n
+    }
+}
+
+impl<R: oio::Read> oio::Read for BufferReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        if self.cursor < self.filled {
+            return Poll::Ready(Ok(self.consume_buffer(buf)));
+        }
+
+        // Bypass the buffer if the output buffer is large enough.
+        if buf.len() >= self.cap {
+            let n = ready!(self.r.poll_read(cx, buf))?;
+            self.reset(self.pos + n as u64);
+            return Poll::Ready(Ok(n));
+        }
+
+        self.buf.resize(self.cap, 0);
+        let n = ready!(self.r.poll_read(cx, &mut self.buf))?;
+        self.filled = n;
+        self.cursor = 0;
+        self.pos += n as u64;
+
+        Poll::Ready(Ok(self.consume_buffer(buf)))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        if let Some(res) = self.seek_in_buffer(pos) {
+            return Poll::Ready(res);
+        }
+
+        let pos = self.inner_seek_pos(pos);
+        let n = ready!(self.r.poll_seek(cx, pos))?;
+        self.reset(n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        if self.cursor < self.filled {
+            let bs = Bytes::copy_from_slice(&self.buf[self.cursor..self.filled]);
+            self.cursor = self.filled;
+            return Poll::Ready(Some(Ok(bs)));
+        }
+
+        match ready!(self.r.poll_next(cx)) {
+            Some(Ok(bs)) => {
+                self.reset(self.pos + bs.len() as u64);
+                Poll::Ready(Some(Ok(bs)))
+            }
+            v => Poll::Ready(v),
+        }
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for BufferReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if self.cursor < self.filled {
+            return Ok(self.consume_buffer(buf));
+        }
+
+        // Bypass the buffer if the output buffer is large enough.
+        if buf.len() >= self.cap {
+            let n = self.r.read(buf)?;
+            self.reset(self.pos + n as u64);
+            return Ok(n);
+        }
+
+        self.buf.resize(self.cap, 0);
+        let n = self.r.read(&mut self.buf)?;
+        self.filled = n;
+        self.cursor = 0;
+        self.pos += n as u64;
+
+        Ok(self.consume_buffer(buf))
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        if let Some(res) = self.seek_in_buffer(pos) {
+            return res;
+        }
+
+        let pos = self.inner_seek_pos(pos);
+        let n = self.r.seek(pos)?;
+        self.reset(n);
+        Ok(n)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        if self.cursor < self.filled {
+            let bs = Bytes::copy_from_slice(&self.buf[self.cursor..self.filled]);
+            self.cursor = self.filled;
+            return Some(Ok(bs));
+        }
+
+        match self.r.next() {
+            Some(Ok(bs)) => {
+                self.reset(self.pos + bs.len() as u64);
+                Some(Ok(bs))
+            }
+            v => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_buffer_reader() {
+        use oio::ReadExt;
+
+        let mut rng = ThreadRng::default();
+        let mut content = vec![0; 4096];
+        rng.fill_bytes(&mut content);
+
+        let r = oio::Cursor::from(content.clone());
+        let mut r = BufferReader::new(Box::new(r) as oio::Reader, 1024);
+
+        let mut buf = vec![0; 16];
+        let n = r.read(&mut buf).await.expect("read must succeed");
+        assert_eq!(&buf[..n], &content[..16]);
+
+        // Seek inside the buffer.
+        let pos = r
+            .seek(SeekFrom::Start(512))
+            .await
+            .expect("seek must succeed");
+        assert_eq!(pos, 512);
+        let n = r.read(&mut buf).await.expect("read must succeed");
+        assert_eq!(&buf[..n], &content[512..528]);
+
+        let pos = r
+            .seek(SeekFrom::Current(-16))
+            .await
+            .expect("seek must succeed");
+        assert_eq!(pos, 512);
+        let n = r.read(&mut buf).await.expect("read must succeed");
+        assert_eq!(&buf[..n], &content[512..528]);
+
+        // Seek outside the buffer.
+        let pos = r
+            .seek(SeekFrom::Start(2048))
+            .await
+            .expect("seek must succeed");
+        assert_eq!(pos, 2048);
+        let n = r.read(&mut buf).await.expect("read must succeed");
+        assert_eq!(&buf[..n], &content[2048..2064]);
+
+        let mut rest = Vec::new();
+        r.read_to_end(&mut rest).await.expect("read must succeed");
+        assert_eq!(rest, &content[2064..]);
+    }
+}
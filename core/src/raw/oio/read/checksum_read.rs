@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use md5::Digest;
+use md5::Md5;
+
+use crate::raw::*;
+use crate::*;
+
+/// ChecksumReader will verify the content read from inner reader against
+/// the given content md5.
+///
+/// # Notes
+///
+/// - The checksum will be verified only when inner reader returns EOF.
+/// - The checksum must cover the whole content, so users should not use
+///   ChecksumReader for range read.
+/// - Seek will disable the verification since we can't compute the checksum
+///   of the whole content anymore.
+/// - The verification will be skipped if the given content md5 is empty or
+///   not a valid md5 digest (for example, the etag of a multipart upload).
+pub struct ChecksumReader<R> {
+    inner: R,
+    /// The expected content md5, could be encoded in base64 or hex.
+    expected: String,
+    /// Hasher will be set to `None` after verified or seeked, or if the
+    /// expected content md5 is not a valid md5 digest.
+    hasher: Option<Md5>,
+}
+
+impl<R> ChecksumReader<R> {
+    /// Create a new checksum reader with expected content md5.
+    pub fn new(inner: R, content_md5: &str) -> Self {
+        let expected = content_md5.trim_matches('"').to_string();
+        let hasher = is_valid_md5(&expected).then(Md5::new);
+        Self {
+            inner,
+            expected,
+            hasher,
+        }
+    }
+
+    fn update(&mut self, bs: &[u8]) {
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.update(bs);
+        }
+    }
+
+    fn verify(&mut self) -> Result<()> {
+        let Some(hasher) = self.hasher.take() else {
+            return Ok(());
+        };
+
+        let digest = hasher.finalize();
+        let expected = self.expected.as_str();
+        if expected == BASE64_STANDARD.encode(digest)
+            || expected.eq_ignore_ascii_case(&format!("{:x}", digest))
+        {
+            return Ok(());
+        }
+
+        Err(
+            Error::new(ErrorKind::ChecksumMismatch, "content md5 is mismatch")
+                .with_context("expected", expected)
+                .with_context("actual", BASE64_STANDARD.encode(digest)),
+        )
+    }
+}
+
+/// Check if the given content md5 is a md5 digest encoded in base64 or hex.
+fn is_valid_md5(v: &str) -> bool {
+    if v.len() == 32 && v.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return true;
+    }
+    matches!(BASE64_STANDARD.decode(v), Ok(bs) if bs.len() == 16)
+}
+
+impl<R: oio::Read> oio::Read for ChecksumReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_read(cx, buf))?;
+        if n == 0 && !buf.is_empty() {
+            self.verify()?;
+        } else {
+            self.update(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let n = ready!(self.inner.poll_seek(cx, pos))?;
+        self.hasher = None;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match ready!(self.inner.poll_next(cx)) {
+            Some(Ok(bs)) => {
+                self.update(&bs);
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => match self.verify() {
+                Ok(()) => Poll::Ready(None),
+                Err(err) => Poll::Ready(Some(Err(err))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::oio::ReadExt;
+
+    #[tokio::test]
+    async fn test_checksum_reader() {
+        let content = b"Hello, World!".to_vec();
+        let md5 = format_content_md5(&content);
+
+        let r = oio::Cursor::from(content.clone());
+        let mut r = ChecksumReader::new(r, &md5);
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await.expect("read must success");
+        assert_eq!(buf, content);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader_mismatch() {
+        let content = b"Hello, World!".to_vec();
+        let md5 = format_content_md5(b"Hello, OpenDAL!");
+
+        let r = oio::Cursor::from(content);
+        let mut r = ChecksumReader::new(r, &md5);
+        let mut buf = Vec::new();
+        let err = r.read_to_end(&mut buf).await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader_skip_invalid_md5() {
+        let content = b"Hello, World!".to_vec();
+
+        for md5 in [
+            "",
+            "\"\"",
+            "not a md5",
+            "\"d41d8cd98f00b204e9800998ecf8427e-2\"",
+        ] {
+            let r = oio::Cursor::from(content.clone());
+            let mut r = ChecksumReader::new(r, md5);
+            let mut buf = Vec::new();
+            r.read_to_end(&mut buf).await.expect("read must success");
+            assert_eq!(buf, content);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader_hex_md5() {
+        let content = b"Hello, World!".to_vec();
+        let md5 = format!("\"{:x}\"", Md5::digest(&content));
+
+        let r = oio::Cursor::from(content.clone());
+        let mut r = ChecksumReader::new(r, &md5);
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await.expect("read must success");
+        assert_eq!(buf, content);
+    }
+}
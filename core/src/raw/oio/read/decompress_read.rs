@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::io::SeekFrom;
+use std::mem;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use bytes::Bytes;
+use futures::stream::IntoAsyncRead;
+use futures::AsyncRead;
+use futures::Stream;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The size of buffer used to read from inner reader and to yield decompressed bytes.
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// DecompressReader will decompress the content read from inner reader.
+///
+/// # Notes
+///
+/// Seek is not supported since the position of decompressed content can't
+/// be mapped to the compressed one.
+pub struct DecompressReader<R: oio::Read> {
+    decoder: Decoder<R>,
+    buf: Vec<u8>,
+}
+
+enum Decoder<R: oio::Read> {
+    Gzip(GzipDecoder<IntoAsyncRead<ReadStream<R>>>),
+    Zstd(ZstdDecoder<IntoAsyncRead<ReadStream<R>>>),
+}
+
+impl<R: oio::Read> DecompressReader<R> {
+    /// Create a new decompress reader.
+    ///
+    /// [`Compression::Auto`] must be resolved before creating the reader.
+    pub fn new(inner: R, compression: Compression) -> Result<Self> {
+        let stream = ReadStream {
+            inner,
+            buf: vec![0; DEFAULT_BUFFER_SIZE],
+        }
+        .into_async_read();
+
+        let decoder = match compression {
+            Compression::Gzip => Decoder::Gzip(GzipDecoder::new(stream)),
+            Compression::Zstd => Decoder::Zstd(ZstdDecoder::new(stream)),
+            Compression::Auto => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "compression auto must be resolved before decompressing",
+                ))
+            }
+        };
+
+        Ok(Self {
+            decoder,
+            buf: Vec::new(),
+        })
+    }
+}
+
+impl<R: oio::Read> oio::Read for DecompressReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let res = match &mut self.decoder {
+            Decoder::Gzip(d) => Pin::new(d).poll_read(cx, buf),
+            Decoder::Zstd(d) => Pin::new(d).poll_read(cx, buf),
+        };
+
+        res.map_err(|err| {
+            new_std_io_error(err)
+                .with_operation(oio::ReadOperation::Read)
+                .with_context("source", "DecompressReader")
+        })
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let (_, _) = (cx, pos);
+
+        Poll::Ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "DecompressReader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        if self.buf.is_empty() {
+            self.buf = vec![0; DEFAULT_BUFFER_SIZE];
+        }
+
+        let mut buf = mem::take(&mut self.buf);
+        let res = self.poll_read(cx, &mut buf);
+        self.buf = buf;
+
+        match ready!(res) {
+            Ok(0) => Poll::Ready(None),
+            Ok(n) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(&self.buf[..n])))),
+            Err(err) => Poll::Ready(Some(Err(err))),
+        }
+    }
+}
+
+/// ReadStream adapts [`oio::Read`] into a stream of bytes so that it can be
+/// used as `AsyncBufRead` by decoders.
+struct ReadStream<R: oio::Read> {
+    inner: R,
+    buf: Vec<u8>,
+}
+
+impl<R: oio::Read> Stream for ReadStream<R> {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        match ready!(this.inner.poll_read(cx, &mut this.buf)) {
+            Ok(0) => Poll::Ready(None),
+            Ok(n) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(&this.buf[..n])))),
+            Err(err) => Poll::Ready(Some(Err(err.into()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_compression::futures::write::GzipEncoder;
+    use futures::AsyncWriteExt;
+    use rand::thread_rng;
+    use rand::RngCore;
+
+    use super::*;
+    use crate::raw::oio::ReadExt;
+
+    #[tokio::test]
+    async fn test_decompress_reader_gzip() -> Result<()> {
+        let mut rng = thread_rng();
+        let mut content = vec![0; 256 * 1024];
+        rng.fill_bytes(&mut content);
+
+        let mut encoder = GzipEncoder::new(Vec::new());
+        encoder.write_all(&content).await.unwrap();
+        encoder.close().await.unwrap();
+        let compressed = encoder.into_inner();
+
+        let mut r = DecompressReader::new(oio::Cursor::from(compressed), Compression::Gzip)?;
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(buf, content);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decompress_reader_corrupted() -> Result<()> {
+        let mut r = DecompressReader::new(
+            oio::Cursor::from(b"not gzip content".to_vec()),
+            Compression::Gzip,
+        )?;
+        let mut buf = Vec::new();
+        assert!(r.read_to_end(&mut buf).await.is_err());
+        Ok(())
+    }
+}
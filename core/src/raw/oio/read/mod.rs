@@ -48,3 +48,23 @@ pub use std_read::StdReader;
 
 mod lazy_read;
 pub use lazy_read::LazyReader;
+
+mod checksum_read;
+pub use checksum_read::ChecksumReader;
+
+mod buffer_read;
+pub use buffer_read::BufferReader;
+
+mod progress_read;
+pub use progress_read::ProgressReader;
+
+mod transform_read;
+pub use transform_read::TransformReader;
+
+mod resume_read;
+pub use resume_read::ResumeReader;
+
+#[cfg(feature = "compress")]
+mod decompress_read;
+#[cfg(feature = "compress")]
+pub use decompress_read::DecompressReader;
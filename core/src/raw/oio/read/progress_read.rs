@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// ProgressReader will call the [`ProgressCallback`] every time a non-empty
+/// chunk has been read from the inner reader.
+///
+/// The callback will be called with the total bytes read so far and the
+/// total size hint if known. Seeking doesn't reset the counter.
+pub struct ProgressReader<R> {
+    inner: R,
+
+    callback: ProgressCallback,
+    read: u64,
+    total: Option<u64>,
+}
+
+impl<R> ProgressReader<R> {
+    /// Create a new progress reader.
+    pub fn new(inner: R, callback: ProgressCallback, total: Option<u64>) -> Self {
+        Self {
+            inner,
+            callback,
+            read: 0,
+            total,
+        }
+    }
+
+    fn advance(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        self.read += n as u64;
+        self.callback.call(self.read, self.total);
+    }
+}
+
+impl<R: oio::Read> oio::Read for ProgressReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_read(cx, buf))?;
+        self.advance(n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let res = ready!(self.inner.poll_next(cx));
+        if let Some(Ok(bs)) = &res {
+            self.advance(bs.len());
+        }
+        Poll::Ready(res)
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.inner.seek(pos)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        let res = self.inner.next();
+        if let Some(Ok(bs)) = &res {
+            self.advance(bs.len());
+        }
+        res
+    }
+}
@@ -23,6 +23,7 @@ use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
+use bytes::Buf;
 use bytes::Bytes;
 
 use crate::raw::*;
@@ -37,6 +38,13 @@ use crate::*;
 /// The `seek` operation on `RangeReader` is zero cost and purely in-memory. But calling `seek`
 /// while there is a pending read request will cancel the request and start a new one. This could
 /// add extra cost to the read operation.
+///
+/// If `chunk` is set in `OpRead`, RangeReader will split the read into multiple requests, each
+/// of them reads at most `chunk` bytes.
+///
+/// If `merge_gap` is set in `OpRead`, seeking forward at most `merge_gap` bytes while reading
+/// will drop the bytes in between from current request instead of starting a new one. The
+/// dropping is lazy and happens in the next read, so `seek` is still purely in-memory.
 pub struct RangeReader<A: Accessor, R> {
     acc: Arc<A>,
     path: Arc<String>,
@@ -45,6 +53,13 @@ pub struct RangeReader<A: Accessor, R> {
     offset: Option<u64>,
     size: Option<u64>,
     cur: u64,
+    chunk: Option<u64>,
+    /// chunk_start is the position where current read request starts.
+    chunk_start: u64,
+    merge_gap: Option<u64>,
+    /// skip is the size of bytes that need to be dropped from current read request
+    /// before serving reads, it's set by seeking forward within `merge_gap`.
+    skip: u64,
     state: State<R>,
 }
 
@@ -87,6 +102,9 @@ where
             v => v,
         };
 
+        let chunk = op.chunk().map(|v| v as u64);
+        let merge_gap = op.merge_gap().map(|v| v as u64);
+
         RangeReader {
             acc,
             path: Arc::new(path.to_string()),
@@ -95,6 +113,10 @@ where
             offset,
             size,
             cur: 0,
+            chunk,
+            chunk_start: 0,
+            merge_gap,
+            skip: 0,
             state: State::<R>::Idle,
         }
     }
@@ -186,7 +208,57 @@ where
             .offset
             .expect("offset must be set before calculating range");
 
-        BytesRange::new(Some(offset + self.cur), self.size.map(|v| v - self.cur))
+        let size = self.size.map(|v| v - self.cur);
+        // Limit the size of read request by chunk.
+        let size = match self.chunk {
+            Some(chunk) => Some(size.map_or(chunk, |v| v.min(chunk))),
+            None => size,
+        };
+
+        BytesRange::new(Some(offset + self.cur), size)
+    }
+
+    /// Ensure size by the `RpRead` returned by read request.
+    ///
+    /// The content size of a chunked read request is the size of current chunk instead
+    /// of the rest content, so we can't use it unless it's shorter than requested.
+    fn ensure_size_by_read(&mut self, rp: &RpRead) {
+        let mut content_size = rp.size();
+        if self.chunk.is_some() && content_size >= self.calculate_range().size() {
+            content_size = None;
+        }
+
+        self.ensure_size(rp.range().unwrap_or_default().size(), content_size);
+    }
+
+    /// Check if we should send a new read request for the next chunk.
+    ///
+    /// We will stop if current chunk returns nothing to avoid endless requests.
+    fn has_next_chunk(&self) -> bool {
+        self.chunk.is_some()
+            && self.cur > self.chunk_start
+            && self.cur < self.size.unwrap_or(u64::MAX)
+    }
+
+    /// Calculate the target position if this seek can be served by dropping bytes
+    /// from current read request.
+    ///
+    /// Returns `None` if merge gap is not set or the seek is not forward within it.
+    fn merge_target(&self, pos: SeekFrom) -> Option<u64> {
+        let gap = self.merge_gap?;
+        let target = match pos {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(n) => self.cur.checked_add(u64::try_from(n).ok()?)?,
+            SeekFrom::End(n) => u64::try_from((self.size? as i64).checked_add(n)?).ok()?,
+        };
+
+        // The target must be inside the range of current read request.
+        let end = match self.chunk {
+            Some(chunk) => self.size.unwrap_or(u64::MAX).min(self.chunk_start + chunk),
+            None => self.size.unwrap_or(u64::MAX),
+        };
+
+        (target > self.cur && target - self.cur <= gap && target < end).then_some(target)
     }
 }
 
@@ -285,6 +357,7 @@ where
 
         match &mut self.state {
             State::Idle => {
+                self.chunk_start = self.cur;
                 self.state = if self.offset.is_none() {
                     // Offset is none means we are doing tailing reading.
                     // we should stat first to get the correct offset.
@@ -317,26 +390,46 @@ where
                     err
                 })?;
 
-                self.ensure_size(rp.range().unwrap_or_default().size(), rp.size());
+                self.ensure_size_by_read(&rp);
 
+                self.skip = 0;
                 self.state = State::Read(r);
                 self.poll_read(cx, buf)
             }
-            State::Read(r) => match ready!(Pin::new(r).poll_read(cx, buf)) {
-                Ok(0) => {
-                    // Reset state to Idle after all data has been consumed.
-                    self.state = State::Idle;
-                    Poll::Ready(Ok(0))
-                }
-                Ok(n) => {
-                    self.cur += n as u64;
-                    Poll::Ready(Ok(n))
+            State::Read(r) => {
+                // Drop the bytes skipped by merged seeks first.
+                while self.skip > 0 {
+                    let size = self.skip.min(buf.len() as u64) as usize;
+                    match ready!(Pin::new(&mut *r).poll_read(cx, &mut buf[..size])) {
+                        Ok(n) if n > 0 => self.skip -= n as u64,
+                        // Current request can't reach the position, start a new one instead.
+                        _ => {
+                            self.state = State::Idle;
+                            return self.poll_read(cx, buf);
+                        }
+                    }
                 }
-                Err(e) => {
-                    self.state = State::Idle;
-                    Poll::Ready(Err(e))
+
+                match ready!(Pin::new(r).poll_read(cx, buf)) {
+                    Ok(0) => {
+                        // Reset state to Idle after all data has been consumed.
+                        self.state = State::Idle;
+                        // Send a new read request if there are more chunks to read.
+                        if self.has_next_chunk() {
+                            return self.poll_read(cx, buf);
+                        }
+                        Poll::Ready(Ok(0))
+                    }
+                    Ok(n) => {
+                        self.cur += n as u64;
+                        Poll::Ready(Ok(n))
+                    }
+                    Err(e) => {
+                        self.state = State::Idle;
+                        Poll::Ready(Err(e))
+                    }
                 }
-            },
+            }
         }
     }
 
@@ -396,6 +489,12 @@ where
                 if pos == SeekFrom::Current(0) || pos == SeekFrom::Start(self.cur) {
                     return Poll::Ready(Ok(self.cur));
                 }
+                // Reuse current request if users seek forward within the merge gap.
+                if let Some(target) = self.merge_target(pos) {
+                    self.skip += target - self.cur;
+                    self.cur = target;
+                    return Poll::Ready(Ok(self.cur));
+                }
 
                 self.state = State::Idle;
                 self.poll_seek(cx, pos)
@@ -411,6 +510,7 @@ where
 
         match &mut self.state {
             State::Idle => {
+                self.chunk_start = self.cur;
                 self.state = if self.offset.is_none() {
                     // Offset is none means we are doing tailing reading.
                     // we should stat first to get the correct offset.
@@ -444,13 +544,24 @@ where
                 })?;
 
                 // Set size if read returns size hint.
-                self.ensure_size(rp.range().unwrap_or_default().size(), rp.size());
+                self.ensure_size_by_read(&rp);
 
+                self.skip = 0;
                 self.state = State::Read(r);
                 self.poll_next(cx)
             }
             State::Read(r) => match ready!(Pin::new(r).poll_next(cx)) {
-                Some(Ok(bs)) => {
+                Some(Ok(mut bs)) => {
+                    // Drop the bytes skipped by merged seeks first.
+                    if self.skip > 0 {
+                        let n = self.skip.min(bs.len() as u64);
+                        self.skip -= n;
+                        bs.advance(n as usize);
+                        if bs.is_empty() {
+                            return self.poll_next(cx);
+                        }
+                    }
+
                     self.cur += bs.len() as u64;
                     Poll::Ready(Some(Ok(bs)))
                 }
@@ -460,6 +571,10 @@ where
                 }
                 None => {
                     self.state = State::Idle;
+                    // Send a new read request if there are more chunks to read.
+                    if self.has_next_chunk() {
+                        return self.poll_next(cx);
+                    }
                     Poll::Ready(None)
                 }
             },
@@ -480,6 +595,7 @@ where
 
         match &mut self.state {
             State::Idle => {
+                self.chunk_start = self.cur;
                 // Offset is none means we are doing tailing reading.
                 // we should stat first to get the correct offset.
                 if self.offset.is_none() {
@@ -492,16 +608,34 @@ where
                 let (rp, r) = self.read_action()?;
 
                 // Set size if read returns size hint.
-                self.ensure_size(rp.range().unwrap_or_default().size(), rp.size());
+                self.ensure_size_by_read(&rp);
 
+                self.skip = 0;
                 self.state = State::Read(r);
                 self.read(buf)
             }
             State::Read(r) => {
+                // Drop the bytes skipped by merged seeks first.
+                while self.skip > 0 {
+                    let size = self.skip.min(buf.len() as u64) as usize;
+                    match r.read(&mut buf[..size]) {
+                        Ok(n) if n > 0 => self.skip -= n as u64,
+                        // Current request can't reach the position, start a new one instead.
+                        _ => {
+                            self.state = State::Idle;
+                            return self.read(buf);
+                        }
+                    }
+                }
+
                 match r.read(buf) {
                     Ok(0) => {
                         // Reset state to Idle after all data has been consumed.
                         self.state = State::Idle;
+                        // Send a new read request if there are more chunks to read.
+                        if self.has_next_chunk() {
+                            return self.read(buf);
+                        }
                         Ok(0)
                     }
                     Ok(n) => {
@@ -563,6 +697,12 @@ where
                 if pos == SeekFrom::Current(0) || pos == SeekFrom::Start(self.cur) {
                     return Ok(self.cur);
                 }
+                // Reuse current request if users seek forward within the merge gap.
+                if let Some(target) = self.merge_target(pos) {
+                    self.skip += target - self.cur;
+                    self.cur = target;
+                    return Ok(self.cur);
+                }
 
                 self.state = State::Idle;
                 self.seek(pos)
@@ -584,6 +724,7 @@ where
                     return None;
                 }
 
+                self.chunk_start = self.cur;
                 // Offset is none means we are doing tailing reading.
                 // we should stat first to get the correct offset.
                 if self.offset.is_none() {
@@ -600,16 +741,27 @@ where
 
                 let r = match self.read_action() {
                     Ok((rp, r)) => {
-                        self.ensure_size(rp.range().unwrap_or_default().size(), rp.size());
+                        self.ensure_size_by_read(&rp);
                         r
                     }
                     Err(err) => return Some(Err(err)),
                 };
+                self.skip = 0;
                 self.state = State::Read(r);
                 self.next()
             }
             State::Read(r) => match r.next() {
-                Some(Ok(bs)) => {
+                Some(Ok(mut bs)) => {
+                    // Drop the bytes skipped by merged seeks first.
+                    if self.skip > 0 {
+                        let n = self.skip.min(bs.len() as u64);
+                        self.skip -= n;
+                        bs.advance(n as usize);
+                        if bs.is_empty() {
+                            return self.next();
+                        }
+                    }
+
                     self.cur += bs.len() as u64;
                     Some(Ok(bs))
                 }
@@ -619,6 +771,10 @@ where
                 }
                 None => {
                     self.state = State::Idle;
+                    // Send a new read request if there are more chunks to read.
+                    if self.has_next_chunk() {
+                        return self.next();
+                    }
                     None
                 }
             },
@@ -635,6 +791,8 @@ where
 #[cfg(test)]
 mod tests {
     use std::io::SeekFrom;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
 
     use async_trait::async_trait;
     use bytes::Bytes;
@@ -661,11 +819,15 @@ mod tests {
     #[derive(Debug, Clone, Default)]
     struct MockReadService {
         data: Bytes,
+        requests: Arc<AtomicUsize>,
     }
 
     impl MockReadService {
         fn new(data: Bytes) -> Self {
-            Self { data }
+            Self {
+                data,
+                requests: Arc::default(),
+            }
         }
     }
 
@@ -689,6 +851,7 @@ mod tests {
         }
 
         async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.requests.fetch_add(1, Ordering::Relaxed);
             let bs = args.range().apply_on_bytes(self.data.clone());
 
             Ok((
@@ -771,6 +934,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_with_chunk() -> anyhow::Result<()> {
+        let (bs, _) = gen_bytes();
+        let acc = Arc::new(MockReadService::new(bs.clone()));
+
+        let mut r = Box::new(RangeReader::new(
+            acc,
+            "x",
+            OpRead::default()
+                .with_range(BytesRange::from(..))
+                .with_chunk(1024 * 1024),
+        )) as oio::Reader;
+
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(bs.len(), buf.len(), "read size");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs)),
+            format!("{:x}", Sha256::digest(&buf)),
+            "read content"
+        );
+
+        let n = r.seek(SeekFrom::Start(1024)).await?;
+        assert_eq!(n, 1024, "seek position must be 1024");
+
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(bs.len() - 1024, buf.len(), "read after seek size");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs[1024..])),
+            format!("{:x}", Sha256::digest(&buf)),
+            "read after seek content"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_part() -> anyhow::Result<()> {
         let (bs, _) = gen_bytes();
@@ -827,4 +1027,55 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_with_merge_gap() -> anyhow::Result<()> {
+        let (bs, _) = gen_bytes();
+        let acc = Arc::new(MockReadService::new(bs.clone()));
+        let requests = acc.requests.clone();
+
+        let mut r = Box::new(RangeReader::new(
+            acc,
+            "x",
+            OpRead::default()
+                .with_range(BytesRange::from(..))
+                .with_merge_gap(8192),
+        )) as oio::Reader;
+
+        let mut buf = vec![0; 1024];
+        r.read_exact(&mut buf).await?;
+        assert_eq!(&bs[..1024], &buf, "read content");
+
+        // Seek forward within merge gap should reuse current request.
+        let n = r.seek(SeekFrom::Current(1024)).await?;
+        assert_eq!(2048, n, "seek to 2048");
+        r.read_exact(&mut buf).await?;
+        assert_eq!(&bs[2048..3072], &buf, "read after seek to 2048");
+
+        let n = r.seek(SeekFrom::Start(3072 + 8192)).await?;
+        assert_eq!(3072 + 8192, n, "seek to 11264");
+        r.read_exact(&mut buf).await?;
+        assert_eq!(&bs[11264..12288], &buf, "read after seek to 11264");
+        assert_eq!(1, requests.load(Ordering::Relaxed), "requests count");
+
+        // Seek out of merge gap should start a new request.
+        let n = r.seek(SeekFrom::Start(1024 * 1024)).await?;
+        assert_eq!(1024 * 1024, n, "seek to 1MiB");
+        r.read_exact(&mut buf).await?;
+        assert_eq!(
+            &bs[1024 * 1024..1024 * 1024 + 1024],
+            &buf,
+            "read after seek to 1MiB"
+        );
+        assert_eq!(2, requests.load(Ordering::Relaxed), "requests count");
+
+        // Seek backward should start a new request.
+        let n = r.seek(SeekFrom::Start(0)).await?;
+        assert_eq!(0, n, "seek to 0");
+        r.read_exact(&mut buf).await?;
+        assert_eq!(&bs[..1024], &buf, "read after seek to 0");
+        assert_eq!(3, requests.load(Ordering::Relaxed), "requests count");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,236 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::Future;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// ResumeReader will read the remaining range again if the inner reader returns
+/// less data than the resolved range.
+///
+/// # Notes
+///
+/// - The range of `OpRead` must have been resolved against the content length.
+/// - The remaining range will be read with `If-Match` set to the given etag if
+///   the service supports it, so the content won't be mixed up if the file has
+///   been changed in between.
+/// - Resuming will stop if a new request returns nothing. If `verify_length` is
+///   enabled, [`ErrorKind::Unexpected`] will be returned if the content is still
+///   short or longer than expected.
+/// - Seek will disable resuming since we can't track the position anymore, and
+///   seeking after a resume is not supported.
+pub struct ResumeReader {
+    acc: FusedAccessor,
+    path: Arc<String>,
+    op: OpRead,
+    offset: u64,
+    size: u64,
+    verify_length: bool,
+
+    /// The size of bytes that have been read.
+    read: u64,
+    /// The position where current read request starts.
+    start: u64,
+    /// Resuming will be disabled after seeked.
+    enabled: bool,
+    state: State,
+}
+
+enum State {
+    /// The remaining range should be read again.
+    Idle,
+    Read(oio::Reader),
+    SendRead(BoxedFuture<Result<(RpRead, oio::Reader)>>),
+}
+
+/// # Safety
+///
+/// wasm32 is a special target that we only have one event-loop for this state.
+unsafe impl Send for State {}
+/// Safety: State will only be accessed under &mut.
+unsafe impl Sync for State {}
+
+impl ResumeReader {
+    /// Create a new resume reader.
+    ///
+    /// `inner` is the reader returned by reading `op`, and `range` is the range of
+    /// `op` resolved against the content length.
+    pub fn new(
+        acc: FusedAccessor,
+        path: &str,
+        op: OpRead,
+        inner: oio::Reader,
+        range: BytesRange,
+        etag: Option<&str>,
+    ) -> Self {
+        let mut op = op;
+        if let Some(etag) = etag {
+            if op.if_match().is_none() && acc.info().full_capability().read_with_if_match {
+                op = op.with_if_match(etag);
+            }
+        }
+        let verify_length = op.verify_length();
+
+        Self {
+            acc,
+            path: Arc::new(path.to_string()),
+            op,
+            offset: range.offset().unwrap_or_default(),
+            size: range.size().unwrap_or_default(),
+            verify_length,
+
+            read: 0,
+            start: 0,
+            enabled: true,
+            state: State::Read(inner),
+        }
+    }
+
+    /// Check if we should read the remaining range again.
+    ///
+    /// We will stop if current request returns nothing to avoid endless requests.
+    fn should_resume(&self) -> bool {
+        self.enabled
+            && self.read < self.size
+            && self.read > self.start
+            && self.acc.info().full_capability().read_with_range
+    }
+
+    fn read_future(&mut self) -> BoxedFuture<Result<(RpRead, oio::Reader)>> {
+        self.start = self.read;
+
+        let acc = self.acc.clone();
+        let path = self.path.clone();
+        let op = self.op.clone().with_range(BytesRange::new(
+            Some(self.offset + self.read),
+            Some(self.size - self.read),
+        ));
+
+        Box::pin(async move { acc.read(&path, op).await })
+    }
+
+    fn check_length(&self) -> Result<()> {
+        if !self.enabled || !self.verify_length || self.read == self.size {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorKind::Unexpected,
+            &format!(
+                "read got unexpected length, expect: {}, actual: {}",
+                self.size, self.read
+            ),
+        )
+        .with_operation("Reader::read")
+        .with_context("service", self.acc.info().scheme())
+        .with_context("path", self.path.as_str()))
+    }
+}
+
+impl oio::Read for ResumeReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        loop {
+            match &mut self.state {
+                State::Read(r) => {
+                    let n = ready!(r.poll_read(cx, buf))?;
+                    if n > 0 || buf.is_empty() {
+                        self.read += n as u64;
+                        return Poll::Ready(Ok(n));
+                    }
+                    if !self.should_resume() {
+                        return Poll::Ready(self.check_length().map(|_| 0));
+                    }
+                    self.state = State::Idle;
+                }
+                State::Idle => self.state = State::SendRead(self.read_future()),
+                State::SendRead(fut) => {
+                    let (_, r) = ready!(Pin::new(fut).poll(cx)).map_err(|err| {
+                        // If read future returns an error, we should reset
+                        // state to Idle so that we can retry it.
+                        self.state = State::Idle;
+                        err
+                    })?;
+                    self.state = State::Read(r);
+                }
+            }
+        }
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        if self.start > 0 {
+            return Poll::Ready(Err(Error::new(
+                ErrorKind::Unsupported,
+                "seek is not supported after resuming a short read",
+            )
+            .with_operation("Reader::seek")
+            .with_context("path", self.path.as_str())));
+        }
+
+        match &mut self.state {
+            State::Read(r) => {
+                let n = ready!(r.poll_seek(cx, pos))?;
+                self.enabled = false;
+                Poll::Ready(Ok(n))
+            }
+            State::Idle | State::SendRead(_) => {
+                unreachable!("resume must not happen before start moved")
+            }
+        }
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        loop {
+            match &mut self.state {
+                State::Read(r) => match ready!(r.poll_next(cx)) {
+                    Some(Ok(bs)) => {
+                        self.read += bs.len() as u64;
+                        return Poll::Ready(Some(Ok(bs)));
+                    }
+                    Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                    None => {
+                        if !self.should_resume() {
+                            return match self.check_length() {
+                                Ok(()) => Poll::Ready(None),
+                                Err(err) => Poll::Ready(Some(Err(err))),
+                            };
+                        }
+                        self.state = State::Idle;
+                    }
+                },
+                State::Idle => self.state = State::SendRead(self.read_future()),
+                State::SendRead(fut) => match ready!(Pin::new(fut).poll(cx)) {
+                    Ok((_, r)) => self.state = State::Read(r),
+                    Err(err) => {
+                        // If read future returns an error, we should reset
+                        // state to Idle so that we can retry it.
+                        self.state = State::Idle;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+            }
+        }
+    }
+}
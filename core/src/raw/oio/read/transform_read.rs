@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp;
+use std::io::SeekFrom;
+use std::mem;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// The size of buffer used to read from inner reader.
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// TransformReader will apply the [`Transform`] to the content read from
+/// inner reader.
+///
+/// # Notes
+///
+/// Seek is not supported since the transform is stateful.
+pub struct TransformReader<R> {
+    inner: R,
+    transform: Box<dyn Transform>,
+
+    /// buf holds the transformed bytes that haven't been returned.
+    buf: Bytes,
+    read_buf: Vec<u8>,
+    finished: bool,
+}
+
+impl<R> TransformReader<R> {
+    /// Create a new transform reader.
+    pub fn new(inner: R, transform: Box<dyn Transform>) -> Self {
+        Self {
+            inner,
+            transform,
+            buf: Bytes::new(),
+            read_buf: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for TransformReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        loop {
+            if !self.buf.is_empty() {
+                let n = cmp::min(buf.len(), self.buf.len());
+                buf[..n].copy_from_slice(&self.buf[..n]);
+                self.buf.advance(n);
+                return Poll::Ready(Ok(n));
+            }
+            if self.finished {
+                return Poll::Ready(Ok(0));
+            }
+
+            if self.read_buf.is_empty() {
+                self.read_buf = vec![0; DEFAULT_BUFFER_SIZE];
+            }
+            let n = ready!(self.inner.poll_read(cx, &mut self.read_buf))?;
+            self.buf = if n == 0 {
+                self.finished = true;
+                self.transform.finish()?
+            } else {
+                self.transform.transform(&self.read_buf[..n])?
+            };
+        }
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let (_, _) = (cx, pos);
+
+        Poll::Ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "TransformReader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        loop {
+            if !self.buf.is_empty() {
+                return Poll::Ready(Some(Ok(mem::take(&mut self.buf))));
+            }
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            let res = match ready!(self.inner.poll_next(cx)) {
+                Some(Ok(bs)) => self.transform.transform(&bs),
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => {
+                    self.finished = true;
+                    self.transform.finish()
+                }
+            };
+            match res {
+                Ok(bs) => self.buf = bs,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::oio::ReadExt;
+
+    /// Upper case all bytes and append a trailing `!` while finishing.
+    struct Shout;
+
+    impl Transform for Shout {
+        fn transform(&mut self, chunk: &[u8]) -> Result<Bytes> {
+            Ok(Bytes::from(chunk.to_ascii_uppercase()))
+        }
+
+        fn finish(&mut self) -> Result<Bytes> {
+            Ok(Bytes::from_static(b"!"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transform_reader() -> Result<()> {
+        let mut r =
+            TransformReader::new(oio::Cursor::from(b"hello, world".to_vec()), Box::new(Shout));
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"HELLO, WORLD!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transform_reader_next() -> Result<()> {
+        let mut r =
+            TransformReader::new(oio::Cursor::from(b"hello, world".to_vec()), Box::new(Shout));
+        let mut buf = Vec::new();
+        while let Some(bs) = r.next().await {
+            buf.extend_from_slice(&bs?);
+        }
+        assert_eq!(buf, b"HELLO, WORLD!");
+        Ok(())
+    }
+}
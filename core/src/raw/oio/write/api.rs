@@ -91,6 +91,24 @@ pub trait Write: Unpin + Send + Sync {
 
     /// Abort the pending writer.
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>>;
+
+    /// Get the metadata of the written file.
+    ///
+    /// This function should only be called after `poll_close` returns `Ok(())`.
+    /// Services that can't report the written file will return a metadata with
+    /// only mode set.
+    fn metadata(&self) -> Metadata {
+        Metadata::new(EntryMode::FILE)
+    }
+
+    /// Get the state of the in-progress multipart upload.
+    ///
+    /// The returning state can be passed to [`OpWrite::with_resume`] to resume
+    /// the upload after this writer has been dropped. Writers that are not
+    /// based on multipart uploads or haven't started one yet will return `None`.
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        None
+    }
 }
 
 impl Write for () {
@@ -128,6 +146,14 @@ impl<T: Write + ?Sized> Write for Box<T> {
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         (**self).poll_abort(cx)
     }
+
+    fn metadata(&self) -> Metadata {
+        (**self).metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        (**self).upload_state()
+    }
 }
 
 /// Impl WriteExt for all T: Write
@@ -131,4 +131,13 @@ where
     fn poll_abort(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
         Poll::Ready(Ok(()))
     }
+
+    /// The offset will be the total size of this object after all appends.
+    fn metadata(&self) -> Metadata {
+        let mut meta = Metadata::new(EntryMode::FILE);
+        if let Some(offset) = self.offset {
+            meta.set_content_length(offset);
+        }
+        meta
+    }
 }
@@ -75,6 +75,20 @@ impl<ONE: oio::Write, TWO: oio::Write> oio::Write for TwoWaysWriter<ONE, TWO> {
             Self::Two(two) => two.poll_abort(cx),
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        match self {
+            Self::One(one) => one.metadata(),
+            Self::Two(two) => two.metadata(),
+        }
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        match self {
+            Self::One(one) => one.upload_state(),
+            Self::Two(two) => two.upload_state(),
+        }
+    }
 }
 
 /// ThreeWaysWriter is used to implement [`Write`] based on three ways.
@@ -115,4 +129,20 @@ impl<ONE: oio::Write, TWO: oio::Write, THREE: oio::Write> oio::Write
             Self::Three(three) => three.poll_abort(cx),
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        match self {
+            Self::One(one) => one.metadata(),
+            Self::Two(two) => two.metadata(),
+            Self::Three(three) => three.metadata(),
+        }
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        match self {
+            Self::One(one) => one.upload_state(),
+            Self::Two(two) => two.upload_state(),
+            Self::Three(three) => three.upload_state(),
+        }
+    }
 }
@@ -0,0 +1,225 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::write::GzipEncoder;
+use async_compression::futures::write::ZstdEncoder;
+use futures::AsyncWrite;
+
+use crate::raw::oio::WriteBuf;
+use crate::raw::*;
+use crate::*;
+
+/// CompressWriter will compress the content before writing into inner writer.
+///
+/// Compressed data is buffered by the encoder and flushed to the inner writer
+/// in chunks, the remaining data will be flushed while closing.
+pub struct CompressWriter<W: oio::Write> {
+    encoder: Encoder<W>,
+}
+
+enum Encoder<W: oio::Write> {
+    Gzip(GzipEncoder<WriteAdapter<W>>),
+    Zstd(ZstdEncoder<WriteAdapter<W>>),
+}
+
+impl<W: oio::Write> CompressWriter<W> {
+    /// Create a new compress writer.
+    ///
+    /// [`Compression::Auto`] is not supported for writing.
+    pub fn new(inner: W, compression: Compression) -> Result<Self> {
+        let w = WriteAdapter { inner };
+        let encoder = match compression {
+            Compression::Gzip => Encoder::Gzip(GzipEncoder::new(w)),
+            Compression::Zstd => Encoder::Zstd(ZstdEncoder::new(w)),
+            Compression::Auto => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "compression auto is not supported for writing",
+                ))
+            }
+        };
+
+        Ok(Self { encoder })
+    }
+
+    fn inner_ref(&self) -> &W {
+        match &self.encoder {
+            Encoder::Gzip(e) => &e.get_ref().inner,
+            Encoder::Zstd(e) => &e.get_ref().inner,
+        }
+    }
+
+    fn inner_mut(&mut self) -> &mut W {
+        match &mut self.encoder {
+            Encoder::Gzip(e) => &mut e.get_mut().inner,
+            Encoder::Zstd(e) => &mut e.get_mut().inner,
+        }
+    }
+}
+
+impl<W: oio::Write> oio::Write for CompressWriter<W> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+        let res = match &mut self.encoder {
+            Encoder::Gzip(e) => Pin::new(e).poll_write(cx, bs.chunk()),
+            Encoder::Zstd(e) => Pin::new(e).poll_write(cx, bs.chunk()),
+        };
+
+        res.map_err(|err| parse_io_error(err, oio::WriteOperation::Write))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // Encoder will finish the compressed stream and close the inner writer.
+        let res = match &mut self.encoder {
+            Encoder::Gzip(e) => Pin::new(e).poll_close(cx),
+            Encoder::Zstd(e) => Pin::new(e).poll_close(cx),
+        };
+
+        res.map_err(|err| parse_io_error(err, oio::WriteOperation::Close))
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner_mut().poll_abort(cx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        self.inner_ref().metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner_ref().upload_state()
+    }
+}
+
+/// WriteAdapter adapts [`oio::Write`] into [`AsyncWrite`] so that it can be
+/// used by encoders.
+struct WriteAdapter<W: oio::Write> {
+    inner: W,
+}
+
+impl<W: oio::Write> AsyncWrite for WriteAdapter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = ready!(self.get_mut().inner.poll_write(cx, &buf))?;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // oio::Write doesn't have flush, data will be flushed while closing.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.get_mut().inner.poll_close(cx))?;
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Convert the io error returned by encoder back, errors returned by the
+/// inner writer will be kept as is.
+fn parse_io_error(err: io::Error, op: oio::WriteOperation) -> Error {
+    if !err.get_ref().map_or(false, |v| v.is::<Error>()) {
+        return new_std_io_error(err)
+            .with_operation(op)
+            .with_context("source", "CompressWriter");
+    }
+
+    let inner = err.into_inner().expect("inner error must exist");
+    *inner
+        .downcast::<Error>()
+        .expect("inner error must be opendal error")
+}
+
+#[cfg(test)]
+mod tests {
+    use async_compression::futures::bufread::GzipDecoder;
+    use bytes::Buf;
+    use bytes::Bytes;
+    use futures::AsyncReadExt;
+    use rand::thread_rng;
+    use rand::RngCore;
+
+    use super::*;
+    use crate::raw::oio::WriteExt;
+
+    struct MockWriter {
+        buf: Vec<u8>,
+        closed: bool,
+    }
+
+    impl oio::Write for MockWriter {
+        fn poll_write(&mut self, _: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+            self.buf.extend_from_slice(bs.chunk());
+            Poll::Ready(Ok(bs.chunk().len()))
+        }
+
+        fn poll_abort(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+            self.closed = true;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compress_writer_gzip() -> Result<()> {
+        let mut rng = thread_rng();
+        let mut content = vec![0; 256 * 1024];
+        rng.fill_bytes(&mut content);
+
+        let inner = MockWriter {
+            buf: vec![],
+            closed: false,
+        };
+        let mut w = CompressWriter::new(inner, Compression::Gzip)?;
+        for chunk in content.chunks(4096) {
+            let mut bs = Bytes::copy_from_slice(chunk);
+            while !bs.is_empty() {
+                let n = w.write(&bs).await?;
+                bs.advance(n);
+            }
+        }
+        w.close().await?;
+        assert!(w.inner_mut().closed, "inner writer must be closed");
+
+        let mut decoder = GzipDecoder::new(w.inner_mut().buf.as_slice());
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, content);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_writer_auto() {
+        let inner = MockWriter {
+            buf: vec![],
+            closed: false,
+        };
+        let res = CompressWriter::new(inner, Compression::Auto);
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+    }
+}
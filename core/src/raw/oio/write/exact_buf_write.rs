@@ -18,6 +18,8 @@
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::raw::oio::WriteBuf;
 use crate::raw::*;
@@ -40,6 +42,14 @@ pub struct ExactBufWriter<W: oio::Write> {
     /// The size for buffer, we will flush the underlying storage at the size of this buffer.
     buffer_size: usize,
     buffer: oio::ChunkedBytes,
+
+    /// Flush the buffer if it has not been flushed for `flush_interval` and has at least
+    /// `flush_min_size` bytes, even if it's not full yet.
+    flush_interval: Option<Duration>,
+    flush_min_size: usize,
+    /// `last_flush` will be initialized lazily to avoid calling `Instant::now` if
+    /// flush interval is not set.
+    last_flush: Option<Instant>,
 }
 
 impl<W: oio::Write> ExactBufWriter<W> {
@@ -49,15 +59,44 @@ impl<W: oio::Write> ExactBufWriter<W> {
             inner,
             buffer_size,
             buffer: oio::ChunkedBytes::default(),
+
+            flush_interval: None,
+            flush_min_size: 0,
+            last_flush: None,
         }
     }
+
+    /// Flush the buffered data every `interval` even if the buffer is not full.
+    ///
+    /// The flush is checked while writing, so buffered data will be flushed on the
+    /// next write after `interval`. Buffer smaller than `min_size` will never be
+    /// flushed early.
+    pub fn with_flush_interval(mut self, interval: Duration, min_size: usize) -> Self {
+        self.flush_interval = Some(interval);
+        self.flush_min_size = min_size;
+        self
+    }
+
+    fn flush_due(&mut self) -> bool {
+        let Some(interval) = self.flush_interval else {
+            return false;
+        };
+        let last_flush = *self.last_flush.get_or_insert_with(Instant::now);
+
+        !self.buffer.is_empty()
+            && self.buffer.len() >= self.flush_min_size
+            && last_flush.elapsed() >= interval
+    }
 }
 
 impl<W: oio::Write> oio::Write for ExactBufWriter<W> {
     fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
-        if self.buffer.len() >= self.buffer_size {
+        if self.buffer.len() >= self.buffer_size || self.flush_due() {
             let written = ready!(self.inner.poll_write(cx, &self.buffer)?);
             self.buffer.advance(written);
+            if self.flush_interval.is_some() {
+                self.last_flush = Some(Instant::now());
+            }
         }
 
         let remaining = self.buffer_size - self.buffer.len();
@@ -78,6 +117,14 @@ impl<W: oio::Write> oio::Write for ExactBufWriter<W> {
 
         self.inner.poll_close(cx)
     }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
 }
 
 #[cfg(test)]
@@ -149,6 +196,54 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_exact_buf_writer_flush_interval() -> Result<()> {
+        let _ = tracing_subscriber::fmt()
+            .pretty()
+            .with_test_writer()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .try_init();
+
+        let mut w = ExactBufWriter::new(MockWriter { buf: vec![] }, 100)
+            .with_flush_interval(Duration::ZERO, 0);
+
+        let n = w.write(&Bytes::from(vec![1; 5])).await?;
+        assert_eq!(n, 5);
+        assert!(w.inner.buf.is_empty(), "nothing flushed yet");
+
+        // Buffer is far from full, but it should be flushed since interval passed.
+        let n = w.write(&Bytes::from(vec![2; 5])).await?;
+        assert_eq!(n, 5);
+        assert_eq!(w.inner.buf, vec![1u8; 5]);
+
+        w.close().await?;
+        assert_eq!(w.inner.buf.len(), 10);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exact_buf_writer_flush_interval_min_size() -> Result<()> {
+        let _ = tracing_subscriber::fmt()
+            .pretty()
+            .with_test_writer()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .try_init();
+
+        let mut w = ExactBufWriter::new(MockWriter { buf: vec![] }, 100)
+            .with_flush_interval(Duration::ZERO, 8);
+
+        w.write(&Bytes::from(vec![1; 5])).await?;
+        w.write(&Bytes::from(vec![2; 5])).await?;
+        assert!(w.inner.buf.is_empty(), "buffer smaller than min size");
+
+        w.write(&Bytes::from(vec![3; 5])).await?;
+        assert_eq!(w.inner.buf.len(), 10);
+
+        w.close().await?;
+        assert_eq!(w.inner.buf.len(), 15);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_fuzz_exact_buf_writer() -> Result<()> {
         let _ = tracing_subscriber::fmt()
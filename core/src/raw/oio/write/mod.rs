@@ -29,6 +29,7 @@ pub use compose_write::TwoWaysWriter;
 
 mod multipart_upload_write;
 pub use multipart_upload_write::MultipartUploadPart;
+pub use multipart_upload_write::MultipartUploadState;
 pub use multipart_upload_write::MultipartUploadWrite;
 pub use multipart_upload_write::MultipartUploadWriter;
 
@@ -46,3 +47,14 @@ pub use exact_buf_write::ExactBufWriter;
 mod range_write;
 pub use range_write::RangeWrite;
 pub use range_write::RangeWriter;
+
+mod progress_write;
+pub use progress_write::ProgressWriter;
+
+mod transform_write;
+pub use transform_write::TransformWriter;
+
+#[cfg(feature = "compress")]
+mod compress_write;
+#[cfg(feature = "compress")]
+pub use compress_write::CompressWriter;
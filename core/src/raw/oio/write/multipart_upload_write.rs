@@ -15,12 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cmp;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
 use async_trait::async_trait;
+use futures::StreamExt;
 
 use crate::raw::*;
 use crate::*;
@@ -55,7 +58,10 @@ pub trait MultipartUploadWrite: Send + Sync + Unpin + 'static {
     /// MultipartUploadWriter will call this API when:
     ///
     /// - All the data has been written to the buffer and we can perform the upload at once.
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()>;
+    ///
+    /// The returning metadata should carry the information reported by the service,
+    /// like etag and version.
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata>;
 
     /// initiate_part will call start a multipart upload and return the upload id.
     ///
@@ -81,9 +87,41 @@ pub trait MultipartUploadWrite: Send + Sync + Unpin + 'static {
         body: AsyncBody,
     ) -> Result<MultipartUploadPart>;
 
+    /// write_part_with_md5 is the same as [`MultipartUploadWrite::write_part`] but
+    /// sends the content md5 along with the part, so that services can reject
+    /// the corrupted part.
+    ///
+    /// MultipartUploadWriter will call this API instead of `write_part` while
+    /// `verify_parts` is enabled.
+    ///
+    /// - content_md5 is the base64 encoded md5 of this part.
+    ///
+    /// Services that support `write_with_verify_parts` must implement this API,
+    /// it returns `Unsupported` by default.
+    async fn write_part_with_md5(
+        &self,
+        _upload_id: &str,
+        _part_number: usize,
+        _size: u64,
+        _content_md5: &str,
+        _body: AsyncBody,
+    ) -> Result<MultipartUploadPart> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "multipart upload doesn't support writing part with content md5",
+        ))
+    }
+
     /// complete_part will complete the multipart upload to build the final
     /// file.
-    async fn complete_part(&self, upload_id: &str, parts: &[MultipartUploadPart]) -> Result<()>;
+    ///
+    /// The returning metadata should carry the information reported by the service
+    /// for the final file, like etag and version.
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[MultipartUploadPart],
+    ) -> Result<Metadata>;
 
     /// abort_part will cancel the multipart upload and purge all data.
     async fn abort_part(&self, upload_id: &str) -> Result<()>;
@@ -95,7 +133,7 @@ pub trait MultipartUploadWrite: Send + Sync + Unpin + 'static {
 ///
 /// - `part_number` is the index of the part, starting from 0.
 /// - `etag` is the `ETag` of the part.
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MultipartUploadPart {
     /// The number of the part, starting from 0.
     pub part_number: usize,
@@ -103,42 +141,167 @@ pub struct MultipartUploadPart {
     pub etag: String,
 }
 
+/// The state of an in-progress multipart upload.
+///
+/// It's returned by [`oio::Write::upload_state`] and can be used to resume the
+/// upload via [`MultipartUploadWriter::with_resume`].
+///
+/// - `upload_id` is the id returned by [`MultipartUploadWrite::initiate_part`].
+/// - `parts` are the completed parts numbered from 0 without gaps, so the
+///   upload should be resumed with the data right after them.
+/// - `uploaded_bytes` is the total size of `parts`, which is the offset of the
+///   data to resume with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultipartUploadState {
+    /// The id of the multipart upload.
+    pub upload_id: String,
+    /// The completed parts in order.
+    pub parts: Vec<MultipartUploadPart>,
+    /// The total size of the completed parts.
+    pub uploaded_bytes: u64,
+}
+
 /// MultipartUploadWriter will implements [`Write`] based on multipart
 /// uploads.
+///
+/// ## Concurrent
+///
+/// MultipartUploadWriter can upload at most `concurrent` parts at the same time.
+/// Parts will be uploaded in background while users keep writing, and `close`
+/// will wait for all in-flight parts before completing the upload.
 pub struct MultipartUploadWriter<W: MultipartUploadWrite> {
-    state: State<W>,
+    state: State,
+    w: Arc<W>,
 
     cache: Option<oio::ChunkedBytes>,
     upload_id: Option<Arc<String>>,
     parts: Vec<MultipartUploadPart>,
+    /// The size of uploaded parts by part number, parts resumed from the
+    /// previous upload are not included.
+    part_sizes: HashMap<usize, u64>,
+    /// The number and total size of parts resumed from the previous upload.
+    resumed_parts: (usize, u64),
+    /// The next part number to upload.
+    next_part_number: usize,
+    /// The part that failed to upload, we will retry it before uploading new parts.
+    retry: Option<(usize, oio::ChunkedBytes)>,
+    futures: ConcurrentFutures<WritePartFuture>,
+    /// The total size of data that has been accepted by this writer.
+    written: u64,
+    /// Whether this writer continues a multipart upload started before, the
+    /// size of data uploaded before is unknown in this case.
+    resumed: bool,
+    /// Whether to send the content md5 along with every part.
+    verify_parts: bool,
+    metadata: Option<Metadata>,
 }
 
-enum State<W> {
-    Idle(Option<W>),
-    Init(BoxedFuture<(W, Result<String>)>),
-    Write(BoxedFuture<(W, Result<MultipartUploadPart>)>),
-    Close(BoxedFuture<(W, Result<()>)>),
-    Abort(BoxedFuture<(W, Result<()>)>),
+/// WritePartFuture is the future of uploading a part.
+///
+/// We will return the part number and content along with the result so that we
+/// can retry it if failed.
+type WritePartFuture = BoxedFuture<(usize, oio::ChunkedBytes, Result<MultipartUploadPart>)>;
+
+enum State {
+    Idle,
+    Init(BoxedFuture<Result<String>>),
+    Close(BoxedFuture<Result<Metadata>>),
+    Abort(BoxedFuture<Result<()>>),
 }
 
 /// # Safety
 ///
-/// wasm32 is a special target that we only have one event-loop for this state.
-unsafe impl<S: MultipartUploadWrite> Send for State<S> {}
+/// wasm32 is a special target that we only have one event-loop for this writer.
+unsafe impl<W: MultipartUploadWrite> Send for MultipartUploadWriter<W> {}
 /// # Safety
 ///
-/// We will only take `&mut Self` reference for State.
-unsafe impl<S: MultipartUploadWrite> Sync for State<S> {}
+/// We will only take `&mut Self` reference for MultipartUploadWriter.
+unsafe impl<W: MultipartUploadWrite> Sync for MultipartUploadWriter<W> {}
 
 impl<W: MultipartUploadWrite> MultipartUploadWriter<W> {
     /// Create a new MultipartUploadWriter.
-    pub fn new(inner: W) -> Self {
+    ///
+    /// At most `concurrent` parts will be uploaded at the same time, `concurrent`
+    /// less than 1 will be treated as 1.
+    pub fn new(inner: W, concurrent: usize) -> Self {
         Self {
-            state: State::Idle(Some(inner)),
+            state: State::Idle,
+            w: Arc::new(inner),
 
             cache: None,
             upload_id: None,
             parts: Vec::new(),
+            part_sizes: HashMap::new(),
+            resumed_parts: (0, 0),
+            next_part_number: 0,
+            retry: None,
+            futures: ConcurrentFutures::new(cmp::max(1, concurrent)),
+            written: 0,
+            resumed: false,
+            verify_parts: false,
+            metadata: None,
+        }
+    }
+
+    /// Send the content md5 along with every part so that services can
+    /// reject the corrupted part.
+    pub fn with_verify_parts(mut self, verify_parts: bool) -> Self {
+        self.verify_parts = verify_parts;
+        self
+    }
+
+    /// Resume the multipart upload with given state.
+    ///
+    /// The parts in state will be kept as is and new parts will be numbered
+    /// after them. `None` means starting a new upload.
+    pub fn with_resume(mut self, state: Option<oio::MultipartUploadState>) -> Self {
+        if let Some(state) = state {
+            self.upload_id = Some(Arc::new(state.upload_id));
+            self.next_part_number = state.parts.len();
+            self.resumed_parts = (state.parts.len(), state.uploaded_bytes);
+            self.parts = state.parts;
+            self.resumed = true;
+        }
+        self
+    }
+
+    /// Push a part into the concurrent futures to upload it in background.
+    fn push_part(&mut self, upload_id: Arc<String>, part_number: usize, bs: oio::ChunkedBytes) {
+        let w = self.w.clone();
+        let content_md5 = self
+            .verify_parts
+            .then(|| format_content_md5_vectored(&oio::WriteBuf::vectored_chunk(&bs)));
+        self.futures.push(Box::pin(async move {
+            let size = bs.len() as u64;
+            let body = AsyncBody::ChunkedBytes(bs.clone());
+            let part = match content_md5 {
+                Some(md5) => {
+                    w.write_part_with_md5(&upload_id, part_number, size, &md5, body)
+                        .await
+                }
+                None => w.write_part(&upload_id, part_number, size, body).await,
+            };
+
+            (part_number, bs, part)
+        }));
+    }
+
+    /// Poll the next finished part.
+    ///
+    /// Returns `Ok(())` if there is no in-flight part. The failed part will be
+    /// stored and retried while next uploading.
+    fn poll_next_part(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        match ready!(self.futures.poll_next_unpin(cx)) {
+            Some((_, bs, Ok(part))) => {
+                self.part_sizes.insert(part.part_number, bs.len() as u64);
+                self.parts.push(part);
+                Poll::Ready(Ok(()))
+            }
+            Some((part_number, bs, Err(err))) => {
+                self.retry = Some((part_number, bs));
+                Poll::Ready(Err(err))
+            }
+            None => Poll::Ready(Ok(())),
         }
     }
 }
@@ -150,27 +313,32 @@ where
     fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
         loop {
             match &mut self.state {
-                State::Idle(w) => {
-                    match self.upload_id.as_ref() {
+                State::Idle => {
+                    match self.upload_id.clone() {
                         Some(upload_id) => {
-                            let upload_id = upload_id.clone();
-                            let part_number = self.parts.len();
-
-                            let bs = self.cache.clone().expect("cache must be valid").clone();
-                            let w = w.take().expect("writer must be valid");
-                            self.state = State::Write(Box::pin(async move {
-                                let size = bs.len();
-                                let part = w
-                                    .write_part(
-                                        &upload_id,
-                                        part_number,
-                                        size as u64,
-                                        AsyncBody::ChunkedBytes(bs),
-                                    )
-                                    .await;
-
-                                (w, part)
-                            }));
+                            if !self.futures.has_remaining() {
+                                ready!(self.poll_next_part(cx))?;
+                                continue;
+                            }
+
+                            // Retry the failed part before uploading new parts.
+                            if let Some((part_number, cache)) = self.retry.take() {
+                                self.push_part(upload_id, part_number, cache);
+                                continue;
+                            }
+
+                            // The cache could be empty if this upload is resumed.
+                            if let Some(cache) = self.cache.take() {
+                                let part_number = self.next_part_number;
+                                self.next_part_number += 1;
+                                self.push_part(upload_id, part_number, cache);
+                            }
+
+                            let size = bs.remaining();
+                            let cb = oio::ChunkedBytes::from_vec(bs.vectored_bytes(size));
+                            self.cache = Some(cb);
+                            self.written += size as u64;
+                            return Poll::Ready(Ok(size));
                         }
                         None => {
                             // Fill cache with the first write.
@@ -178,33 +346,21 @@ where
                                 let size = bs.remaining();
                                 let cb = oio::ChunkedBytes::from_vec(bs.vectored_bytes(size));
                                 self.cache = Some(cb);
+                                self.written += size as u64;
                                 return Poll::Ready(Ok(size));
                             }
 
-                            let w = w.take().expect("writer must be valid");
-                            self.state = State::Init(Box::pin(async move {
-                                let upload_id = w.initiate_part().await;
-                                (w, upload_id)
-                            }));
+                            let w = self.w.clone();
+                            self.state =
+                                State::Init(Box::pin(async move { w.initiate_part().await }));
                         }
                     }
                 }
                 State::Init(fut) => {
-                    let (w, upload_id) = ready!(fut.as_mut().poll(cx));
-                    self.state = State::Idle(Some(w));
+                    let upload_id = ready!(fut.as_mut().poll(cx));
+                    self.state = State::Idle;
                     self.upload_id = Some(Arc::new(upload_id?));
                 }
-                State::Write(fut) => {
-                    let (w, part) = ready!(fut.as_mut().poll(cx));
-                    self.state = State::Idle(Some(w));
-                    self.parts.push(part?);
-
-                    // Replace the cache when last write succeeded
-                    let size = bs.remaining();
-                    let cb = oio::ChunkedBytes::from_vec(bs.vectored_bytes(size));
-                    self.cache = Some(cb);
-                    return Poll::Ready(Ok(size));
-                }
                 State::Close(_) => {
                     unreachable!(
                         "MultipartUploadWriter must not go into State::Close during poll_write"
@@ -222,73 +378,75 @@ where
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         loop {
             match &mut self.state {
-                State::Idle(w) => {
-                    let w = w.take().expect("writer must be valid");
+                State::Idle => {
+                    let w = self.w.clone();
                     match self.upload_id.clone() {
                         Some(upload_id) => {
-                            let parts = self.parts.clone();
-                            match self.cache.clone() {
-                                Some(bs) => {
-                                    let upload_id = upload_id.clone();
-                                    self.state = State::Write(Box::pin(async move {
-                                        let size = bs.len();
-                                        let part = w
-                                            .write_part(
-                                                &upload_id,
-                                                parts.len(),
-                                                size as u64,
-                                                AsyncBody::ChunkedBytes(bs),
-                                            )
-                                            .await;
-                                        (w, part)
-                                    }));
+                            if self.retry.is_some() || self.cache.is_some() {
+                                if !self.futures.has_remaining() {
+                                    ready!(self.poll_next_part(cx))?;
+                                    continue;
                                 }
-                                None => {
-                                    self.state = State::Close(Box::pin(async move {
-                                        let res = w.complete_part(&upload_id, &parts).await;
-                                        (w, res)
-                                    }));
+
+                                if let Some((part_number, cache)) = self.retry.take() {
+                                    self.push_part(upload_id, part_number, cache);
+                                    continue;
                                 }
+
+                                let part_number = self.next_part_number;
+                                self.next_part_number += 1;
+                                let cache = self.cache.take().expect("cache must be valid");
+                                self.push_part(upload_id, part_number, cache);
+                                continue;
+                            }
+
+                            // Wait for all in-flight parts.
+                            if !self.futures.is_empty() {
+                                ready!(self.poll_next_part(cx))?;
+                                continue;
                             }
+
+                            // Parts could be out of order if some of them have been retried.
+                            self.parts.sort_by_key(|p| p.part_number);
+                            let parts = self.parts.clone();
+                            self.state = State::Close(Box::pin(async move {
+                                w.complete_part(&upload_id, &parts).await
+                            }));
                         }
                         None => match self.cache.clone() {
                             Some(bs) => {
                                 self.state = State::Close(Box::pin(async move {
                                     let size = bs.len();
-                                    let res = w
-                                        .write_once(size as u64, AsyncBody::ChunkedBytes(bs))
-                                        .await;
-                                    (w, res)
+                                    w.write_once(size as u64, AsyncBody::ChunkedBytes(bs)).await
                                 }));
                             }
                             None => {
                                 // Call write_once if there is no data in cache and no upload_id.
                                 self.state = State::Close(Box::pin(async move {
-                                    let res = w.write_once(0, AsyncBody::Empty).await;
-                                    (w, res)
+                                    w.write_once(0, AsyncBody::Empty).await
                                 }));
                             }
                         },
                     }
                 }
                 State::Close(fut) => {
-                    let (w, res) = futures::ready!(fut.as_mut().poll(cx));
-                    self.state = State::Idle(Some(w));
+                    let res = futures::ready!(fut.as_mut().poll(cx));
+                    self.state = State::Idle;
                     // We should check res first before clean up cache.
-                    res?;
+                    let mut meta = res?;
 
+                    // Fill content length with the total size we have written if
+                    // service doesn't return it.
+                    if !self.resumed && !meta.contains_metakey(Metakey::ContentLength) {
+                        meta.set_content_length(self.written);
+                    }
+                    self.metadata = Some(meta);
                     self.cache = None;
                     return Poll::Ready(Ok(()));
                 }
                 State::Init(_) => unreachable!(
                     "MultipartUploadWriter must not go into State::Init during poll_close"
                 ),
-                State::Write(fut) => {
-                    let (w, part) = ready!(fut.as_mut().poll(cx));
-                    self.state = State::Idle(Some(w));
-                    self.parts.push(part?);
-                    self.cache = None;
-                }
                 State::Abort(_) => unreachable!(
                     "MultipartUploadWriter must not go into State::Abort during poll_close"
                 ),
@@ -296,17 +454,56 @@ where
         }
     }
 
+    fn metadata(&self) -> Metadata {
+        self.metadata
+            .clone()
+            .unwrap_or_else(|| Metadata::new(EntryMode::FILE))
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        let upload_id = self.upload_id.as_ref()?;
+
+        // Parts could finish out of order, only the parts without gaps can be
+        // resumed.
+        let mut parts = self.parts.clone();
+        parts.sort_by_key(|p| p.part_number);
+        let completed = parts
+            .iter()
+            .enumerate()
+            .take_while(|(idx, p)| p.part_number == *idx)
+            .count();
+        parts.truncate(completed);
+
+        let (resumed_parts, resumed_bytes) = self.resumed_parts;
+        let uploaded_bytes = resumed_bytes
+            + parts
+                .iter()
+                .skip(resumed_parts)
+                .map(|p| self.part_sizes.get(&p.part_number).copied().unwrap_or(0))
+                .sum::<u64>();
+
+        Some(oio::MultipartUploadState {
+            upload_id: upload_id.to_string(),
+            parts,
+            uploaded_bytes,
+        })
+    }
+
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         loop {
             match &mut self.state {
-                State::Idle(w) => {
-                    let w = w.take().expect("writer must be valid");
+                State::Idle => {
+                    // Drop all in-flight parts, they are useless after abort.
+                    self.futures.clear();
+                    self.retry = None;
+
+                    let w = self.w.clone();
                     match self.upload_id.clone() {
                         Some(upload_id) => {
-                            self.state = State::Abort(Box::pin(async move {
-                                let res = w.abort_part(&upload_id).await;
-                                (w, res)
-                            }));
+                            self.state =
+                                State::Abort(Box::pin(
+                                    async move { w.abort_part(&upload_id).await },
+                                ));
                         }
                         None => {
                             self.cache = None;
@@ -315,16 +512,13 @@ where
                     }
                 }
                 State::Abort(fut) => {
-                    let (w, res) = futures::ready!(fut.as_mut().poll(cx));
-                    self.state = State::Idle(Some(w));
+                    let res = futures::ready!(fut.as_mut().poll(cx));
+                    self.state = State::Idle;
                     return Poll::Ready(res);
                 }
                 State::Init(_) => unreachable!(
                     "MultipartUploadWriter must not go into State::Init during poll_abort"
                 ),
-                State::Write(_) => unreachable!(
-                    "MultipartUploadWriter must not go into State::Write during poll_abort"
-                ),
                 State::Close(_) => unreachable!(
                     "MultipartUploadWriter must not go into State::Close during poll_abort"
                 ),
@@ -332,3 +526,166 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::raw::oio::Write;
+    use crate::raw::oio::WriteExt;
+
+    #[derive(Default)]
+    struct MockWrite {
+        /// The part number and size of uploaded parts.
+        uploaded: Mutex<Vec<(usize, u64)>>,
+        /// The content md5 of uploaded parts.
+        content_md5s: Mutex<Vec<Option<String>>>,
+        completed: Mutex<Vec<MultipartUploadPart>>,
+    }
+
+    #[async_trait]
+    impl MultipartUploadWrite for Arc<MockWrite> {
+        async fn write_once(&self, _: u64, _: AsyncBody) -> Result<Metadata> {
+            Ok(Metadata::new(EntryMode::FILE))
+        }
+
+        async fn initiate_part(&self) -> Result<String> {
+            Ok("upload".to_string())
+        }
+
+        async fn write_part(
+            &self,
+            upload_id: &str,
+            part_number: usize,
+            size: u64,
+            _: AsyncBody,
+        ) -> Result<MultipartUploadPart> {
+            assert_eq!(upload_id, "upload");
+            self.uploaded.lock().unwrap().push((part_number, size));
+            self.content_md5s.lock().unwrap().push(None);
+
+            Ok(MultipartUploadPart {
+                part_number,
+                etag: format!("etag-{part_number}"),
+            })
+        }
+
+        async fn write_part_with_md5(
+            &self,
+            upload_id: &str,
+            part_number: usize,
+            size: u64,
+            content_md5: &str,
+            _: AsyncBody,
+        ) -> Result<MultipartUploadPart> {
+            assert_eq!(upload_id, "upload");
+            self.uploaded.lock().unwrap().push((part_number, size));
+            self.content_md5s
+                .lock()
+                .unwrap()
+                .push(Some(content_md5.to_string()));
+
+            Ok(MultipartUploadPart {
+                part_number,
+                etag: format!("etag-{part_number}"),
+            })
+        }
+
+        async fn complete_part(
+            &self,
+            upload_id: &str,
+            parts: &[MultipartUploadPart],
+        ) -> Result<Metadata> {
+            assert_eq!(upload_id, "upload");
+            *self.completed.lock().unwrap() = parts.to_vec();
+
+            Ok(Metadata::new(EntryMode::FILE))
+        }
+
+        async fn abort_part(&self, _: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_upload_state() -> Result<()> {
+        let mock = Arc::new(MockWrite::default());
+        let mut w = MultipartUploadWriter::new(mock.clone(), 1);
+        assert_eq!(w.upload_state(), None);
+
+        for bs in ["a", "bb", "ccc"] {
+            w.write(&bytes::Bytes::from(bs)).await?;
+        }
+
+        // Part 0 has been uploaded while part 1 is still in-flight.
+        let state = w.upload_state().expect("upload state must exist");
+        assert_eq!(state.upload_id, "upload");
+        assert_eq!(state.uploaded_bytes, 1);
+        assert_eq!(
+            state.parts,
+            vec![MultipartUploadPart {
+                part_number: 0,
+                etag: "etag-0".to_string(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_resume() -> Result<()> {
+        let mock = Arc::new(MockWrite::default());
+        let state = oio::MultipartUploadState {
+            upload_id: "upload".to_string(),
+            parts: vec![MultipartUploadPart {
+                part_number: 0,
+                etag: "etag-0".to_string(),
+            }],
+            uploaded_bytes: 1,
+        };
+        let mut w = MultipartUploadWriter::new(mock.clone(), 1).with_resume(Some(state));
+
+        for bs in ["bb", "ccc"] {
+            w.write(&bytes::Bytes::from(bs)).await?;
+        }
+        w.close().await?;
+
+        assert_eq!(*mock.uploaded.lock().unwrap(), vec![(1, 2), (2, 3)]);
+        let completed = mock.completed.lock().unwrap();
+        assert_eq!(
+            completed.iter().map(|p| p.part_number).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_verify_parts() -> Result<()> {
+        let mock = Arc::new(MockWrite::default());
+        let mut w = MultipartUploadWriter::new(mock.clone(), 1).with_verify_parts(true);
+
+        for bs in ["a", "bb", "ccc"] {
+            w.write(&bytes::Bytes::from(bs)).await?;
+        }
+        w.close().await?;
+
+        assert_eq!(
+            *mock.content_md5s.lock().unwrap(),
+            ["a", "bb", "ccc"]
+                .iter()
+                .map(|v| Some(format_content_md5(v.as_bytes())))
+                .collect::<Vec<_>>()
+        );
+
+        let mock = Arc::new(MockWrite::default());
+        let mut w = MultipartUploadWriter::new(mock.clone(), 1);
+        for bs in ["a", "bb"] {
+            w.write(&bytes::Bytes::from(bs)).await?;
+        }
+        w.close().await?;
+        assert_eq!(*mock.content_md5s.lock().unwrap(), vec![None, None]);
+        Ok(())
+    }
+}
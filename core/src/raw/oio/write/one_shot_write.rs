@@ -36,18 +36,22 @@ pub trait OneShotWrite: Send + Sync + Unpin + 'static {
     /// write_once write all data at once.
     ///
     /// Implementations should make sure that the data is written correctly at once.
-    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<()>;
+    ///
+    /// The returning metadata should carry the information reported by the service,
+    /// like etag and version.
+    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<Metadata>;
 }
 
 /// OneShotWrite is used to implement [`Write`] based on one shot.
 pub struct OneShotWriter<W: OneShotWrite> {
     state: State<W>,
     buffer: Option<oio::ChunkedBytes>,
+    metadata: Option<Metadata>,
 }
 
 enum State<W> {
     Idle(Option<W>),
-    Write(BoxFuture<'static, (W, Result<()>)>),
+    Write(BoxFuture<'static, (W, Result<Metadata>)>),
 }
 
 /// # Safety
@@ -61,6 +65,7 @@ impl<W: OneShotWrite> OneShotWriter<W> {
         Self {
             state: State::Idle(Some(inner)),
             buffer: None,
+            metadata: None,
         }
     }
 }
@@ -114,12 +119,27 @@ impl<W: OneShotWrite> oio::Write for OneShotWriter<W> {
                 State::Write(fut) => {
                     let (w, res) = ready!(fut.as_mut().poll(cx));
                     self.state = State::Idle(Some(w));
-                    return Poll::Ready(res);
+                    let mut meta = res?;
+
+                    // Fill content length with the size we have written if
+                    // service doesn't return it.
+                    if !meta.contains_metakey(Metakey::ContentLength) {
+                        let size = self.buffer.as_ref().map(|v| v.len() as u64).unwrap_or(0);
+                        meta.set_content_length(size);
+                    }
+                    self.metadata = Some(meta);
+                    return Poll::Ready(Ok(()));
                 }
             }
         }
     }
 
+    fn metadata(&self) -> Metadata {
+        self.metadata
+            .clone()
+            .unwrap_or_else(|| Metadata::new(EntryMode::FILE))
+    }
+
     fn poll_abort(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
         self.buffer = None;
         Poll::Ready(Ok(()))
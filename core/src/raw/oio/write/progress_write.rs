@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use crate::raw::oio::WriteBuf;
+use crate::raw::*;
+use crate::*;
+
+/// ProgressWriter will call the [`ProgressCallback`] after every chunk has been
+/// accepted by the inner writer.
+///
+/// The callback will be called with the total bytes accepted so far and the
+/// total size hint if known. Zero-sized writes will not trigger the callback.
+///
+/// Bytes accepted by the inner writer could still be buffered instead of being
+/// uploaded to the service, the upload is only complete after `close` returns.
+pub struct ProgressWriter<W> {
+    inner: W,
+
+    callback: ProgressCallback,
+    written: u64,
+    total: Option<u64>,
+}
+
+impl<W> ProgressWriter<W> {
+    /// Create a new progress writer.
+    pub fn new(inner: W, callback: ProgressCallback, total: Option<u64>) -> Self {
+        Self {
+            inner,
+            callback,
+            written: 0,
+            total,
+        }
+    }
+
+    fn advance(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        self.written += n as u64;
+        self.callback.call(self.written, self.total);
+    }
+}
+
+impl<W: oio::Write> oio::Write for ProgressWriter<W> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_write(cx, bs))?;
+        self.advance(n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_close(cx)
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_abort(cx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for ProgressWriter<W> {
+    fn write(&mut self, bs: &dyn WriteBuf) -> Result<usize> {
+        let n = self.inner.write(bs)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+}
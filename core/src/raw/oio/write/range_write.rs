@@ -55,7 +55,10 @@ pub trait RangeWrite: Send + Sync + Unpin + 'static {
     /// RangeWriter will call this API when:
     ///
     /// - All the data has been written to the buffer and we can perform the upload at once.
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()>;
+    ///
+    /// The returning metadata should carry the information reported by the service,
+    /// like etag and version.
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata>;
 
     /// Initiate range the range write, the returning value is the location.
     async fn initiate_range(&self) -> Result<String>;
@@ -70,13 +73,16 @@ pub trait RangeWrite: Send + Sync + Unpin + 'static {
     ) -> Result<()>;
 
     /// complete_range will complete the range write by uploading the last chunk.
+    ///
+    /// The returning metadata should carry the information reported by the service,
+    /// like etag and version.
     async fn complete_range(
         &self,
         location: &str,
         written: u64,
         size: u64,
         body: AsyncBody,
-    ) -> Result<()>;
+    ) -> Result<Metadata>;
 
     /// abort_range will abort the range write by abort all already uploaded data.
     async fn abort_range(&self, location: &str) -> Result<()>;
@@ -87,6 +93,7 @@ pub struct RangeWriter<W: RangeWrite> {
     location: Option<String>,
     written: u64,
     buffer: Option<oio::ChunkedBytes>,
+    metadata: Option<Metadata>,
 
     state: State<W>,
 }
@@ -95,7 +102,7 @@ enum State<W> {
     Idle(Option<W>),
     Init(BoxFuture<'static, (W, Result<String>)>),
     Write(BoxFuture<'static, (W, Result<u64>)>),
-    Complete(BoxFuture<'static, (W, Result<()>)>),
+    Complete(BoxFuture<'static, (W, Result<Metadata>)>),
     Abort(BoxFuture<'static, (W, Result<()>)>),
 }
 
@@ -111,6 +118,7 @@ impl<W: RangeWrite> RangeWriter<W> {
             state: State::Idle(Some(inner)),
 
             buffer: None,
+            metadata: None,
             location: None,
             written: 0,
         }
@@ -242,7 +250,16 @@ impl<W: RangeWrite> oio::Write for RangeWriter<W> {
                 State::Complete(fut) => {
                     let (w, res) = ready!(fut.poll_unpin(cx));
                     self.state = State::Idle(Some(w));
-                    return Poll::Ready(res);
+                    let mut meta = res?;
+
+                    // Fill content length with the total size we have written if
+                    // service doesn't return it.
+                    if !meta.contains_metakey(Metakey::ContentLength) {
+                        let size = self.buffer.as_ref().map(|v| v.len() as u64).unwrap_or(0);
+                        meta.set_content_length(self.written + size);
+                    }
+                    self.metadata = Some(meta);
+                    return Poll::Ready(Ok(()));
                 }
                 State::Abort(_) => {
                     unreachable!("RangeWriter must not go into State::Abort during poll_close")
@@ -251,6 +268,12 @@ impl<W: RangeWrite> oio::Write for RangeWriter<W> {
         }
     }
 
+    fn metadata(&self) -> Metadata {
+        self.metadata
+            .clone()
+            .unwrap_or_else(|| Metadata::new(EntryMode::FILE))
+    }
+
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         loop {
             match &mut self.state {
@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+
+use crate::raw::oio::WriteBuf;
+use crate::raw::*;
+use crate::*;
+
+/// TransformWriter will apply the [`Transform`] to the content before writing
+/// into inner writer.
+///
+/// The input will be consumed as soon as it has been transformed, and the
+/// transformed bytes will be flushed into inner writer before accepting the
+/// next chunk.
+pub struct TransformWriter<W> {
+    inner: W,
+    transform: Box<dyn Transform>,
+
+    /// buf holds the transformed bytes that haven't been written into inner writer.
+    buf: Bytes,
+    finished: bool,
+}
+
+impl<W> TransformWriter<W> {
+    /// Create a new transform writer.
+    pub fn new(inner: W, transform: Box<dyn Transform>) -> Self {
+        Self {
+            inner,
+            transform,
+            buf: Bytes::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<W: oio::Write> TransformWriter<W> {
+    fn poll_flush_buf(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        while !self.buf.is_empty() {
+            let n = ready!(self.inner.poll_write(cx, &self.buf))?;
+            self.buf.advance(n);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: oio::Write> oio::Write for TransformWriter<W> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+        ready!(self.poll_flush_buf(cx))?;
+
+        let chunk = bs.chunk();
+        self.buf = self.transform.transform(chunk)?;
+        Poll::Ready(Ok(chunk.len()))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            ready!(self.poll_flush_buf(cx))?;
+            if self.finished {
+                return self.inner.poll_close(cx);
+            }
+
+            self.buf = self.transform.finish()?;
+            self.finished = true;
+        }
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.buf = Bytes::new();
+        self.inner.poll_abort(cx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        self.inner.metadata()
+    }
+
+    fn upload_state(&self) -> Option<oio::MultipartUploadState> {
+        self.inner.upload_state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::oio::WriteExt;
+
+    struct MockWriter {
+        buf: Vec<u8>,
+        closed: bool,
+    }
+
+    impl oio::Write for MockWriter {
+        fn poll_write(&mut self, _: &mut Context<'_>, bs: &dyn WriteBuf) -> Poll<Result<usize>> {
+            // Accept at most 3 bytes every time to make sure partial writes are handled.
+            let n = bs.chunk().len().min(3);
+            self.buf.extend_from_slice(&bs.chunk()[..n]);
+            Poll::Ready(Ok(n))
+        }
+
+        fn poll_abort(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+            self.closed = true;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// Upper case all bytes and append a trailing `!` while finishing.
+    struct Shout;
+
+    impl Transform for Shout {
+        fn transform(&mut self, chunk: &[u8]) -> Result<Bytes> {
+            Ok(Bytes::from(chunk.to_ascii_uppercase()))
+        }
+
+        fn finish(&mut self) -> Result<Bytes> {
+            Ok(Bytes::from_static(b"!"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transform_writer() -> Result<()> {
+        let inner = MockWriter {
+            buf: vec![],
+            closed: false,
+        };
+        let mut w = TransformWriter::new(inner, Box::new(Shout));
+        for chunk in [&b"hello, "[..], &b"world"[..]] {
+            let mut bs = Bytes::from_static(chunk);
+            while !bs.is_empty() {
+                let n = w.write(&bs).await?;
+                bs.advance(n);
+            }
+        }
+        w.close().await?;
+
+        assert!(w.inner.closed, "inner writer must be closed");
+        assert_eq!(w.inner.buf, b"HELLO, WORLD!");
+        Ok(())
+    }
+}
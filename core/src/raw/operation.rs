@@ -45,6 +45,8 @@ pub enum Operation {
     Batch,
     /// Operation for [`crate::raw::Accessor::presign`]
     Presign,
+    /// Operation for [`crate::raw::Accessor::restore`]
+    Restore,
     /// Operation for [`crate::raw::Accessor::blocking_create_dir`]
     BlockingCreateDir,
     /// Operation for [`crate::raw::Accessor::blocking_read`]
@@ -59,6 +61,10 @@ pub enum Operation {
     BlockingStat,
     /// Operation for [`crate::raw::Accessor::blocking_delete`]
     BlockingDelete,
+    /// Operation for [`crate::raw::Accessor::blocking_batch`]
+    BlockingBatch,
+    /// Operation for [`crate::raw::Accessor::blocking_presign`]
+    BlockingPresign,
     /// Operation for [`crate::raw::Accessor::blocking_list`]
     BlockingList,
 }
@@ -89,6 +95,7 @@ impl From<Operation> for &'static str {
             Operation::Delete => "delete",
             Operation::List => "list",
             Operation::Presign => "presign",
+            Operation::Restore => "restore",
             Operation::Batch => "batch",
             Operation::BlockingCreateDir => "blocking_create_dir",
             Operation::BlockingRead => "blocking_read",
@@ -97,6 +104,8 @@ impl From<Operation> for &'static str {
             Operation::BlockingRename => "blocking_rename",
             Operation::BlockingStat => "blocking_stat",
             Operation::BlockingDelete => "blocking_delete",
+            Operation::BlockingBatch => "blocking_batch",
+            Operation::BlockingPresign => "blocking_presign",
             Operation::BlockingList => "blocking_list",
         }
     }
@@ -19,24 +19,58 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
 
 use crate::raw::*;
+use crate::Compression;
 use crate::Metakey;
+use crate::Transform;
 
 /// Args for `create` operation.
 ///
 /// The path must be normalized.
 #[derive(Debug, Clone, Default)]
-pub struct OpCreateDir {}
+pub struct OpCreateDir {
+    content_type: Option<String>,
+    user_metadata: Option<HashMap<String, String>>,
+}
 
 impl OpCreateDir {
     /// Create a new `OpCreateDir`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the content type of the directory marker.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get the content type of the directory marker.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Set the user metadata of the directory marker.
+    pub fn with_user_metadata(mut self, user_metadata: HashMap<String, String>) -> Self {
+        self.user_metadata = Some(user_metadata);
+        self
+    }
+
+    /// Get the user metadata of the directory marker.
+    pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
+        self.user_metadata.as_ref()
+    }
 }
 
 /// Args for `delete` operation.
@@ -45,6 +79,7 @@ impl OpCreateDir {
 #[derive(Debug, Clone, Default)]
 pub struct OpDelete {
     version: Option<String>,
+    if_match: Option<String>,
 }
 
 impl OpDelete {
@@ -65,6 +100,94 @@ impl OpDelete {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the If-Match of this delete operation.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.into());
+        self
+    }
+
+    /// Get the If-Match of this delete operation.
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+}
+
+/// Args for `remove_all` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpRemoveAll {
+    dry_run: bool,
+}
+
+impl OpRemoveAll {
+    /// Create a new `OpRemoveAll`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the dry_run of this remove_all operation.
+    ///
+    /// If dry_run is set, remove_all will only list the paths that would be
+    /// removed without removing them.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Get the dry_run of this remove_all operation.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+/// Args for `restore` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone, Default)]
+pub struct OpRestore {
+    days: Option<u32>,
+    tier: Option<String>,
+    version: Option<String>,
+}
+
+impl OpRestore {
+    /// Create a new `OpRestore`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the days to keep the restored copy.
+    pub fn with_days(mut self, days: u32) -> Self {
+        self.days = Some(days);
+        self
+    }
+
+    /// Get the days to keep the restored copy.
+    pub fn days(&self) -> Option<u32> {
+        self.days
+    }
+
+    /// Set the tier of this restore operation.
+    pub fn with_tier(mut self, tier: &str) -> Self {
+        self.tier = Some(tier.to_string());
+        self
+    }
+
+    /// Get the tier of this restore operation.
+    pub fn tier(&self) -> Option<&str> {
+        self.tier.as_deref()
+    }
+
+    /// Change the version of this restore operation.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Get the version of this restore operation.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
 }
 
 /// Args for `list` operation.
@@ -78,6 +201,11 @@ pub struct OpList {
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     start_after: Option<String>,
+    /// The start_before is used to only return entries whose path is
+    /// lexicographically strictly before the specified key.
+    ///
+    /// Entries will be filtered at client side.
+    start_before: Option<String>,
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -85,6 +213,11 @@ pub struct OpList {
     ///
     /// Default to `false`.
     recursive: bool,
+    /// The delimiter is used to group entries into common prefixes by a custom delimiter
+    /// instead of `/`.
+    ///
+    /// Only takes effect while `recursive` is `false`.
+    delimiter: Option<char>,
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -99,6 +232,57 @@ pub struct OpList {
     /// - If this is set to > 1, the list operation will be concurrent,
     ///   and the maximum number of concurrent operations will be determined by this value.
     concurrent: usize,
+    /// The versions is used to control whether the list operation should return all versions
+    /// of objects instead of only the current ones.
+    ///
+    /// - If `false`, list operation will only list the current version of objects.
+    /// - If `true`, list operation will list all versions of objects (including delete markers).
+    ///
+    /// Default to `false`.
+    versions: bool,
+    /// The glob pattern used to filter entries.
+    ///
+    /// The pattern is matched against the path relative to the listing path
+    /// at client side.
+    pattern: Option<String>,
+    /// The limit_total is used to cap the total entries returned by this list operation.
+    ///
+    /// Unlike `limit` which controls the page size of every request, lister will stop
+    /// fetching more pages once `limit_total` entries have been returned.
+    limit_total: Option<usize>,
+    /// The sorted is used to control whether entries should be returned in sorted path order.
+    ///
+    /// Default to `false`.
+    sorted: bool,
+    /// The min_size is used to only return entries whose content length is
+    /// larger than or equal to the specified bytes.
+    ///
+    /// Entries will be filtered at client side, dirs are always returned.
+    min_size: Option<u64>,
+    /// The max_size is used to only return entries whose content length is
+    /// smaller than or equal to the specified bytes.
+    ///
+    /// Entries will be filtered at client side, dirs are always returned.
+    max_size: Option<u64>,
+    /// The modified_after is used to only return entries whose last modified
+    /// is after the specified time.
+    ///
+    /// Entries will be filtered at client side, dirs are always returned.
+    modified_after: Option<DateTime<Utc>>,
+    /// The modified_before is used to only return entries whose last modified
+    /// is before the specified time.
+    ///
+    /// Entries will be filtered at client side, dirs are always returned.
+    modified_before: Option<DateTime<Utc>>,
+    /// The only_files is used to only return file entries.
+    ///
+    /// Entries will be filtered at client side.
+    only_files: bool,
+    /// The only_dirs is used to only return dir entries.
+    ///
+    /// Dirs will be derived from the parent paths of returned entries for services
+    /// that don't return dir entries.
+    only_dirs: bool,
 }
 
 impl Default for OpList {
@@ -106,10 +290,22 @@ impl Default for OpList {
         OpList {
             limit: None,
             start_after: None,
+            start_before: None,
             recursive: false,
+            delimiter: None,
             // By default, we want to know what's the mode of this entry.
             metakey: Metakey::Mode.into(),
             concurrent: 1,
+            versions: false,
+            pattern: None,
+            limit_total: None,
+            sorted: false,
+            min_size: None,
+            max_size: None,
+            modified_after: None,
+            modified_before: None,
+            only_files: false,
+            only_dirs: false,
         }
     }
 }
@@ -142,6 +338,17 @@ impl OpList {
         self.start_after.as_deref()
     }
 
+    /// Change the start_before of this list operation.
+    pub fn with_start_before(mut self, start_before: &str) -> Self {
+        self.start_before = Some(start_before.into());
+        self
+    }
+
+    /// Get the start_before of list operation.
+    pub fn start_before(&self) -> Option<&str> {
+        self.start_before.as_deref()
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -158,6 +365,25 @@ impl OpList {
         self.recursive
     }
 
+    /// Change the delimiter of this list operation.
+    ///
+    /// Entries will be grouped into common prefixes by this delimiter instead of `/`.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Get the custom delimiter of list operation.
+    pub fn delimiter(&self) -> Option<char> {
+        self.delimiter
+    }
+
+    /// Clear the custom delimiter of list operation.
+    pub(crate) fn without_delimiter(mut self) -> Self {
+        self.delimiter = None;
+        self
+    }
+
     /// Change the metakey of this list operation.
     ///
     /// The default metakey is `Metakey::Mode`.
@@ -183,6 +409,118 @@ impl OpList {
     pub fn concurrent(&self) -> usize {
         self.concurrent
     }
+
+    /// Change the versions of this list operation.
+    ///
+    /// If versions is set, list operation will return all versions of objects.
+    pub fn with_versions(mut self, versions: bool) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Get the versions of list operation.
+    pub fn versions(&self) -> bool {
+        self.versions
+    }
+
+    /// Change the glob pattern of this list operation.
+    pub fn with_pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Get the glob pattern of list operation.
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Change the limit_total of this list operation.
+    pub fn with_limit_total(mut self, limit_total: usize) -> Self {
+        self.limit_total = Some(limit_total);
+        self
+    }
+
+    /// Get the limit_total of list operation.
+    pub fn limit_total(&self) -> Option<usize> {
+        self.limit_total
+    }
+
+    /// Change the sorted of this list operation.
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Get the sorted of list operation.
+    pub fn sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// Change the min_size of this list operation.
+    pub fn with_min_size(mut self, min_size: u64) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Get the min_size of list operation.
+    pub fn min_size(&self) -> Option<u64> {
+        self.min_size
+    }
+
+    /// Change the max_size of this list operation.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Get the max_size of list operation.
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
+    }
+
+    /// Change the modified_after of this list operation.
+    pub fn with_modified_after(mut self, modified_after: DateTime<Utc>) -> Self {
+        self.modified_after = Some(modified_after);
+        self
+    }
+
+    /// Get the modified_after of list operation.
+    pub fn modified_after(&self) -> Option<DateTime<Utc>> {
+        self.modified_after
+    }
+
+    /// Change the modified_before of this list operation.
+    pub fn with_modified_before(mut self, modified_before: DateTime<Utc>) -> Self {
+        self.modified_before = Some(modified_before);
+        self
+    }
+
+    /// Get the modified_before of list operation.
+    pub fn modified_before(&self) -> Option<DateTime<Utc>> {
+        self.modified_before
+    }
+
+    /// Change the only_files of this list operation.
+    pub fn with_only_files(mut self, only_files: bool) -> Self {
+        self.only_files = only_files;
+        self
+    }
+
+    /// Get the only_files of list operation.
+    pub fn only_files(&self) -> bool {
+        self.only_files
+    }
+
+    /// Change the only_dirs of this list operation.
+    pub fn with_only_dirs(mut self, only_dirs: bool) -> Self {
+        self.only_dirs = only_dirs;
+        self
+    }
+
+    /// Get the only_dirs of list operation.
+    pub fn only_dirs(&self) -> bool {
+        self.only_dirs
+    }
 }
 
 /// Args for `presign` operation.
@@ -193,6 +531,8 @@ pub struct OpPresign {
     expire: Duration,
 
     op: PresignOperation,
+    headers: Vec<(String, String)>,
+    query: Vec<(String, String)>,
 }
 
 impl OpPresign {
@@ -201,9 +541,36 @@ impl OpPresign {
         Self {
             op: op.into(),
             expire,
+            headers: Vec::new(),
+            query: Vec::new(),
         }
     }
 
+    /// Add an extra header that will be included in the signature.
+    ///
+    /// Clients must send this header with the same value while sending
+    /// the presigned request, otherwise services will reject it.
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Get extra headers from op.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Add an extra query parameter that will be included in the signature.
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Get extra query parameters from op.
+    pub fn query(&self) -> &[(String, String)] {
+        &self.query
+    }
+
     /// Get operation from op.
     pub fn operation(&self) -> &PresignOperation {
         &self.op
@@ -220,6 +587,39 @@ impl OpPresign {
     }
 }
 
+/// Args for presign `upload_part` operation.
+///
+/// This is used to presign a single part of an already initiated multipart upload,
+/// so that clients can upload parts directly without routing data through server.
+#[derive(Debug, Clone)]
+pub struct OpUploadPart {
+    upload_id: String,
+    part_number: usize,
+}
+
+impl OpUploadPart {
+    /// Create a new `OpUploadPart`.
+    ///
+    /// `part_number` is passed to services as is, please refer to service's doc
+    /// about its valid range. For example, s3 requires it between `[1, 10000]`.
+    pub fn new(upload_id: &str, part_number: usize) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            part_number,
+        }
+    }
+
+    /// Get upload_id from option.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get part_number from option.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+}
+
 /// Presign operation used for presign.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -230,6 +630,12 @@ pub enum PresignOperation {
     Read(OpRead),
     /// Presign a write operation.
     Write(OpWrite),
+    /// Presign a delete operation.
+    Delete(OpDelete),
+    /// Presign an upload part operation of multipart upload.
+    UploadPart(OpUploadPart),
+    /// Presign a browser based form upload via POST policy.
+    Post(OpPresignPost),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -250,6 +656,58 @@ impl From<OpWrite> for PresignOperation {
     }
 }
 
+impl From<OpDelete> for PresignOperation {
+    fn from(v: OpDelete) -> Self {
+        Self::Delete(v)
+    }
+}
+
+impl From<OpUploadPart> for PresignOperation {
+    fn from(v: OpUploadPart) -> Self {
+        Self::UploadPart(v)
+    }
+}
+
+impl From<OpPresignPost> for PresignOperation {
+    fn from(v: OpPresignPost) -> Self {
+        Self::Post(v)
+    }
+}
+
+/// Args for presign `post` operation.
+///
+/// Conditions will be added into the POST policy, browsers must send
+/// form fields that satisfy all of them or the upload will be rejected.
+#[derive(Debug, Clone, Default)]
+pub struct OpPresignPost {
+    conditions: Vec<PostPolicyCondition>,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new(conditions: Vec<PostPolicyCondition>) -> Self {
+        Self { conditions }
+    }
+
+    /// Get conditions from op.
+    pub fn conditions(&self) -> &[PostPolicyCondition] {
+        &self.conditions
+    }
+}
+
+/// Condition of a POST policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostPolicyCondition {
+    /// The uploaded content length must be between `[min, max]`.
+    ContentLengthRange(u64, u64),
+    /// The form field must equal to given value.
+    ///
+    /// This field will be returned in the presigned form fields as well.
+    Eq(String, String),
+    /// The form field must start with given prefix.
+    StartsWith(String, String),
+}
+
 /// Args for `batch` operation.
 #[derive(Debug, Clone)]
 pub struct OpBatch {
@@ -297,16 +755,78 @@ impl BatchOperation {
     }
 }
 
+/// ProgressCallback will be called with the bytes transferred so far and the total
+/// size hint (if known) while data is flowing.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(u64, Option<u64>) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Create a new ProgressCallback.
+    pub fn new(f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Call the callback with transferred bytes and total size hint.
+    pub fn call(&self, transferred: u64, total: Option<u64>) {
+        (self.0)(transferred, total)
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressCallback").finish_non_exhaustive()
+    }
+}
+
+/// TransformSlot holds the [`Transform`] given by users.
+///
+/// Transform is stateful, so it can only be applied once. The first one that
+/// calls [`TransformSlot::take`] will own it.
+#[derive(Clone)]
+pub struct TransformSlot(Arc<Mutex<Option<Box<dyn Transform>>>>);
+
+impl TransformSlot {
+    /// Create a new TransformSlot.
+    pub fn new(transform: Box<dyn Transform>) -> Self {
+        Self(Arc::new(Mutex::new(Some(transform))))
+    }
+
+    /// Take the transform out of the slot.
+    ///
+    /// Returns `None` if the transform has already been taken.
+    pub fn take(&self) -> Option<Box<dyn Transform>> {
+        self.0.lock().expect("lock must be valid").take()
+    }
+}
+
+impl Debug for TransformSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransformSlot").finish_non_exhaustive()
+    }
+}
+
 /// Args for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRead {
     br: BytesRange,
     if_match: Option<String>,
     if_none_match: Option<String>,
+    if_modified_since: Option<DateTime<Utc>>,
+    if_unmodified_since: Option<DateTime<Utc>>,
     override_content_type: Option<String>,
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    verify_checksum: bool,
+    verify_length: bool,
+    chunk: Option<usize>,
+    buffer: Option<usize>,
+    merge_gap: Option<usize>,
+    auto_concurrent: bool,
+    memory_budget: Option<usize>,
+    decompress: Option<Compression>,
+    progress: Option<ProgressCallback>,
+    transform: Option<TransformSlot>,
 }
 
 impl OpRead {
@@ -399,6 +919,157 @@ impl OpRead {
         self.if_none_match.as_deref()
     }
 
+    /// Set the If-Modified-Since of the option
+    pub fn with_if_modified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.if_modified_since = Some(v);
+        self
+    }
+
+    /// Get If-Modified-Since from option
+    pub fn if_modified_since(&self) -> Option<DateTime<Utc>> {
+        self.if_modified_since
+    }
+
+    /// Set the If-Unmodified-Since of the option
+    pub fn with_if_unmodified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.if_unmodified_since = Some(v);
+        self
+    }
+
+    /// Get If-Unmodified-Since from option
+    pub fn if_unmodified_since(&self) -> Option<DateTime<Utc>> {
+        self.if_unmodified_since
+    }
+
+    /// Set the verify_checksum of the option
+    ///
+    /// If verify_checksum is set, the content will be verified against the checksum
+    /// returned by service.
+    pub fn with_verify_checksum(mut self, verify_checksum: bool) -> Self {
+        self.verify_checksum = verify_checksum;
+        self
+    }
+
+    /// Get verify_checksum from option
+    pub fn verify_checksum(&self) -> bool {
+        self.verify_checksum
+    }
+
+    /// Set the verify_length of the option
+    ///
+    /// If verify_length is set, the length of content will be verified against the
+    /// resolved range.
+    pub fn with_verify_length(mut self, verify_length: bool) -> Self {
+        self.verify_length = verify_length;
+        self
+    }
+
+    /// Get verify_length from option
+    pub fn verify_length(&self) -> bool {
+        self.verify_length
+    }
+
+    /// Set the chunk size of the option.
+    ///
+    /// If chunk is set, the read will be split into multiple requests, each of them
+    /// reads at most `chunk` bytes.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Get chunk from option
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
+
+    /// Set the read-ahead buffer size of the option.
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = Some(buffer);
+        self
+    }
+
+    /// Get buffer from option
+    pub fn buffer(&self) -> Option<usize> {
+        self.buffer
+    }
+
+    /// Set the merge gap of the option.
+    pub fn with_merge_gap(mut self, merge_gap: usize) -> Self {
+        self.merge_gap = Some(merge_gap);
+        self
+    }
+
+    /// Get merge gap from option
+    pub fn merge_gap(&self) -> Option<usize> {
+        self.merge_gap
+    }
+
+    /// Set the auto concurrent of the option.
+    ///
+    /// If auto concurrent is enabled, the chunk size and concurrency will be
+    /// picked based on the content length.
+    pub fn with_auto_concurrent(mut self, auto_concurrent: bool) -> Self {
+        self.auto_concurrent = auto_concurrent;
+        self
+    }
+
+    /// Get auto concurrent from option
+    pub fn auto_concurrent(&self) -> bool {
+        self.auto_concurrent
+    }
+
+    /// Set the memory budget of in-flight chunks for auto concurrent.
+    pub fn with_memory_budget(mut self, memory_budget: usize) -> Self {
+        self.memory_budget = Some(memory_budget);
+        self
+    }
+
+    /// Get memory budget from option
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    /// Set the decompression of the option.
+    ///
+    /// If decompress is set, the content will be decompressed while reading.
+    pub fn with_decompress(mut self, compression: Compression) -> Self {
+        self.decompress = Some(compression);
+        self
+    }
+
+    /// Get decompress from option
+    pub fn decompress(&self) -> Option<Compression> {
+        self.decompress
+    }
+
+    /// Set the progress callback of the option.
+    ///
+    /// The callback will be called with the bytes read so far every time a
+    /// non-empty chunk has been read.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get progress callback from option
+    pub fn progress(&self) -> Option<&ProgressCallback> {
+        self.progress.as_ref()
+    }
+
+    /// Set the transform of the option.
+    ///
+    /// The transform will be applied to the content after it has been read.
+    pub fn with_transform(mut self, transform: TransformSlot) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Get transform from option
+    pub fn transform(&self) -> Option<&TransformSlot> {
+        self.transform.as_ref()
+    }
+
     /// Set the version of the option
     pub fn with_version(mut self, version: &str) -> Self {
         self.version = Some(version.to_string());
@@ -416,10 +1087,14 @@ impl OpRead {
 pub struct OpStat {
     if_match: Option<String>,
     if_none_match: Option<String>,
+    if_modified_since: Option<DateTime<Utc>>,
+    if_unmodified_since: Option<DateTime<Utc>>,
     override_content_type: Option<String>,
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    infer_dir: bool,
+    raw_headers: bool,
 }
 
 impl OpStat {
@@ -450,6 +1125,28 @@ impl OpStat {
         self.if_none_match.as_deref()
     }
 
+    /// Set the If-Modified-Since of the option
+    pub fn with_if_modified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.if_modified_since = Some(v);
+        self
+    }
+
+    /// Get If-Modified-Since from option
+    pub fn if_modified_since(&self) -> Option<DateTime<Utc>> {
+        self.if_modified_since
+    }
+
+    /// Set the If-Unmodified-Since of the option
+    pub fn with_if_unmodified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.if_unmodified_since = Some(v);
+        self
+    }
+
+    /// Get If-Unmodified-Since from option
+    pub fn if_unmodified_since(&self) -> Option<DateTime<Utc>> {
+        self.if_unmodified_since
+    }
+
     /// Sets the content-disposition header that should be send back by the remote read operation.
     pub fn with_override_content_disposition(mut self, content_disposition: &str) -> Self {
         self.override_content_disposition = Some(content_disposition.into());
@@ -494,6 +1191,28 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set whether to infer dir via list if stat a dir path returns `NotFound`.
+    pub fn with_infer_dir(mut self, infer_dir: bool) -> Self {
+        self.infer_dir = infer_dir;
+        self
+    }
+
+    /// Get infer dir from option
+    pub fn infer_dir(&self) -> bool {
+        self.infer_dir
+    }
+
+    /// Set whether services should return the raw response headers.
+    pub fn with_raw_headers(mut self, raw_headers: bool) -> Self {
+        self.raw_headers = raw_headers;
+        self
+    }
+
+    /// Get raw headers from option
+    pub fn raw_headers(&self) -> bool {
+        self.raw_headers
+    }
 }
 
 /// Args for `write` operation.
@@ -501,10 +1220,28 @@ impl OpStat {
 pub struct OpWrite {
     append: bool,
     buffer: Option<usize>,
+    concurrent: usize,
+    flush_interval: Option<Duration>,
+    compress: Option<Compression>,
 
+    content_length: Option<u64>,
     content_type: Option<String>,
     content_disposition: Option<String>,
+    content_encoding: Option<String>,
+    content_md5: Option<String>,
     cache_control: Option<String>,
+    storage_class: Option<String>,
+    user_metadata: Option<HashMap<String, String>>,
+    if_not_exists: bool,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    resume: Option<oio::MultipartUploadState>,
+    verify_parts: bool,
+    expires: Option<DateTime<Utc>>,
+    allow_unsupported: bool,
+
+    progress: Option<ProgressCallback>,
+    transform: Option<TransformSlot>,
 }
 
 impl OpWrite {
@@ -554,6 +1291,74 @@ impl OpWrite {
         self
     }
 
+    /// Get the concurrent from op.
+    ///
+    /// The concurrent is the max number of parts that could be uploaded at the same time
+    /// while performing multipart uploads.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the concurrent of op.
+    ///
+    /// - If concurrent is set to <= 1, parts will be uploaded sequentially.
+    /// - If concurrent is set to > 1, at most `concurrent` parts will be uploaded at the same time.
+    ///
+    /// ## NOTE
+    ///
+    /// Concurrent only takes effect on services that write via multipart uploads,
+    /// it will be ignored by other services.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get the content length from option
+    ///
+    /// The content length is the total size of content that users are going to write.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Set the content length of option
+    pub fn with_content_length(mut self, content_length: u64) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+
+    /// Get the flush interval from option
+    ///
+    /// The flush interval is used to flush the buffered data on the next write even if the
+    /// buffer is not full.
+    pub fn flush_interval(&self) -> Option<Duration> {
+        self.flush_interval
+    }
+
+    /// Set the flush interval of option
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = Some(flush_interval);
+        self
+    }
+
+    /// Get the compression from option
+    pub fn compress(&self) -> Option<Compression> {
+        self.compress
+    }
+
+    /// Set the compression of option
+    ///
+    /// If compress is set, the content will be compressed before sending to services.
+    pub fn with_compress(mut self, compression: Compression) -> Self {
+        self.compress = Some(compression);
+        self
+    }
+
+    /// Clear the content length of option.
+    pub(crate) fn without_content_length(mut self) -> Self {
+        self.content_length = None;
+        self
+    }
+
     /// Get the content type from option
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
@@ -576,6 +1381,28 @@ impl OpWrite {
         self
     }
 
+    /// Get the content encoding from option
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.content_encoding.as_deref()
+    }
+
+    /// Set the content encoding of option
+    pub fn with_content_encoding(mut self, content_encoding: &str) -> Self {
+        self.content_encoding = Some(content_encoding.to_string());
+        self
+    }
+
+    /// Get the content md5 from option
+    pub fn content_md5(&self) -> Option<&str> {
+        self.content_md5.as_deref()
+    }
+
+    /// Set the content md5 of option
+    pub fn with_content_md5(mut self, content_md5: &str) -> Self {
+        self.content_md5 = Some(content_md5.to_string());
+        self
+    }
+
     /// Get the cache control from option
     pub fn cache_control(&self) -> Option<&str> {
         self.cache_control.as_deref()
@@ -586,26 +1413,265 @@ impl OpWrite {
         self.cache_control = Some(cache_control.to_string());
         self
     }
+
+    /// Get the storage class from option
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Set the storage class of option
+    pub fn with_storage_class(mut self, storage_class: &str) -> Self {
+        self.storage_class = Some(storage_class.to_string());
+        self
+    }
+
+    /// Get the user metadata from option
+    pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
+        self.user_metadata.as_ref()
+    }
+
+    /// Set the user metadata of option
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support user metadata will ignore this hint.
+    pub fn with_user_metadata(mut self, user_metadata: HashMap<String, String>) -> Self {
+        self.user_metadata = Some(user_metadata);
+        self
+    }
+
+    /// Get the if not exists from option
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Set the if not exists of option
+    ///
+    /// If set, the write will fail with `ConditionNotMatch` if the path already exists.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Get the If-Match from option
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+
+    /// Set the If-Match of option
+    ///
+    /// If set, the write will fail with `ConditionNotMatch` if the etag of existing
+    /// path doesn't match.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.to_string());
+        self
+    }
+
+    /// Get the If-None-Match from option
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.if_none_match.as_deref()
+    }
+
+    /// Set the If-None-Match of option
+    ///
+    /// If set, the write will fail with `ConditionNotMatch` if the etag of existing
+    /// path matches. `*` matches any existing path.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Get the multipart upload state to resume from option
+    pub fn resume(&self) -> Option<&oio::MultipartUploadState> {
+        self.resume.as_ref()
+    }
+
+    /// Set the multipart upload state to resume of option
+    ///
+    /// If set, the write will continue the given multipart upload instead of
+    /// starting a new one, the data written should start right after the
+    /// completed parts.
+    pub fn with_resume(mut self, state: oio::MultipartUploadState) -> Self {
+        self.resume = Some(state);
+        self
+    }
+
+    /// Get the verify_parts from option
+    pub fn verify_parts(&self) -> bool {
+        self.verify_parts
+    }
+
+    /// Set the verify_parts of option
+    ///
+    /// If set, every part of multipart upload will be sent along with its content
+    /// md5 so that services can reject the corrupted part.
+    pub fn with_verify_parts(mut self, verify_parts: bool) -> Self {
+        self.verify_parts = verify_parts;
+        self
+    }
+
+    /// Get the expires from option
+    pub fn expires(&self) -> Option<DateTime<Utc>> {
+        self.expires
+    }
+
+    /// Set the expires of option
+    ///
+    /// The expires will be sent as the `Expires` header of the object, which is an
+    /// HTTP caching header instead of a TTL.
+    pub fn with_expires(mut self, expires: DateTime<Utc>) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Get the allow_unsupported from option
+    pub fn allow_unsupported(&self) -> bool {
+        self.allow_unsupported
+    }
+
+    /// Set the allow_unsupported of option
+    ///
+    /// If set, `expires` will be ignored instead of returning `Unsupported`
+    /// error if the service doesn't support it. Other options are not affected.
+    pub fn with_allow_unsupported(mut self, allow_unsupported: bool) -> Self {
+        self.allow_unsupported = allow_unsupported;
+        self
+    }
+
+    /// Get the progress callback from option
+    pub fn progress(&self) -> Option<&ProgressCallback> {
+        self.progress.as_ref()
+    }
+
+    /// Set the progress callback of option
+    ///
+    /// The callback will be called with the bytes written so far after every
+    /// chunk has been accepted by the underlying writer.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get the transform from option
+    pub fn transform(&self) -> Option<&TransformSlot> {
+        self.transform.as_ref()
+    }
+
+    /// Set the transform of option
+    ///
+    /// The transform will be applied to the content before it's written.
+    pub fn with_transform(mut self, transform: TransformSlot) -> Self {
+        self.transform = Some(transform);
+        self
+    }
 }
 
 /// Args for `copy` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpCopy {}
+pub struct OpCopy {
+    if_none_match: Option<String>,
+    metadata_directive: Option<MetadataDirective>,
+    content_type: Option<String>,
+    allow_fallback: bool,
+    preserve_last_modified: bool,
+}
 
 impl OpCopy {
     /// Create a new `OpCopy`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the If-None-Match of the option
+    ///
+    /// Use `*` to copy only if the destination doesn't exist.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Get If-None-Match from option
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.if_none_match.as_deref()
+    }
+
+    /// Set the metadata directive of the option
+    pub fn with_metadata_directive(mut self, directive: MetadataDirective) -> Self {
+        self.metadata_directive = Some(directive);
+        self
+    }
+
+    /// Get metadata directive from option
+    pub fn metadata_directive(&self) -> Option<MetadataDirective> {
+        self.metadata_directive
+    }
+
+    /// Set the content type of the option
+    ///
+    /// Setting content type implies [`MetadataDirective::Replace`].
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get content type from option
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Set whether to fallback to read and write if copy is not supported.
+    pub fn with_allow_fallback(mut self, allow_fallback: bool) -> Self {
+        self.allow_fallback = allow_fallback;
+        self
+    }
+
+    /// Get allow fallback from option
+    pub fn allow_fallback(&self) -> bool {
+        self.allow_fallback
+    }
+
+    /// Set whether to keep the last modified of the source on the destination.
+    pub fn with_preserve_last_modified(mut self, preserve_last_modified: bool) -> Self {
+        self.preserve_last_modified = preserve_last_modified;
+        self
+    }
+
+    /// Get preserve last modified from option
+    pub fn preserve_last_modified(&self) -> bool {
+        self.preserve_last_modified
+    }
+}
+
+/// MetadataDirective decides how metadata of the destination will be set
+/// while copying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataDirective {
+    /// Copy metadata from the source object.
+    Copy,
+    /// Replace metadata with the ones given in the copy request.
+    Replace,
 }
 
 /// Args for `rename` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpRename {}
+pub struct OpRename {
+    allow_fallback: bool,
+}
 
 impl OpRename {
     /// Create a new `OpMove`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set whether to fallback to copy and delete if rename is not supported.
+    pub fn with_allow_fallback(mut self, allow_fallback: bool) -> Self {
+        self.allow_fallback = allow_fallback;
+        self
+    }
+
+    /// Get allow fallback from option
+    pub fn allow_fallback(&self) -> bool {
+        self.allow_fallback
+    }
 }
@@ -93,6 +93,9 @@ pub fn build_rel_path(root: &str, path: &str) -> String {
 /// - All leading / will be trimmed: `///abc` => `abc`
 /// - Internal // will be replaced by /: `abc///def` => `abc/def`
 /// - Empty path will be `/`: `` => `/`
+///
+/// `.`, `..` and percent-encoded chars will be kept as is since most services
+/// treat them as part of the key: `abc/./def` and `abc/def` are different paths.
 pub fn normalize_path(path: &str) -> String {
     // - all whitespace has been trimmed.
     // - all leading `/` has been trimmed.
@@ -221,6 +224,57 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     }
 }
 
+/// Check if given path matches the glob pattern.
+///
+/// Both pattern and path are split by `/` and matched segment by segment:
+///
+/// - `*` matches any sequence of characters inside a segment.
+/// - `?` matches exactly one character inside a segment.
+/// - `**` matches zero or more segments.
+///
+/// For example, `**/*.parquet` matches `a.parquet` and `year=2023/a.parquet`.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').filter(|v| !v.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|v| !v.is_empty()).collect();
+
+    glob_match_segments(&pattern, &path)
+}
+
+fn glob_match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|idx| glob_match_segments(rest, &path[idx..])),
+        Some((p, rest)) => match path.split_first() {
+            None => false,
+            Some((s, path_rest)) => {
+                glob_match_segment(p, s) && glob_match_segments(rest, path_rest)
+            }
+        },
+    }
+}
+
+fn glob_match_segment(pattern: &str, s: &str) -> bool {
+    let mut chars = pattern.chars();
+    match chars.next() {
+        None => s.is_empty(),
+        Some('*') => {
+            let rest = chars.as_str();
+            s.char_indices()
+                .map(|(idx, _)| idx)
+                .chain(Some(s.len()))
+                .any(|idx| glob_match_segment(rest, &s[idx..]))
+        }
+        Some('?') => {
+            let mut s = s.chars();
+            s.next().is_some() && glob_match_segment(chars.as_str(), s.as_str())
+        }
+        Some(c) => match s.strip_prefix(c) {
+            Some(s) => glob_match_segment(chars.as_str(), s),
+            None => false,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +294,9 @@ mod tests {
             ("file path contains ///", "abc///def", "abc/def"),
             ("dir path contains ///", "abc///def///", "abc/def/"),
             ("file with whitespace", "abc/def   ", "abc/def"),
+            ("file path contains .", "abc/./def", "abc/./def"),
+            ("file path contains ..", "abc/../def", "abc/../def"),
+            ("file path percent-encoded", "abc%2Fdef", "abc%2Fdef"),
         ];
 
         for (name, input, expect) in cases {
@@ -377,4 +434,45 @@ mod tests {
             assert_eq!(actual, expect, "{name}")
         }
     }
+
+    #[test]
+    fn test_glob_match() {
+        let cases = vec![
+            ("match file", "*.parquet", "a.parquet", true),
+            ("not match file", "*.parquet", "a.csv", false),
+            (
+                "star not cross segment",
+                "*.parquet",
+                "dir/a.parquet",
+                false,
+            ),
+            (
+                "double star match zero segment",
+                "**/*.parquet",
+                "a.parquet",
+                true,
+            ),
+            (
+                "double star match segments",
+                "**/*.parquet",
+                "x/y/a.parquet",
+                true,
+            ),
+            (
+                "double star in middle",
+                "x/**/a.parquet",
+                "x/a.parquet",
+                true,
+            ),
+            ("question mark", "?.csv", "a.csv", true),
+            ("question mark not match empty", "?.csv", ".csv", false),
+            ("literal", "dir/a.csv", "dir/a.csv", true),
+            ("trailing double star", "dir/**", "dir/x/y", true),
+            ("unicode", "?.csv", "文.csv", true),
+        ];
+
+        for (name, pattern, path, expect) in cases {
+            assert_eq!(glob_match(pattern, path), expect, "{name}")
+        }
+    }
 }
@@ -32,22 +32,71 @@ pub struct RpDelete {}
 #[derive(Debug, Clone, Default)]
 pub struct RpList {}
 
+/// Reply for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpRestore {}
+
 /// Reply for `presign` operation.
 #[derive(Debug, Clone)]
 pub struct RpPresign {
     req: PresignedRequest,
+    fields: Vec<(String, String)>,
 }
 
 impl RpPresign {
     /// Create a new reply for `presign`.
     pub fn new(req: PresignedRequest) -> Self {
-        RpPresign { req }
+        RpPresign {
+            req,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Set the form fields of this reply, only used by presign `post`.
+    pub fn with_fields(mut self, fields: Vec<(String, String)>) -> Self {
+        self.fields = fields;
+        self
     }
 
     /// Consume reply to build a presigned request.
     pub fn into_presigned_request(self) -> PresignedRequest {
         self.req
     }
+
+    /// Consume reply to build a presigned post.
+    pub fn into_presigned_post(self) -> PresignedPost {
+        PresignedPost {
+            uri: self.req.uri,
+            fields: self.fields,
+        }
+    }
+}
+
+/// PresignedPost is a presigned form upload returned by `presign_post`.
+///
+/// Browsers should send a `multipart/form-data` POST request to `uri` with
+/// all `fields` and the file content as the last field named `file`.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    uri: http::Uri,
+    fields: Vec<(String, String)>,
+}
+
+impl PresignedPost {
+    /// Create a new PresignedPost
+    pub fn new(uri: http::Uri, fields: Vec<(String, String)>) -> Self {
+        Self { uri, fields }
+    }
+
+    /// Return form's action uri.
+    pub fn uri(&self) -> &http::Uri {
+        &self.uri
+    }
+
+    /// Return form's fields.
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
 }
 
 /// PresignedRequest is a presigned request return by `presign`.
@@ -191,12 +240,29 @@ impl From<RpDelete> for BatchedReply {
 #[derive(Debug, Clone)]
 pub struct RpStat {
     meta: Metadata,
+    headers: Option<http::HeaderMap>,
 }
 
 impl RpStat {
     /// Create a new reply for `stat`.
     pub fn new(meta: Metadata) -> Self {
-        RpStat { meta }
+        RpStat {
+            meta,
+            headers: None,
+        }
+    }
+
+    /// Set the raw response headers of this reply.
+    ///
+    /// Services should only set them while `OpStat::raw_headers` is enabled.
+    pub fn with_headers(mut self, headers: http::HeaderMap) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+
+    /// Get the raw response headers of this reply.
+    pub fn headers(&self) -> Option<&http::HeaderMap> {
+        self.headers.as_ref()
     }
 
     /// Operate on inner metadata.
@@ -209,6 +275,11 @@ impl RpStat {
     pub fn into_metadata(self) -> Metadata {
         self.meta
     }
+
+    /// Consume RpStat to get the inner metadata and raw response headers.
+    pub fn into_parts(self) -> (Metadata, Option<http::HeaderMap>) {
+        (self.meta, self.headers)
+    }
 }
 
 /// Reply for `write` operation.
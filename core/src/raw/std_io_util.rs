@@ -34,7 +34,8 @@ pub fn new_std_io_error(err: std::io::Error) -> Error {
         InvalidInput => (ErrorKind::InvalidInput, false),
         Unsupported => (ErrorKind::Unsupported, false),
 
-        Interrupted | UnexpectedEof | TimedOut | WouldBlock => (ErrorKind::Unexpected, true),
+        TimedOut => (ErrorKind::Timeout, true),
+        Interrupted | UnexpectedEof | WouldBlock => (ErrorKind::Unexpected, true),
         _ => (ErrorKind::Unexpected, true),
     };
 
@@ -556,6 +556,7 @@ impl Accessor for AzblobBackend {
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
+                stat_with_raw_headers: true,
                 stat_with_if_none_match: true,
 
                 read: true,
@@ -645,7 +646,13 @@ impl Accessor for AzblobBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut rp = parse_into_metadata(path, resp.headers()).map(RpStat::new)?;
+                if args.raw_headers() {
+                    rp = rp.with_headers(resp.headers().clone());
+                }
+                Ok(rp)
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
@@ -673,6 +680,13 @@ impl Accessor for AzblobBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "azblob doesn't support presign with extra headers or query",
+            ));
+        }
+
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.azblob_head_blob_request(path, v)?,
             PresignOperation::Read(v) => self.core.azblob_get_blob_request(path, v)?,
@@ -682,6 +696,24 @@ impl Accessor for AzblobBackend {
                 &OpWrite::default(),
                 AsyncBody::Empty,
             )?,
+            PresignOperation::Delete(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support presign delete",
+                ))
+            }
+            PresignOperation::UploadPart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support presign upload part",
+                ))
+            }
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support presign post",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req).await?;
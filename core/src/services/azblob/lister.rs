@@ -92,15 +92,17 @@ impl oio::PageList for AzblobLister {
                 continue;
             }
 
-            let meta = Metadata::new(EntryMode::from_path(&path))
+            let mut meta = Metadata::new(EntryMode::from_path(&path))
                 // Keep fit with ETag header.
                 .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
                 .with_content_length(object.properties.content_length)
                 .with_content_md5(object.properties.content_md5)
-                .with_content_type(object.properties.content_type)
                 .with_last_modified(parse_datetime_from_rfc2822(
                     object.properties.last_modified.as_str(),
                 )?);
+            if !object.properties.content_type.is_empty() {
+                meta.set_content_type(&object.properties.content_type);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
@@ -45,7 +45,7 @@ impl AzblobWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for AzblobWriter {
-    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<Metadata> {
         let bs = oio::ChunkedBytes::from_vec(bs.vectored_bytes(bs.remaining()));
         let mut req = self.core.azblob_put_blob_request(
             &self.path,
@@ -62,8 +62,9 @@ impl oio::OneShotWrite for AzblobWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -71,8 +71,8 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         StatusCode::PRECONDITION_FAILED => (ErrorKind::ConditionNotMatch, false),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        | StatusCode::SERVICE_UNAVAILABLE => (ErrorKind::Unexpected, true),
+        StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     };
 
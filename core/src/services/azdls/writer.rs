@@ -44,7 +44,7 @@ impl AzdlsWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for AzdlsWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let mut req =
             self.core
                 .azdls_create_request(&self.path, "file", &self.op, AsyncBody::Empty)?;
@@ -80,8 +80,9 @@ impl oio::OneShotWrite for AzdlsWriter {
         let status = resp.status();
         match status {
             StatusCode::OK | StatusCode::ACCEPTED => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp)
                 .await?
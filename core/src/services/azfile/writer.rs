@@ -42,7 +42,7 @@ impl AzfileWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for AzfileWriter {
-    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<Metadata> {
         let bs = oio::ChunkedBytes::from_vec(bs.vectored_bytes(bs.remaining()));
 
         let resp = self
@@ -69,8 +69,9 @@ impl oio::OneShotWrite for AzfileWriter {
         let status = resp.status();
         return match status {
             StatusCode::OK | StatusCode::CREATED => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp)
                 .await?
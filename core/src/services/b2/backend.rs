@@ -293,6 +293,7 @@ impl Accessor for B2Backend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 // The min multipart size of b2 is 5 MiB.
                 //
@@ -345,9 +346,11 @@ impl Accessor for B2Backend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let concurrent = args.concurrent();
+        let resume = args.resume().cloned();
         let writer = B2Writer::new(self.core.clone(), path, args);
 
-        let w = oio::MultipartUploadWriter::new(writer);
+        let w = oio::MultipartUploadWriter::new(writer, concurrent).with_resume(resume);
 
         Ok((RpWrite::default(), w))
     }
@@ -455,8 +458,22 @@ impl Accessor for B2Backend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "b2 doesn't support presign with extra headers or query",
+            ));
+        }
+
         match args.operation() {
-            PresignOperation::Stat(_) => {
+            PresignOperation::Stat(v) => {
+                if v.if_match().is_some() || v.if_none_match().is_some() {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "b2 doesn't support presign stat with if_match or if_none_match",
+                    ));
+                }
+
                 let resp = self
                     .core
                     .get_download_authorization(path, &OpRead::default(), args.expire())
@@ -536,6 +553,18 @@ impl Accessor for B2Backend {
                     parts.headers,
                 )))
             }
+            PresignOperation::Delete(_) => Err(Error::new(
+                ErrorKind::Unsupported,
+                "b2 doesn't support presign delete",
+            )),
+            PresignOperation::UploadPart(_) => Err(Error::new(
+                ErrorKind::Unsupported,
+                "b2 doesn't support presign upload part",
+            )),
+            PresignOperation::Post(_) => Err(Error::new(
+                ErrorKind::Unsupported,
+                "b2 doesn't support presign post",
+            )),
         }
     }
 }
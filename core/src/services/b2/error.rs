@@ -45,7 +45,8 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         // Service b2 could return 403, show the authorization error
         401 => (ErrorKind::PermissionDenied, true),
         429 => (ErrorKind::RateLimited, true),
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        500 | 502 | 503 => (ErrorKind::Unexpected, true),
+        504 => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     };
 
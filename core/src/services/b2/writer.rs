@@ -48,7 +48,7 @@ impl B2Writer {
 
 #[async_trait]
 impl oio::MultipartUploadWrite for B2Writer {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let resp = self
             .core
             .upload_file(&self.path, Some(size), &self.op, body)
@@ -58,8 +58,9 @@ impl oio::MultipartUploadWrite for B2Writer {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -120,7 +121,7 @@ impl oio::MultipartUploadWrite for B2Writer {
         &self,
         upload_id: &str,
         parts: &[oio::MultipartUploadPart],
-    ) -> Result<()> {
+    ) -> Result<Metadata> {
         let part_sha1_array = parts
             .iter()
             .map(|p| {
@@ -142,9 +143,10 @@ impl oio::MultipartUploadWrite for B2Writer {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
 
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -40,8 +40,8 @@ pub(crate) async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Err
         StatusCode::LOCKED => (ErrorKind::Unexpected, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        | StatusCode::SERVICE_UNAVAILABLE => (ErrorKind::Unexpected, true),
+        StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     };
 
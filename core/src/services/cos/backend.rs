@@ -256,6 +256,7 @@ impl Accessor for CosBackend {
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
+                stat_with_raw_headers: true,
                 stat_with_if_none_match: true,
 
                 read: true,
@@ -268,6 +269,7 @@ impl Accessor for CosBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 write_with_content_disposition: true,
@@ -329,7 +331,10 @@ impl Accessor for CosBackend {
         let w = if args.append() {
             CosWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            CosWriters::One(oio::MultipartUploadWriter::new(writer))
+            CosWriters::One(
+                oio::MultipartUploadWriter::new(writer, args.concurrent())
+                    .with_resume(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -355,7 +360,13 @@ impl Accessor for CosBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut rp = parse_into_metadata(path, resp.headers()).map(RpStat::new)?;
+                if args.raw_headers() {
+                    rp = rp.with_headers(resp.headers().clone());
+                }
+                Ok(rp)
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
@@ -374,6 +385,13 @@ impl Accessor for CosBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "cos doesn't support presign with extra headers or query",
+            ));
+        }
+
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.cos_head_object_request(path, v)?,
             PresignOperation::Read(v) => self.core.cos_get_object_request(path, v)?,
@@ -381,6 +399,24 @@ impl Accessor for CosBackend {
                 self.core
                     .cos_put_object_request(path, None, v, AsyncBody::Empty)?
             }
+            PresignOperation::Delete(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "cos doesn't support presign delete",
+                ))
+            }
+            PresignOperation::UploadPart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "cos doesn't support presign upload part",
+                ))
+            }
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "cos doesn't support presign post",
+                ))
+            }
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
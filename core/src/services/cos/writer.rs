@@ -47,7 +47,7 @@ impl CosWriter {
 
 #[async_trait]
 impl oio::MultipartUploadWrite for CosWriter {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let mut req = self
             .core
             .cos_put_object_request(&self.path, Some(size), &self.op, body)?;
@@ -60,8 +60,9 @@ impl oio::MultipartUploadWrite for CosWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -129,7 +130,7 @@ impl oio::MultipartUploadWrite for CosWriter {
         &self,
         upload_id: &str,
         parts: &[oio::MultipartUploadPart],
-    ) -> Result<()> {
+    ) -> Result<Metadata> {
         let parts = parts
             .iter()
             .map(|p| CompleteMultipartUploadRequestPart {
@@ -147,9 +148,10 @@ impl oio::MultipartUploadWrite for CosWriter {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
 
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
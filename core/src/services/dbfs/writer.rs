@@ -41,7 +41,7 @@ impl DbfsWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for DbfsWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let bs = bs.bytes(bs.remaining());
         let size = bs.len();
 
@@ -60,8 +60,9 @@ impl oio::OneShotWrite for DbfsWriter {
         let status = resp.status();
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -39,7 +39,7 @@ impl DropboxWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for DropboxWriter {
-    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<Metadata> {
         let bs = oio::ChunkedBytes::from_vec(bs.vectored_bytes(bs.remaining()));
 
         let resp = self
@@ -54,8 +54,9 @@ impl oio::OneShotWrite for DropboxWriter {
         let status = resp.status();
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
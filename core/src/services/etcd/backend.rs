@@ -323,7 +323,7 @@ impl Adapter {
         client.get_owned().await.map_err(|err| match err {
             RunError::User(err) => err,
             RunError::TimedOut => {
-                Error::new(ErrorKind::Unexpected, "connection request: timeout").set_temporary()
+                Error::new(ErrorKind::Timeout, "connection request: timeout").set_temporary()
             }
         })
     }
@@ -182,6 +182,15 @@ fn tmp_file_of(path: &str) -> String {
     format!("{name}.{uuid}")
 }
 
+fn new_same_file_error(from: &Path, to: &Path) -> Error {
+    Error::new(
+        ErrorKind::IsSameFile,
+        "from and to paths point to the same file",
+    )
+    .with_context("from", from.to_string_lossy())
+    .with_context("to", to.to_string_lossy())
+}
+
 impl FsBackend {
     // Synchronously build write path and ensure the parent dirs created
     fn blocking_ensure_write_abs_path(parent: &Path, path: &str) -> Result<PathBuf> {
@@ -267,7 +276,9 @@ impl Accessor for FsBackend {
                 list: true,
 
                 copy: true,
+                copy_with_preserve_last_modified: true,
                 rename: true,
+                rename_dir: true,
                 blocking: true,
 
                 ..Default::default()
@@ -346,15 +357,36 @@ impl Accessor for FsBackend {
         Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         let from = self.root.join(from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
-        tokio::fs::metadata(&from).await.map_err(new_std_io_error)?;
+        let meta = tokio::fs::metadata(&from).await.map_err(new_std_io_error)?;
+
+        // Paths like `a/./b` and `a/b` are different but point to the same file,
+        // copying a file to itself will truncate it.
+        if let Ok(target) = tokio::fs::canonicalize(self.root.join(to.trim_end_matches('/'))).await
+        {
+            if tokio::fs::canonicalize(&from)
+                .await
+                .map_err(new_std_io_error)?
+                == target
+            {
+                return Err(new_same_file_error(&from, &target));
+            }
+        }
 
         let to = Self::ensure_write_abs_path(&self.root, to.trim_end_matches('/')).await?;
 
-        tokio::fs::copy(from, to).await.map_err(new_std_io_error)?;
+        tokio::fs::copy(from, &to).await.map_err(new_std_io_error)?;
+
+        if args.preserve_last_modified() {
+            let mtime = filetime::FileTime::from_last_modification_time(&meta);
+            tokio::task::spawn_blocking(move || filetime::set_file_mtime(to, mtime))
+                .await
+                .map_err(new_task_join_error)?
+                .map_err(new_std_io_error)?;
+        }
 
         Ok(RpCopy::default())
     }
@@ -495,15 +527,28 @@ impl Accessor for FsBackend {
         Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
     }
 
-    fn blocking_copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         let from = self.root.join(from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
-        std::fs::metadata(&from).map_err(new_std_io_error)?;
+        let meta = std::fs::metadata(&from).map_err(new_std_io_error)?;
+
+        // Paths like `a/./b` and `a/b` are different but point to the same file,
+        // copying a file to itself will truncate it.
+        if let Ok(target) = std::fs::canonicalize(self.root.join(to.trim_end_matches('/'))) {
+            if std::fs::canonicalize(&from).map_err(new_std_io_error)? == target {
+                return Err(new_same_file_error(&from, &target));
+            }
+        }
 
         let to = Self::blocking_ensure_write_abs_path(&self.root, to.trim_end_matches('/'))?;
 
-        std::fs::copy(from, to).map_err(new_std_io_error)?;
+        std::fs::copy(from, &to).map_err(new_std_io_error)?;
+
+        if args.preserve_last_modified() {
+            let mtime = filetime::FileTime::from_last_modification_time(&meta);
+            filetime::set_file_mtime(to, mtime).map_err(new_std_io_error)?;
+        }
 
         Ok(RpCopy::default())
     }
@@ -22,6 +22,7 @@ use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
+use chrono::DateTime;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use tokio::io::AsyncWrite;
@@ -35,7 +36,8 @@ pub struct FsWriter<F> {
     tmp_path: Option<PathBuf>,
 
     f: Option<F>,
-    fut: Option<BoxFuture<'static, Result<()>>>,
+    fut: Option<BoxFuture<'static, Result<Option<Metadata>>>>,
+    metadata: Option<Metadata>,
 }
 
 impl<F> FsWriter<F> {
@@ -46,6 +48,7 @@ impl<F> FsWriter<F> {
 
             f: Some(f),
             fut: None,
+            metadata: None,
         }
     }
 }
@@ -69,7 +72,8 @@ impl oio::Write for FsWriter<tokio::fs::File> {
             if let Some(fut) = self.fut.as_mut() {
                 let res = ready!(fut.poll_unpin(cx));
                 self.fut = None;
-                return Poll::Ready(res);
+                self.metadata = res?;
+                return Poll::Ready(Ok(()));
             }
 
             let mut f = self.f.take().expect("FsWriter must be initialized");
@@ -78,6 +82,7 @@ impl oio::Write for FsWriter<tokio::fs::File> {
             self.fut = Some(Box::pin(async move {
                 f.flush().await.map_err(new_std_io_error)?;
                 f.sync_all().await.map_err(new_std_io_error)?;
+                let meta = f.metadata().await.map_err(new_std_io_error)?;
 
                 if let Some(tmp_path) = &tmp_path {
                     tokio::fs::rename(tmp_path, &target_path)
@@ -85,7 +90,11 @@ impl oio::Write for FsWriter<tokio::fs::File> {
                         .map_err(new_std_io_error)?;
                 }
 
-                Ok(())
+                let mut m = Metadata::new(EntryMode::FILE).with_content_length(meta.len());
+                if let Ok(v) = meta.modified() {
+                    m.set_last_modified(DateTime::from(v));
+                }
+                Ok(Some(m))
             }));
         }
     }
@@ -95,7 +104,7 @@ impl oio::Write for FsWriter<tokio::fs::File> {
             if let Some(fut) = self.fut.as_mut() {
                 let res = ready!(fut.poll_unpin(cx));
                 self.fut = None;
-                return Poll::Ready(res);
+                return Poll::Ready(res.map(|_| ()));
             }
 
             let _ = self.f.take().expect("FsWriter must be initialized");
@@ -104,7 +113,8 @@ impl oio::Write for FsWriter<tokio::fs::File> {
                 if let Some(tmp_path) = &tmp_path {
                     tokio::fs::remove_file(tmp_path)
                         .await
-                        .map_err(new_std_io_error)
+                        .map_err(new_std_io_error)?;
+                    Ok(None)
                 } else {
                     Err(Error::new(
                         ErrorKind::Unsupported,
@@ -114,6 +124,12 @@ impl oio::Write for FsWriter<tokio::fs::File> {
             }));
         }
     }
+
+    fn metadata(&self) -> Metadata {
+        self.metadata
+            .clone()
+            .unwrap_or_else(|| Metadata::new(EntryMode::FILE))
+    }
 }
 
 impl oio::BlockingWrite for FsWriter<std::fs::File> {
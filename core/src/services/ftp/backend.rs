@@ -478,7 +478,7 @@ impl FtpBackend {
         pool.get_owned().await.map_err(|err| match err {
             RunError::User(err) => err.into(),
             RunError::TimedOut => {
-                Error::new(ErrorKind::Unexpected, "connection request: timeout").set_temporary()
+                Error::new(ErrorKind::Timeout, "connection request: timeout").set_temporary()
             }
         })
     }
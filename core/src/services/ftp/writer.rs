@@ -48,7 +48,7 @@ unsafe impl Sync for FtpWriter {}
 
 #[async_trait]
 impl oio::OneShotWrite for FtpWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let size = bs.remaining();
         let bs = bs.bytes(size);
 
@@ -59,6 +59,6 @@ impl oio::OneShotWrite for FtpWriter {
         })?;
 
         ftp_stream.finalize_put_stream(data_stream).await?;
-        Ok(())
+        Ok(Metadata::new(EntryMode::FILE))
     }
 }
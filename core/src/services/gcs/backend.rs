@@ -342,6 +342,7 @@ impl Accessor for GcsBackend {
                 write_can_empty: true,
                 write_can_multi: true,
                 write_with_content_type: true,
+                write_with_storage_class: true,
                 // The buffer size should be a multiple of 256 KiB (256 x 1024 bytes), unless it's the last chunk that completes the upload.
                 // Larger chunk sizes typically make uploads faster, but note that there's a tradeoff between speed and memory usage.
                 // It's recommended that you use at least 8 MiB for the chunk size.
@@ -425,6 +426,9 @@ impl Accessor for GcsBackend {
         if !meta.content_type.is_empty() {
             m.set_content_type(&meta.content_type);
         }
+        if !meta.storage_class.is_empty() {
+            m.set_storage_class(&meta.storage_class);
+        }
 
         m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
 
@@ -514,6 +518,13 @@ impl Accessor for GcsBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "gcs doesn't support presign with extra headers or query",
+            ));
+        }
+
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.gcs_head_object_xml_request(path, v)?,
@@ -522,6 +533,24 @@ impl Accessor for GcsBackend {
                 self.core
                     .gcs_insert_object_xml_request(path, v, AsyncBody::Empty)?
             }
+            PresignOperation::Delete(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs doesn't support presign delete",
+                ))
+            }
+            PresignOperation::UploadPart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs doesn't support presign upload part",
+                ))
+            }
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs doesn't support presign post",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -561,6 +590,10 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"contentType": "image/png",`
     content_type: String,
+    /// Storage class of this object.
+    ///
+    /// For example: `"storageClass": "STANDARD",`
+    storage_class: String,
 }
 
 #[cfg(test)]
@@ -597,5 +630,6 @@ mod tests {
         assert_eq!(meta.md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
+        assert_eq!(meta.storage_class, "STANDARD");
     }
 }
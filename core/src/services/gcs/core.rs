@@ -226,8 +226,8 @@ impl GcsCore {
         let p = build_abs_path(&self.root, path);
 
         let mut metadata = HashMap::new();
-        if let Some(storage_class) = &self.default_storage_class {
-            metadata.insert("storageClass", storage_class.as_str());
+        if let Some(storage_class) = op.storage_class().or(self.default_storage_class.as_deref()) {
+            metadata.insert("storageClass", storage_class);
         }
         if let Some(cache_control) = op.cache_control() {
             metadata.insert("cacheControl", cache_control);
@@ -324,7 +324,10 @@ impl GcsCore {
             req = req.header("x-goog-acl", acl);
         }
 
-        if let Some(storage_class) = &self.default_storage_class {
+        if let Some(storage_class) = args
+            .storage_class()
+            .or(self.default_storage_class.as_deref())
+        {
             req = req.header("x-goog-storage-class", storage_class);
         }
 
@@ -626,6 +629,7 @@ pub struct ListResponseItem {
     pub md5_hash: String,
     pub updated: String,
     pub content_type: String,
+    pub storage_class: String,
 }
 
 #[cfg(test)]
@@ -124,6 +124,9 @@ impl oio::PageList for GcsLister {
             if !object.content_type.is_empty() {
                 meta.set_content_type(&object.content_type);
             }
+            if !object.storage_class.is_empty() {
+                meta.set_storage_class(&object.storage_class);
+            }
 
             meta.set_last_modified(parse_datetime_from_rfc3339(object.updated.as_str())?);
 
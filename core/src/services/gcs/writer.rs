@@ -45,7 +45,7 @@ impl GcsWriter {
 
 #[async_trait]
 impl oio::RangeWrite for GcsWriter {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let mut req = self.core.gcs_insert_object_request(
             &percent_encode_path(&self.path),
             Some(size),
@@ -61,8 +61,9 @@ impl oio::RangeWrite for GcsWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -116,7 +117,7 @@ impl oio::RangeWrite for GcsWriter {
         written: u64,
         size: u64,
         body: AsyncBody,
-    ) -> Result<()> {
+    ) -> Result<Metadata> {
         let resp = self
             .core
             .gcs_complete_resumable_upload(location, written, size, body)
@@ -125,8 +126,9 @@ impl oio::RangeWrite for GcsWriter {
         let status = resp.status();
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -89,7 +89,7 @@ impl GdriveWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for GdriveWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let bs = bs.bytes(bs.remaining());
         let size = bs.len();
         if self.file_id.is_none() {
@@ -98,6 +98,6 @@ impl oio::OneShotWrite for GdriveWriter {
             self.write_overwrite(size as u64, bs).await?;
         }
 
-        Ok(())
+        Ok(Metadata::new(EntryMode::FILE))
     }
 }
@@ -237,6 +237,7 @@ impl Accessor for HttpBackend {
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
+                stat_with_raw_headers: true,
                 stat_with_if_none_match: true,
 
                 read: true,
@@ -284,7 +285,13 @@ impl Accessor for HttpBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut rp = parse_into_metadata(path, resp.headers()).map(RpStat::new)?;
+                if args.raw_headers() {
+                    rp = rp.with_headers(resp.headers().clone());
+                }
+                Ok(rp)
+            }
             // HTTP Server like nginx could return FORBIDDEN if auto-index
             // is not enabled, we should ignore them.
             StatusCode::NOT_FOUND | StatusCode::FORBIDDEN if path.ends_with('/') => {
@@ -493,6 +500,50 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_stat_only_sends_head() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/hello"))
+            .respond_with(ResponseTemplate::new(200).insert_header("content-length", "13"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        // stat must never download the body.
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .set_body_string("Hello, World!"),
+            )
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        let op = Operator::new(builder)?.finish();
+
+        let meta = op.stat("hello").await?;
+        assert_eq!(meta.content_length(), 13);
+        let meta = op.stat_with("hello").if_none_match("*").await?;
+        assert_eq!(meta.content_length(), 13);
+
+        let reqs = mock_server
+            .received_requests()
+            .await
+            .expect("request recording must be enabled");
+        assert_eq!(reqs.len(), 2);
+        assert!(reqs.iter().all(|req| req.method.to_string() == "HEAD"));
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_with() -> Result<()> {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
@@ -177,7 +177,7 @@ impl Adapter {
 
         pool.get().await.map_err(|err| match err {
             RunError::TimedOut => {
-                Error::new(ErrorKind::Unexpected, "get connection from pool failed").set_temporary()
+                Error::new(ErrorKind::Timeout, "get connection from pool failed").set_temporary()
             }
             RunError::User(err) => err,
         })
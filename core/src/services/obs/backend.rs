@@ -263,6 +263,7 @@ impl Accessor for ObsBackend {
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
+                stat_with_raw_headers: true,
                 stat_with_if_none_match: true,
 
                 read: true,
@@ -275,6 +276,7 @@ impl Accessor for ObsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 // The min multipart size of OBS is 5 MiB.
@@ -308,6 +310,13 @@ impl Accessor for ObsBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "obs doesn't support presign with extra headers or query",
+            ));
+        }
+
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.obs_head_object_request(path, v)?,
             PresignOperation::Read(v) => self.core.obs_get_object_request(path, v)?,
@@ -315,6 +324,24 @@ impl Accessor for ObsBackend {
                 self.core
                     .obs_put_object_request(path, None, v, AsyncBody::Empty)?
             }
+            PresignOperation::Delete(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs doesn't support presign delete",
+                ))
+            }
+            PresignOperation::UploadPart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs doesn't support presign upload part",
+                ))
+            }
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs doesn't support presign post",
+                ))
+            }
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
@@ -356,7 +383,10 @@ impl Accessor for ObsBackend {
         let w = if args.append() {
             ObsWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            ObsWriters::One(oio::MultipartUploadWriter::new(writer))
+            ObsWriters::One(
+                oio::MultipartUploadWriter::new(writer, args.concurrent())
+                    .with_resume(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -383,7 +413,13 @@ impl Accessor for ObsBackend {
 
         // The response is very similar to azblob.
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut rp = parse_into_metadata(path, resp.headers()).map(RpStat::new)?;
+                if args.raw_headers() {
+                    rp = rp.with_headers(resp.headers().clone());
+                }
+                Ok(rp)
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -48,7 +48,7 @@ impl ObsWriter {
 
 #[async_trait]
 impl oio::MultipartUploadWrite for ObsWriter {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let mut req = self
             .core
             .obs_put_object_request(&self.path, Some(size), &self.op, body)?;
@@ -61,8 +61,9 @@ impl oio::MultipartUploadWrite for ObsWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -126,7 +127,11 @@ impl oio::MultipartUploadWrite for ObsWriter {
         }
     }
 
-    async fn complete_part(&self, upload_id: &str, parts: &[MultipartUploadPart]) -> Result<()> {
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[MultipartUploadPart],
+    ) -> Result<Metadata> {
         let parts = parts
             .iter()
             .map(|p| CompleteMultipartUploadRequestPart {
@@ -144,9 +149,10 @@ impl oio::MultipartUploadWrite for ObsWriter {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
 
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
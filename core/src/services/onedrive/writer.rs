@@ -46,7 +46,7 @@ impl OneDriveWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for OneDriveWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let bs = bs.bytes(bs.remaining());
         let size = bs.len();
 
@@ -56,7 +56,7 @@ impl oio::OneShotWrite for OneDriveWriter {
             self.write_chunked(bs).await?;
         }
 
-        Ok(())
+        Ok(Metadata::new(EntryMode::FILE))
     }
 }
 
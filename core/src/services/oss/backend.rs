@@ -391,6 +391,7 @@ impl Accessor for OssBackend {
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
+                stat_with_raw_headers: true,
                 stat_with_if_none_match: true,
 
                 read: true,
@@ -403,6 +404,7 @@ impl Accessor for OssBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_with_content_disposition: true,
@@ -478,7 +480,10 @@ impl Accessor for OssBackend {
         let w = if args.append() {
             OssWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            OssWriters::One(oio::MultipartUploadWriter::new(writer))
+            OssWriters::One(
+                oio::MultipartUploadWriter::new(writer, args.concurrent())
+                    .with_resume(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -506,7 +511,13 @@ impl Accessor for OssBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut rp = parse_into_metadata(path, resp.headers()).map(RpStat::new)?;
+                if args.raw_headers() {
+                    rp = rp.with_headers(resp.headers().clone());
+                }
+                Ok(rp)
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
@@ -535,6 +546,13 @@ impl Accessor for OssBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() || !args.query().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "oss doesn't support presign with extra headers or query",
+            ));
+        }
+
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => {
@@ -553,6 +571,24 @@ impl Accessor for OssBackend {
                 self.core
                     .oss_put_object_request(path, None, v, AsyncBody::Empty, true)?
             }
+            PresignOperation::Delete(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "oss doesn't support presign delete",
+                ))
+            }
+            PresignOperation::UploadPart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "oss doesn't support presign upload part",
+                ))
+            }
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "oss doesn't support presign post",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
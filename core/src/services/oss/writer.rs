@@ -47,7 +47,7 @@ impl OssWriter {
 
 #[async_trait]
 impl oio::MultipartUploadWrite for OssWriter {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let mut req =
             self.core
                 .oss_put_object_request(&self.path, Some(size), &self.op, body, false)?;
@@ -60,8 +60,9 @@ impl oio::MultipartUploadWrite for OssWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -135,7 +136,7 @@ impl oio::MultipartUploadWrite for OssWriter {
         &self,
         upload_id: &str,
         parts: &[oio::MultipartUploadPart],
-    ) -> Result<()> {
+    ) -> Result<Metadata> {
         let parts = parts
             .iter()
             .map(|p| MultipartUploadPart {
@@ -153,9 +154,10 @@ impl oio::MultipartUploadWrite for OssWriter {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
 
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
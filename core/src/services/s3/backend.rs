@@ -25,7 +25,12 @@ use async_trait::async_trait;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Buf;
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Method;
 use http::StatusCode;
+use http::Uri;
 use log::debug;
 use log::warn;
 use md5::Digest;
@@ -964,6 +969,7 @@ impl Builder for S3Builder {
                 default_storage_class,
                 allow_anonymous: self.config.allow_anonymous,
                 disable_stat_with_override: self.config.disable_stat_with_override,
+                region,
                 signer,
                 loader,
                 client,
@@ -998,15 +1004,20 @@ impl Accessor for S3Backend {
                 stat: true,
                 stat_with_if_match: true,
                 stat_with_if_none_match: true,
+                stat_with_if_modified_since: true,
+                stat_with_if_unmodified_since: true,
                 stat_with_override_cache_control: !self.core.disable_stat_with_override,
                 stat_with_override_content_disposition: !self.core.disable_stat_with_override,
                 stat_with_override_content_type: !self.core.disable_stat_with_override,
+                stat_with_raw_headers: true,
 
                 read: true,
                 read_can_next: true,
                 read_with_range: true,
                 read_with_if_match: true,
                 read_with_if_none_match: true,
+                read_with_if_modified_since: true,
+                read_with_if_unmodified_since: true,
                 read_with_override_cache_control: true,
                 read_with_override_content_disposition: true,
                 read_with_override_content_type: true,
@@ -1014,8 +1025,17 @@ impl Accessor for S3Backend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_resume: true,
+                write_with_verify_parts: true,
+                write_with_expires: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_content_encoding: true,
+                write_with_content_md5: true,
+                write_with_storage_class: true,
+                write_with_if_not_exists: true,
+                write_with_if_match: true,
+                write_with_if_none_match: true,
                 // The min multipart size of S3 is 5 MiB.
                 //
                 // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
@@ -1030,17 +1050,27 @@ impl Accessor for S3Backend {
                 },
 
                 delete: true,
+                delete_with_if_match: true,
                 copy: true,
+                copy_with_if_none_match: true,
+                copy_with_metadata_directive: true,
 
                 list: true,
                 list_with_limit: true,
                 list_with_start_after: true,
                 list_with_recursive: true,
+                list_with_versions: true,
+                list_with_delimiter: true,
 
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_delete: true,
+                presign_upload_part: true,
+                presign_post: true,
+
+                restore: true,
 
                 batch: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
@@ -1074,15 +1104,27 @@ impl Accessor for S3Backend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.content_md5().is_some() && args.resume().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "content md5 is not supported while resuming multipart upload",
+            ));
+        }
+
+        let concurrent = args.concurrent();
+        let resume = args.resume().cloned();
+        let verify_parts = args.verify_parts();
         let writer = S3Writer::new(self.core.clone(), path, args);
 
-        let w = oio::MultipartUploadWriter::new(writer);
+        let w = oio::MultipartUploadWriter::new(writer, concurrent)
+            .with_resume(resume)
+            .with_verify_parts(verify_parts);
 
         Ok((RpWrite::default(), w))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self.core.s3_copy_object(from, to).await?;
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let resp = self.core.s3_copy_object(from, to, &args).await?;
 
         let status = resp.status();
 
@@ -1099,18 +1141,47 @@ impl Accessor for S3Backend {
     }
 
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        // Head object without version will always return the latest version.
+        let is_latest = args.version().is_none();
+        let raw_headers = args.raw_headers();
         let resp = self.core.s3_head_object(path, args).await?;
 
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let headers = resp.headers();
+                let meta = parse_head_object_metadata(path, headers, is_latest)?;
+
+                let mut rp = RpStat::new(meta);
+                if raw_headers {
+                    rp = rp.with_headers(headers.clone());
+                }
+                Ok(rp)
+            }
+            // Head a delete marker with version will return `405 Method Not Allowed`
+            // with `x-amz-delete-marker: true`.
+            StatusCode::METHOD_NOT_ALLOWED
+                if !is_latest
+                    && parse_header_to_str(resp.headers(), constants::X_AMZ_DELETE_MARKER)?
+                        == Some("true") =>
+            {
+                let mut meta = Metadata::new(EntryMode::from_path(path));
+                if let Some(v) = parse_header_to_str(resp.headers(), constants::X_AMZ_VERSION_ID)? {
+                    meta.set_version(v);
+                }
+                meta.set_is_deleted(true);
+                meta.set_content_length(0);
+                resp.into_body().consume().await?;
+
+                Ok(RpStat::new(meta))
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.s3_delete_object(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.s3_delete_object(path, &args).await?;
 
         let status = resp.status();
 
@@ -1129,28 +1200,91 @@ impl Accessor for S3Backend {
             self.core.clone(),
             path,
             args.recursive(),
+            args.delimiter(),
             args.limit(),
             args.start_after(),
+            args.versions(),
         );
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
-    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
-        let (expire, op) = args.into_parts();
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.s3_restore_object(path, &args).await?;
+
+        let status = resp.status();
 
+        match status {
+            // - `202 Accepted` means the restore request has been accepted.
+            // - `200 OK` means the object has been restored already and its
+            //    expiry date is updated.
+            StatusCode::ACCEPTED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpRestore::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
-        let mut req = match op {
-            PresignOperation::Stat(v) => self.core.s3_head_object_request(path, v)?,
-            PresignOperation::Read(v) => self.core.s3_get_object_request(path, v)?,
-            PresignOperation::Write(_) => self.core.s3_put_object_request(
+        let mut req = match args.operation() {
+            PresignOperation::Stat(v) => self.core.s3_head_object_request(path, v.clone())?,
+            PresignOperation::Read(v) => self.core.s3_get_object_request(path, v.clone())?,
+            PresignOperation::Write(v) => {
+                self.core
+                    .s3_put_object_request(path, None, v, AsyncBody::Empty)?
+            }
+            PresignOperation::Delete(v) => self.core.s3_delete_object_request(path, v)?,
+            PresignOperation::UploadPart(v) => self.core.s3_upload_part_request(
                 path,
+                v.upload_id(),
+                v.part_number(),
+                None,
                 None,
-                &OpWrite::default(),
                 AsyncBody::Empty,
             )?,
+            PresignOperation::Post(v) => {
+                let (url, fields) = self.core.presign_post(path, args.expire(), v).await?;
+                let uri = url
+                    .parse::<Uri>()
+                    .map_err(|err| new_request_build_error(err.into()))?;
+
+                return Ok(RpPresign::new(PresignedRequest::new(
+                    Method::POST,
+                    uri,
+                    HeaderMap::new(),
+                ))
+                .with_fields(fields));
+            }
         };
 
-        self.core.sign_query(&mut req, expire).await?;
+        // Extra headers and query must be added before signing so that
+        // they become part of the signature.
+        for (key, value) in args.headers() {
+            let key = HeaderName::from_bytes(key.as_bytes())
+                .map_err(|err| new_request_build_error(err.into()))?;
+            let value =
+                HeaderValue::from_str(value).map_err(|err| new_request_build_error(err.into()))?;
+            req.headers_mut().insert(key, value);
+        }
+        if !args.query().is_empty() {
+            let mut uri = req.uri().to_string();
+            for (key, value) in args.query() {
+                uri.push(if uri.contains('?') { '&' } else { '?' });
+                write!(
+                    uri,
+                    "{}={}",
+                    percent_encode_path(key),
+                    percent_encode_path(value)
+                )
+                .expect("write into string must succeed");
+            }
+            *req.uri_mut() = uri
+                .parse::<Uri>()
+                .map_err(|err| new_request_build_error(err.into()))?;
+        }
+
+        self.core.sign_query(&mut req, args.expire()).await?;
 
         // We don't need this request anymore, consume it directly.
         let (parts, _) = req.into_parts();
@@ -1172,7 +1306,12 @@ impl Accessor for S3Backend {
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths = ops.into_iter().map(|(p, _)| p).collect();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(op) => (p, op),
+            })
+            .collect();
 
         let resp = self.core.s3_delete_objects(paths).await?;
 
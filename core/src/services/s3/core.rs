@@ -21,15 +21,25 @@ use std::fmt::Formatter;
 use std::fmt::Write;
 use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Bytes;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::HeaderName;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::header::EXPIRES;
 use http::header::HOST;
 use http::header::IF_MATCH;
+use http::header::IF_MODIFIED_SINCE;
 use http::header::IF_NONE_MATCH;
+use http::header::IF_UNMODIFIED_SINCE;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
@@ -38,12 +48,16 @@ use reqsign::AwsCredentialLoad;
 use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha2::Sha256;
 
 use crate::raw::*;
 use crate::*;
 
-mod constants {
+pub mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
+    pub const X_AMZ_METADATA_DIRECTIVE: &str = "x-amz-metadata-directive";
+    pub const X_AMZ_META_PREFIX: &str = "x-amz-meta-";
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
@@ -55,6 +69,9 @@ mod constants {
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
         "x-amz-server-side-encryption-aws-kms-key-id";
     pub const X_AMZ_STORAGE_CLASS: &str = "x-amz-storage-class";
+    pub const X_AMZ_RESTORE: &str = "x-amz-restore";
+    pub const X_AMZ_VERSION_ID: &str = "x-amz-version-id";
+    pub const X_AMZ_DELETE_MARKER: &str = "x-amz-delete-marker";
 
     pub const X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
         "x-amz-copy-source-server-side-encryption-customer-algorithm";
@@ -66,6 +83,8 @@ mod constants {
     pub const RESPONSE_CONTENT_DISPOSITION: &str = "response-content-disposition";
     pub const RESPONSE_CONTENT_TYPE: &str = "response-content-type";
     pub const RESPONSE_CACHE_CONTROL: &str = "response-cache-control";
+
+    pub const QUERY_VERSION_ID: &str = "versionId";
 }
 
 pub struct S3Core {
@@ -81,6 +100,7 @@ pub struct S3Core {
     pub allow_anonymous: bool,
     pub disable_stat_with_override: bool,
 
+    pub region: String,
     pub signer: AwsV4Signer,
     pub loader: Box<dyn AwsCredentialLoad>,
     pub client: HttpClient,
@@ -165,6 +185,103 @@ impl S3Core {
         Ok(())
     }
 
+    /// Build a POST policy and its form fields for browser based uploads.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html>
+    pub async fn presign_post(
+        &self,
+        path: &str,
+        expire: Duration,
+        args: &OpPresignPost,
+    ) -> Result<(String, Vec<(String, String)>)> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/", self.endpoint);
+
+        let mut fields = vec![("key".to_string(), p.clone())];
+        let mut conditions = vec![json!({ "bucket": self.bucket }), json!(["eq", "$key", p])];
+        for cond in args.conditions() {
+            match cond {
+                PostPolicyCondition::ContentLengthRange(min, max) => {
+                    conditions.push(json!(["content-length-range", min, max]))
+                }
+                PostPolicyCondition::Eq(k, v) => {
+                    conditions.push(json!(["eq", format!("${k}"), v]));
+                    fields.push((k.to_string(), v.to_string()));
+                }
+                PostPolicyCondition::StartsWith(k, v) => {
+                    conditions.push(json!(["starts-with", format!("${k}"), v]))
+                }
+            }
+        }
+
+        let cred = if let Some(cred) = self.load_credential().await? {
+            cred
+        } else {
+            // Anonymous uploads don't need any policy.
+            return Ok((url, fields));
+        };
+
+        let now = Utc::now();
+        let date = now.format("%Y%m%d").to_string();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let credential = format!(
+            "{}/{}/{}/s3/aws4_request",
+            cred.access_key_id, date, self.region
+        );
+
+        let mut signed_fields = vec![
+            (
+                "x-amz-algorithm".to_string(),
+                "AWS4-HMAC-SHA256".to_string(),
+            ),
+            ("x-amz-credential".to_string(), credential),
+            ("x-amz-date".to_string(), amz_date),
+        ];
+        if let Some(token) = &cred.session_token {
+            signed_fields.push(("x-amz-security-token".to_string(), token.to_string()));
+        }
+        for (k, v) in &signed_fields {
+            conditions.push(json!({ k: v }));
+        }
+
+        let expiration = now
+            + chrono::Duration::from_std(expire).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "presign expire is out of range").set_source(err)
+            })?;
+        let policy = json!({
+            "expiration": expiration.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let hmac_sha256 = |key: &[u8], content: &[u8]| -> Vec<u8> {
+            let mut h =
+                Hmac::<Sha256>::new_from_slice(key).expect("hmac must accept any key length");
+            h.update(content);
+            h.finalize().into_bytes().to_vec()
+        };
+        let signing_key = [self.region.as_str(), "s3", "aws4_request"].iter().fold(
+            hmac_sha256(
+                format!("AWS4{}", cred.secret_access_key).as_bytes(),
+                date.as_bytes(),
+            ),
+            |key, v| hmac_sha256(&key, v.as_bytes()),
+        );
+        let signature =
+            hmac_sha256(&signing_key, policy.as_bytes())
+                .iter()
+                .fold(String::new(), |mut s, b| {
+                    write!(s, "{b:02x}").expect("write into string must succeed");
+                    s
+                });
+
+        fields.extend(signed_fields);
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-amz-signature".to_string(), signature));
+
+        Ok((url, fields))
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
         self.client.send(req).await
@@ -261,6 +378,13 @@ impl S3Core {
                 percent_encode_path(override_cache_control)
             ))
         }
+        if let Some(version) = args.version() {
+            query_args.push(format!(
+                "{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ))
+        }
         if !query_args.is_empty() {
             url.push_str(&format!("?{}", query_args.join("&")));
         }
@@ -277,6 +401,20 @@ impl S3Core {
             req = req.header(IF_MATCH, if_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(
+                IF_MODIFIED_SINCE,
+                format_datetime_into_http_date(if_modified_since),
+            );
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(
+                IF_UNMODIFIED_SINCE,
+                format_datetime_into_http_date(if_unmodified_since),
+            );
+        }
+
         let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
@@ -313,6 +451,13 @@ impl S3Core {
                 percent_encode_path(override_cache_control)
             ))
         }
+        if let Some(version) = args.version() {
+            query_args.push(format!(
+                "{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ))
+        }
         if !query_args.is_empty() {
             url.push_str(&format!("?{}", query_args.join("&")));
         }
@@ -331,6 +476,20 @@ impl S3Core {
         if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
         }
+
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(
+                IF_MODIFIED_SINCE,
+                format_datetime_into_http_date(if_modified_since),
+            );
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(
+                IF_UNMODIFIED_SINCE,
+                format_datetime_into_http_date(if_unmodified_since),
+            );
+        }
         // Set SSE headers.
         // TODO: how will this work with presign?
         req = self.insert_sse_headers(req, false);
@@ -379,12 +538,44 @@ impl S3Core {
             req = req.header(CONTENT_DISPOSITION, pos)
         }
 
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding)
+        }
+
+        if let Some(content_md5) = args.content_md5() {
+            req = req.header("CONTENT-MD5", content_md5)
+        }
+
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(expires) = args.expires() {
+            req = req.header(EXPIRES, format_datetime_into_http_date(expires))
+        }
+
+        if let Some(user_metadata) = args.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("{}{key}", constants::X_AMZ_META_PREFIX), value)
+            }
+        }
+
+        if args.if_not_exists() {
+            req = req.header(IF_NONE_MATCH, "*")
+        }
+
+        if let Some(if_match) = args.if_match() {
+            req = req.header(IF_MATCH, if_match)
+        }
+
+        if let Some(if_none_match) = args.if_none_match() {
+            req = req.header(IF_NONE_MATCH, if_none_match)
+        }
+
         // Set storage class header
-        if let Some(v) = &self.default_storage_class {
+        if let Some(v) = args.storage_class() {
+            req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
+        } else if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
@@ -409,13 +600,79 @@ impl S3Core {
         self.send(req).await
     }
 
-    pub async fn s3_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub fn s3_delete_object_request(
+        &self,
+        path: &str,
+        args: &OpDelete,
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
 
-        let mut req = Request::delete(&url)
-            .body(AsyncBody::Empty)
+        if let Some(version) = args.version() {
+            url.push_str(&format!(
+                "?{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ));
+        }
+
+        let mut req = Request::delete(&url);
+
+        if let Some(if_match) = args.if_match() {
+            req = req.header(IF_MATCH, if_match);
+        }
+
+        req.body(AsyncBody::Empty).map_err(new_request_build_error)
+    }
+
+    pub async fn s3_delete_object(
+        &self,
+        path: &str,
+        args: &OpDelete,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_delete_object_request(path, args)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_restore_object(
+        &self,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        if let Some(version) = args.version() {
+            url.push_str(&format!(
+                "&{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ));
+        }
+
+        let req = Request::post(&url);
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            // S3 requires days to be set for restoring archived objects.
+            days: args.days().unwrap_or(1),
+            glacier_job_parameters: args.tier().map(|tier| RestoreRequestGlacierJobParameters {
+                tier: tier.to_string(),
+            }),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        let req = req.header(CONTENT_LENGTH, content.len());
+        // Set content-type to `application/xml` to avoid mixed with form post.
+        let req = req.header(CONTENT_TYPE, "application/xml");
+
+        let mut req = req
+            .body(AsyncBody::Bytes(Bytes::from(content)))
             .map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
@@ -427,6 +684,7 @@ impl S3Core {
         &self,
         from: &str,
         to: &str,
+        args: &OpCopy,
     ) -> Result<Response<IncomingAsyncBody>> {
         let from = build_abs_path(&self.root, from);
         let to = build_abs_path(&self.root, to);
@@ -475,6 +733,24 @@ impl S3Core {
             )
         }
 
+        if let Some(if_none_match) = args.if_none_match() {
+            req = req.header(IF_NONE_MATCH, if_none_match);
+        }
+
+        // Setting content type requires replacing the metadata of the target.
+        let directive = match (args.metadata_directive(), args.content_type()) {
+            (Some(MetadataDirective::Replace), _) | (_, Some(_)) => Some("REPLACE"),
+            (Some(MetadataDirective::Copy), None) => Some("COPY"),
+            (None, None) => None,
+        };
+        if let Some(directive) = directive {
+            req = req.header(constants::X_AMZ_METADATA_DIRECTIVE, directive);
+        }
+
+        if let Some(mime) = args.content_type() {
+            req = req.header(CONTENT_TYPE, mime);
+        }
+
         let mut req = req
             .header(constants::X_AMZ_COPY_SOURCE, &source)
             .body(AsyncBody::Empty)
@@ -501,7 +777,8 @@ impl S3Core {
                 .expect("write into string must succeed");
         }
         if !delimiter.is_empty() {
-            write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
+            write!(url, "&delimiter={}", percent_encode_path(delimiter))
+                .expect("write into string must succeed");
         }
         if let Some(limit) = limit {
             write!(url, "&max-keys={limit}").expect("write into string must succeed");
@@ -533,6 +810,50 @@ impl S3Core {
         self.send(req).await
     }
 
+    pub async fn s3_list_object_versions(
+        &self,
+        prefix: &str,
+        delimiter: &str,
+        limit: Option<usize>,
+        key_marker: &str,
+        version_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = format!("{}?versions", self.endpoint);
+        if !p.is_empty() {
+            write!(url, "&prefix={}", percent_encode_path(&p))
+                .expect("write into string must succeed");
+        }
+        if !delimiter.is_empty() {
+            write!(url, "&delimiter={}", percent_encode_path(delimiter))
+                .expect("write into string must succeed");
+        }
+        if let Some(limit) = limit {
+            write!(url, "&max-keys={limit}").expect("write into string must succeed");
+        }
+        if !key_marker.is_empty() {
+            write!(url, "&key-marker={}", percent_encode_path(key_marker))
+                .expect("write into string must succeed");
+        }
+        if !version_id_marker.is_empty() {
+            write!(
+                url,
+                "&version-id-marker={}",
+                percent_encode_path(version_id_marker)
+            )
+            .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_initiate_multipart_upload(
         &self,
         path: &str,
@@ -552,12 +873,28 @@ impl S3Core {
             req = req.header(CONTENT_DISPOSITION, content_disposition)
         }
 
+        if let Some(content_encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, content_encoding)
+        }
+
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(expires) = args.expires() {
+            req = req.header(EXPIRES, format_datetime_into_http_date(expires))
+        }
+
+        if let Some(user_metadata) = args.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("{}{key}", constants::X_AMZ_META_PREFIX), value)
+            }
+        }
+
         // Set storage class header
-        if let Some(v) = &self.default_storage_class {
+        if let Some(v) = args.storage_class() {
+            req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
+        } else if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
@@ -578,7 +915,8 @@ impl S3Core {
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
+        content_md5: Option<&str>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -593,7 +931,13 @@ impl S3Core {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        if let Some(content_md5) = content_md5 {
+            req = req.header("CONTENT-MD5", content_md5)
+        }
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -609,6 +953,7 @@ impl S3Core {
         path: &str,
         upload_id: &str,
         parts: Vec<CompleteMultipartUploadRequestPart>,
+        args: &OpWrite,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -619,7 +964,20 @@ impl S3Core {
             percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        let mut req = Request::post(&url);
+
+        // The condition of multipart upload is checked while completing.
+        if args.if_not_exists() {
+            req = req.header(IF_NONE_MATCH, "*")
+        }
+
+        if let Some(if_match) = args.if_match() {
+            req = req.header(IF_MATCH, if_match)
+        }
+
+        if let Some(if_none_match) = args.if_none_match() {
+            req = req.header(IF_NONE_MATCH, if_none_match)
+        }
 
         // Set SSE headers.
         let req = self.insert_sse_headers(req, true);
@@ -664,7 +1022,7 @@ impl S3Core {
 
     pub async fn s3_delete_objects(
         &self,
-        paths: Vec<String>,
+        paths: Vec<(String, OpDelete)>,
     ) -> Result<Response<IncomingAsyncBody>> {
         let url = format!("{}/?delete", self.endpoint);
 
@@ -673,8 +1031,9 @@ impl S3Core {
         let content = quick_xml::se::to_string(&DeleteObjectsRequest {
             object: paths
                 .into_iter()
-                .map(|path| DeleteObjectsRequestObject {
+                .map(|(path, op)| DeleteObjectsRequestObject {
                     key: build_abs_path(&self.root, &path),
+                    version_id: op.version().map(|v| v.to_string()),
                 })
                 .collect(),
         })
@@ -748,6 +1107,29 @@ pub struct CompleteMultipartUploadRequestPart {
     pub etag: String,
 }
 
+/// Result of CompleteMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CompleteMultipartUploadResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glacier_job_parameters: Option<RestoreRequestGlacierJobParameters>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RestoreRequestGlacierJobParameters {
+    pub tier: String,
+}
+
 /// Request of DeleteObjects.
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "Delete", rename_all = "PascalCase")]
@@ -759,6 +1141,8 @@ pub struct DeleteObjectsRequest {
 #[serde(rename_all = "PascalCase")]
 pub struct DeleteObjectsRequestObject {
     pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
 }
 
 /// Result of DeleteObjects.
@@ -809,6 +1193,7 @@ pub struct ListObjectsOutputContent {
     pub last_modified: String,
     #[serde(rename = "ETag")]
     pub etag: Option<String>,
+    pub storage_class: Option<String>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
@@ -817,6 +1202,121 @@ pub struct OutputCommonPrefix {
     pub prefix: String,
 }
 
+/// Output of ListObjectVersions.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListObjectVersionsOutput {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_version_id_marker: Option<String>,
+    pub common_prefixes: Vec<OutputCommonPrefix>,
+    pub version: Vec<ListObjectVersionsOutputVersion>,
+    pub delete_marker: Vec<ListObjectVersionsOutputDeleteMarker>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListObjectVersionsOutputVersion {
+    pub key: String,
+    pub version_id: String,
+    pub is_latest: bool,
+    pub size: u64,
+    pub last_modified: String,
+    #[serde(rename = "ETag")]
+    pub etag: Option<String>,
+    pub storage_class: Option<String>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListObjectVersionsOutputDeleteMarker {
+    pub key: String,
+    pub version_id: String,
+    pub is_latest: bool,
+    pub last_modified: String,
+}
+
+/// Parse the metadata of object from the response headers of `HeadObject`.
+///
+/// `is_latest` should be `true` only if the object is headed without version.
+pub fn parse_head_object_metadata(
+    path: &str,
+    headers: &HeaderMap,
+    is_latest: bool,
+) -> Result<Metadata> {
+    let mut meta = parse_into_metadata(path, headers)?;
+
+    // Only versioned buckets will return `x-amz-version-id`.
+    if let Some(v) = parse_header_to_str(headers, constants::X_AMZ_VERSION_ID)? {
+        meta.set_version(v);
+    }
+    if is_latest {
+        meta.set_is_latest(true);
+    }
+
+    // S3 doesn't return `Content-MD5`, but the etag of objects uploaded in a
+    // single request without SSE-KMS or SSE-C is the md5 of the content.
+    let encryption = parse_header_to_str(headers, constants::X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+    let customer_algorithm = parse_header_to_str(
+        headers,
+        constants::X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+    )?;
+    if meta.content_md5().is_none()
+        && !matches!(encryption, Some(v) if v.starts_with("aws:kms"))
+        && customer_algorithm.is_none()
+    {
+        let md5 = meta
+            .etag()
+            .map(|v| v.trim_matches('"'))
+            .filter(|v| v.len() == 32 && v.bytes().all(|b| b.is_ascii_hexdigit()))
+            .map(String::from);
+        if let Some(md5) = md5 {
+            meta.set_content_md5(&md5);
+        }
+    }
+
+    // S3 will not return `x-amz-storage-class` for `STANDARD` objects.
+    let storage_class =
+        parse_header_to_str(headers, constants::X_AMZ_STORAGE_CLASS)?.unwrap_or("STANDARD");
+    meta.set_storage_class(storage_class);
+    meta.set_restore_status(parse_restore_status(
+        storage_class,
+        parse_header_to_str(headers, constants::X_AMZ_RESTORE)?,
+    )?);
+
+    Ok(meta)
+}
+
+/// Parse the restore status of object by its storage class and `x-amz-restore` header.
+///
+/// `x-amz-restore` will be returned only if the object is being restored or has been
+/// restored, for example:
+///
+/// - `ongoing-request="true"`
+/// - `ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"`
+pub fn parse_restore_status(storage_class: &str, restore: Option<&str>) -> Result<RestoreStatus> {
+    let Some(restore) = restore else {
+        return match storage_class {
+            "GLACIER" | "DEEP_ARCHIVE" => Ok(RestoreStatus::Archived),
+            _ => Ok(RestoreStatus::NotArchived),
+        };
+    };
+
+    if restore.contains(r#"ongoing-request="true""#) {
+        return Ok(RestoreStatus::InProgress);
+    }
+
+    match restore.split_once(r#"expiry-date=""#) {
+        Some((_, v)) => {
+            let expiry = v.split('"').next().unwrap_or_default();
+            Ok(RestoreStatus::Restored(parse_datetime_from_rfc2822(
+                expiry,
+            )?))
+        }
+        None => Ok(RestoreStatus::Archived),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -845,6 +1345,25 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Examples
+    #[test]
+    fn test_deserialize_complete_multipart_upload_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <CompleteMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+             <Location>http://Example-Bucket.s3.region.amazonaws.com/Example-Object</Location>
+             <Bucket>Example-Bucket</Bucket>
+             <Key>Example-Object</Key>
+             <ETag>"3858f62230ac3c915f300c664312c11f-9"</ETag>
+            </CompleteMultipartUploadResult>"#,
+        );
+
+        let out: CompleteMultipartUploadResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.etag, "\"3858f62230ac3c915f300c664312c11f-9\"")
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Examples
     #[test]
     fn test_serialize_complete_multipart_upload_request() {
@@ -897,9 +1416,11 @@ mod tests {
             object: vec![
                 DeleteObjectsRequestObject {
                     key: "sample1.txt".to_string(),
+                    version_id: None,
                 },
                 DeleteObjectsRequestObject {
                     key: "sample2.txt".to_string(),
+                    version_id: None,
                 },
             ],
         };
@@ -921,6 +1442,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_serialize_delete_objects_request_with_version() {
+        let req = DeleteObjectsRequest {
+            object: vec![DeleteObjectsRequestObject {
+                key: "sample1.txt".to_string(),
+                version_id: Some("3HL4kqtJvjVBH40Nrjfkd".to_string()),
+            }],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<Delete>
+             <Object>
+             <Key>sample1.txt</Key>
+             <VersionId>3HL4kqtJvjVBH40Nrjfkd</VersionId>
+             </Object>
+             </Delete>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
     #[test]
     fn test_deserialize_delete_objects_result() {
@@ -1007,20 +1552,139 @@ mod tests {
                     size: 56,
                     etag: Some("\"d41d8cd98f00b204e9800998ecf8427e\"".to_string()),
                     last_modified: "2016-04-30T23:51:29.000Z".to_string(),
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 ListObjectsOutputContent {
                     key: "photos/2007".to_string(),
                     size: 100,
                     last_modified: "2016-04-30T23:51:29.000Z".to_string(),
                     etag: Some("\"d41d8cd98f00b204e9800998ecf8427e\"".to_string()),
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 ListObjectsOutputContent {
                     key: "photos/2008".to_string(),
                     size: 42,
                     last_modified: "2016-05-30T23:51:29.000Z".to_string(),
                     etag: None,
+                    storage_class: None,
                 },
             ]
         )
     }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjectVersions.html#API_ListObjectVersions_Examples
+    #[test]
+    fn test_parse_list_object_versions_output() {
+        let bs = bytes::Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListVersionsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01">
+  <Name>bucket</Name>
+  <Prefix>my</Prefix>
+  <KeyMarker/>
+  <VersionIdMarker/>
+  <NextKeyMarker>my-second-image.jpg</NextKeyMarker>
+  <NextVersionIdMarker>03jpff543dhffds434rfdsFDN943fdsFkdmqnh892</NextVersionIdMarker>
+  <MaxKeys>5</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <Version>
+    <Key>my-image.jpg</Key>
+    <VersionId>3/L4kqtJl40Nr8X8gdRQBpUMLUo</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2009-10-12T17:50:30.000Z</LastModified>
+    <ETag>"fba9dede5f27731c9771645a39863328"</ETag>
+    <Size>434234</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Version>
+  <DeleteMarker>
+    <Key>my-second-image.jpg</Key>
+    <VersionId>03jpff543dhffds434rfdsFDN943fdsFkdmqnh892</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2009-11-12T17:50:30.000Z</LastModified>
+  </DeleteMarker>
+</ListVersionsResult>"#,
+        );
+
+        let out: ListObjectVersionsOutput =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert!(out.is_truncated.unwrap());
+        assert_eq!(out.next_key_marker.as_deref(), Some("my-second-image.jpg"));
+        assert_eq!(
+            out.next_version_id_marker.as_deref(),
+            Some("03jpff543dhffds434rfdsFDN943fdsFkdmqnh892")
+        );
+        assert_eq!(
+            out.version,
+            vec![ListObjectVersionsOutputVersion {
+                key: "my-image.jpg".to_string(),
+                version_id: "3/L4kqtJl40Nr8X8gdRQBpUMLUo".to_string(),
+                is_latest: true,
+                size: 434234,
+                last_modified: "2009-10-12T17:50:30.000Z".to_string(),
+                etag: Some("\"fba9dede5f27731c9771645a39863328\"".to_string()),
+                storage_class: Some("STANDARD".to_string()),
+            }]
+        );
+        assert_eq!(
+            out.delete_marker,
+            vec![ListObjectVersionsOutputDeleteMarker {
+                key: "my-second-image.jpg".to_string(),
+                version_id: "03jpff543dhffds434rfdsFDN943fdsFkdmqnh892".to_string(),
+                is_latest: true,
+                last_modified: "2009-11-12T17:50:30.000Z".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_restore_status() {
+        let cases = vec![
+            ("STANDARD", None, RestoreStatus::NotArchived),
+            ("GLACIER", None, RestoreStatus::Archived),
+            (
+                "GLACIER",
+                Some(r#"ongoing-request="true""#),
+                RestoreStatus::InProgress,
+            ),
+            (
+                "DEEP_ARCHIVE",
+                Some(r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#),
+                RestoreStatus::Restored(
+                    parse_datetime_from_rfc2822("Fri, 21 Dec 2012 00:00:00 GMT").unwrap(),
+                ),
+            ),
+        ];
+
+        for (storage_class, restore, expected) in cases {
+            let actual = parse_restore_status(storage_class, restore).expect("must success");
+            assert_eq!(actual, expected, "{storage_class} {restore:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_head_object_metadata() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("5"));
+        headers.insert(
+            http::header::ETAG,
+            HeaderValue::from_static("\"5d41402abc4b2a76b9719d911017c592\""),
+        );
+
+        let meta = parse_head_object_metadata("hello", &headers, true).expect("must success");
+        assert_eq!(meta.version(), None);
+        assert_eq!(meta.is_latest(), Some(true));
+        assert_eq!(meta.content_md5(), Some("5d41402abc4b2a76b9719d911017c592"));
+
+        headers.insert(
+            constants::X_AMZ_VERSION_ID,
+            HeaderValue::from_static("3/L4kqtJl40Nr8X8gdRQBpUMLUo"),
+        );
+        let meta = parse_head_object_metadata("hello", &headers, true).expect("must success");
+        assert_eq!(meta.version(), Some("3/L4kqtJl40Nr8X8gdRQBpUMLUo"));
+        assert_eq!(meta.is_latest(), Some(true));
+
+        let meta = parse_head_object_metadata("hello", &headers, false).expect("must success");
+        assert_eq!(meta.version(), Some("3/L4kqtJl40Nr8X8gdRQBpUMLUo"));
+        assert_eq!(meta.is_latest(), None);
+    }
 }
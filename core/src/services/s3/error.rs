@@ -47,7 +47,8 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         // Service like R2 could return 499 error with a message like:
         // Client Disconnect, we should retry it.
         499 => (ErrorKind::Unexpected, true),
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        500 | 502 | 503 => (ErrorKind::Unexpected, true),
+        504 => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     };
 
@@ -78,7 +79,7 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // > or written to within the timeout period."
         //
         // It's Ok for us to retry it again.
-        "RequestTimeout" => Some((ErrorKind::Unexpected, true)),
+        "RequestTimeout" => Some((ErrorKind::Timeout, true)),
         // > An internal error occurred. Try again.
         "InternalError" => Some((ErrorKind::Unexpected, true)),
         // > A conflicting conditional operation is currently in progress
@@ -94,6 +95,11 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // indicates a temporary issue with the service or server, such as high load,
         // maintenance, or an internal problem.
         "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        // > The Content-MD5 or checksum value that you specified did not match
+        // > what the server received.
+        "BadDigest" => Some((ErrorKind::ChecksumMismatch, false)),
+        // > The Content-MD5 or checksum value that you specified is not valid.
+        "InvalidDigest" => Some((ErrorKind::ChecksumMismatch, false)),
         _ => None,
     }
 }
@@ -21,23 +21,27 @@ use async_trait::async_trait;
 use bytes::Buf;
 use quick_xml::de;
 
+use super::core::ListObjectVersionsOutput;
 use super::core::ListObjectsOutput;
 use super::core::S3Core;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::EntryMode;
 use crate::Metadata;
+use crate::Metakey;
 use crate::Result;
 
 pub struct S3Lister {
     core: Arc<S3Core>,
 
     path: String,
-    delimiter: &'static str,
+    delimiter: String,
     limit: Option<usize>,
 
     /// Amazon S3 starts listing **after** this specified key
     start_after: Option<String>,
+    /// List all versions of objects via `ListObjectVersions` instead.
+    versions: bool,
 }
 
 impl S3Lister {
@@ -45,10 +49,16 @@ impl S3Lister {
         core: Arc<S3Core>,
         path: &str,
         recursive: bool,
+        delimiter: Option<char>,
         limit: Option<usize>,
         start_after: Option<&str>,
+        versions: bool,
     ) -> Self {
-        let delimiter = if recursive { "" } else { "/" };
+        let delimiter = match (recursive, delimiter) {
+            (true, _) => String::new(),
+            (false, Some(c)) => c.to_string(),
+            (false, None) => "/".to_string(),
+        };
         Self {
             core,
 
@@ -56,6 +66,7 @@ impl S3Lister {
             delimiter,
             limit,
             start_after: start_after.map(String::from),
+            versions,
         }
     }
 }
@@ -64,12 +75,22 @@ impl S3Lister {
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl oio::PageList for S3Lister {
     async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        if self.versions {
+            self.next_versions_page(ctx).await
+        } else {
+            self.next_objects_page(ctx).await
+        }
+    }
+}
+
+impl S3Lister {
+    async fn next_objects_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
         let resp = self
             .core
             .s3_list_objects(
                 &self.path,
                 &ctx.token,
-                self.delimiter,
+                &self.delimiter,
                 self.limit,
                 // State after should only be set for the first page.
                 if ctx.token.is_empty() {
@@ -104,10 +125,7 @@ impl oio::PageList for S3Lister {
         ctx.token = output.next_continuation_token.clone().unwrap_or_default();
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let de = build_prefix_entry(&self.core.root, &prefix.prefix);
 
             ctx.entries.push_back(de);
         }
@@ -127,6 +145,9 @@ impl oio::PageList for S3Lister {
                 meta.set_content_md5(etag.trim_matches('"'));
             }
             meta.set_content_length(object.size);
+            if let Some(storage_class) = &object.storage_class {
+                meta.set_storage_class(storage_class);
+            }
 
             // object.last_modified provides more precious time that contains
             // nanosecond, let's trim them.
@@ -138,4 +159,122 @@ impl oio::PageList for S3Lister {
 
         Ok(())
     }
+    /// List all versions of objects.
+    ///
+    /// `ListObjectVersions` requires both `key-marker` and `version-id-marker` to continue,
+    /// so we store them in token like `<key_marker>\n<version_id_marker>`. Version id will
+    /// never contain `\n`, so it's safe to split from the right side.
+    async fn next_versions_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let (key_marker, version_id_marker) = if ctx.token.is_empty() {
+            // Start after should only be set for the first page.
+            let key_marker = self
+                .start_after
+                .as_ref()
+                .map(|v| build_abs_path(&self.core.root, v))
+                .unwrap_or_default();
+            (key_marker, String::new())
+        } else {
+            let (k, v) = ctx
+                .token
+                .rsplit_once('\n')
+                .unwrap_or((ctx.token.as_str(), ""));
+            (k.to_string(), v.to_string())
+        };
+
+        let resp = self
+            .core
+            .s3_list_object_versions(
+                &self.path,
+                &self.delimiter,
+                self.limit,
+                &key_marker,
+                &version_id_marker,
+            )
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+
+        let output: ListObjectVersionsOutput =
+            de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+        ctx.done = if let Some(is_truncated) = output.is_truncated {
+            !is_truncated
+        } else {
+            output.next_key_marker.is_none()
+        };
+        ctx.token = format!(
+            "{}\n{}",
+            output.next_key_marker.unwrap_or_default(),
+            output.next_version_id_marker.unwrap_or_default()
+        );
+
+        for prefix in output.common_prefixes {
+            let de = build_prefix_entry(&self.core.root, &prefix.prefix);
+
+            ctx.entries.push_back(de);
+        }
+
+        for version in output.version {
+            let path = build_rel_path(&self.core.root, &version.key);
+
+            // s3 could return the dir itself in contents.
+            if path == self.path {
+                continue;
+            }
+
+            let mut meta = Metadata::new(EntryMode::from_path(&path));
+            meta.set_version(&version.version_id);
+            meta.set_is_latest(version.is_latest);
+            if let Some(etag) = &version.etag {
+                meta.set_etag(etag);
+                meta.set_content_md5(etag.trim_matches('"'));
+            }
+            meta.set_content_length(version.size);
+            if let Some(storage_class) = &version.storage_class {
+                meta.set_storage_class(storage_class);
+            }
+            meta.set_last_modified(parse_datetime_from_rfc3339(version.last_modified.as_str())?);
+
+            ctx.entries.push_back(oio::Entry::with(path, meta));
+        }
+
+        for marker in output.delete_marker {
+            let path = build_rel_path(&self.core.root, &marker.key);
+
+            // Delete markers don't have any content, mark them as complete so that
+            // we will not try to stat them.
+            let mut meta = Metadata::new(EntryMode::from_path(&path));
+            meta.set_version(&marker.version_id);
+            meta.set_is_deleted(true);
+            meta.set_is_latest(marker.is_latest);
+            meta.set_content_length(0);
+            meta.set_last_modified(parse_datetime_from_rfc3339(marker.last_modified.as_str())?);
+            let meta = meta.with_metakey(meta.metakey() | Metakey::Complete);
+
+            ctx.entries.push_back(oio::Entry::with(path, meta));
+        }
+
+        Ok(())
+    }
+}
+
+/// Build the entry of a common prefix.
+///
+/// Only prefixes grouped by `/` are dirs. Prefixes grouped by custom delimiters
+/// will be returned as unknown entries and marked as complete so that we will
+/// not try to stat them.
+fn build_prefix_entry(root: &str, prefix: &str) -> oio::Entry {
+    let path = build_rel_path(root, prefix);
+    let meta = if path.ends_with('/') {
+        Metadata::new(EntryMode::DIR)
+    } else {
+        let meta = Metadata::new(EntryMode::Unknown);
+        meta.with_metakey(meta.metakey() | Metakey::Complete)
+    };
+
+    oio::Entry::with(path, meta)
 }
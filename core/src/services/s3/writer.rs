@@ -42,12 +42,58 @@ impl S3Writer {
             op,
         }
     }
+
+    /// Upload a part along with its content md5 if given.
+    async fn upload_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        content_md5: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        // AWS S3 requires part number must between [1..=10000]
+        let part_number = part_number + 1;
+
+        let mut req = self.core.s3_upload_part_request(
+            &self.path,
+            upload_id,
+            part_number,
+            Some(size),
+            content_md5,
+            body,
+        )?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(oio::MultipartUploadPart { part_number, etag })
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl oio::MultipartUploadWrite for S3Writer {
-    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<Metadata> {
         let mut req = self
             .core
             .s3_put_object_request(&self.path, Some(size), &self.op, body)?;
@@ -60,14 +106,27 @@ impl oio::MultipartUploadWrite for S3Writer {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let mut meta = parse_write_metadata(resp.headers())?;
+                // Only versioned buckets will return `x-amz-version-id`.
+                if let Some(v) = parse_header_to_str(resp.headers(), constants::X_AMZ_VERSION_ID)? {
+                    meta.set_version(v);
+                }
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
     }
 
     async fn initiate_part(&self) -> Result<String> {
+        // Content md5 covers the whole content and can't be sent along with parts.
+        if self.op.content_md5().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "content md5 is not supported while uploading in multipart",
+            ));
+        }
+
         let resp = self
             .core
             .s3_initiate_multipart_upload(&self.path, &self.op)
@@ -96,43 +155,27 @@ impl oio::MultipartUploadWrite for S3Writer {
         size: u64,
         body: AsyncBody,
     ) -> Result<oio::MultipartUploadPart> {
-        // AWS S3 requires part number must between [1..=10000]
-        let part_number = part_number + 1;
-
-        let mut req =
-            self.core
-                .s3_upload_part_request(&self.path, upload_id, part_number, size, body)?;
-
-        self.core.sign(&mut req).await?;
-
-        let resp = self.core.send(req).await?;
-
-        let status = resp.status();
-
-        match status {
-            StatusCode::OK => {
-                let etag = parse_etag(resp.headers())?
-                    .ok_or_else(|| {
-                        Error::new(
-                            ErrorKind::Unexpected,
-                            "ETag not present in returning response",
-                        )
-                    })?
-                    .to_string();
-
-                resp.into_body().consume().await?;
+        self.upload_part(upload_id, part_number, size, None, body)
+            .await
+    }
 
-                Ok(oio::MultipartUploadPart { part_number, etag })
-            }
-            _ => Err(parse_error(resp).await?),
-        }
+    async fn write_part_with_md5(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        content_md5: &str,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        self.upload_part(upload_id, part_number, size, Some(content_md5), body)
+            .await
     }
 
     async fn complete_part(
         &self,
         upload_id: &str,
         parts: &[oio::MultipartUploadPart],
-    ) -> Result<()> {
+    ) -> Result<Metadata> {
         let parts = parts
             .iter()
             .map(|p| CompleteMultipartUploadRequestPart {
@@ -143,16 +186,28 @@ impl oio::MultipartUploadWrite for S3Writer {
 
         let resp = self
             .core
-            .s3_complete_multipart_upload(&self.path, upload_id, parts)
+            .s3_complete_multipart_upload(&self.path, upload_id, parts, &self.op)
             .await?;
 
         let status = resp.status();
 
         match status {
             StatusCode::OK => {
-                resp.into_body().consume().await?;
+                let mut meta = Metadata::new(EntryMode::FILE);
+                // Only versioned buckets will return `x-amz-version-id`.
+                if let Some(v) = parse_header_to_str(resp.headers(), constants::X_AMZ_VERSION_ID)? {
+                    meta.set_version(v);
+                }
 
-                Ok(())
+                let bs = resp.into_body().bytes().await?;
+                let result: CompleteMultipartUploadResult =
+                    quick_xml::de::from_reader(bytes::Buf::reader(bs))
+                        .map_err(new_xml_deserialize_error)?;
+                if !result.etag.is_empty() {
+                    meta.set_etag(&result.etag);
+                }
+
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
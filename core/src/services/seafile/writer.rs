@@ -45,7 +45,7 @@ impl SeafileWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for SeafileWriter {
-    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn oio::WriteBuf) -> Result<Metadata> {
         let path = build_abs_path(&self.core.root, &self.path);
         let bs = oio::ChunkedBytes::from_vec(bs.vectored_bytes(bs.remaining()));
 
@@ -80,8 +80,9 @@ impl oio::OneShotWrite for SeafileWriter {
 
         match status {
             StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
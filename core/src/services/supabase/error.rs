@@ -41,7 +41,8 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
 
     // Check HTTP status code first/
     let (mut kind, mut retryable) = match parts.status.as_u16() {
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        500 | 502 | 503 => (ErrorKind::Unexpected, true),
+        504 => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     };
 
@@ -77,8 +78,8 @@ fn parse_supabase_error(err: &SupabaseError) -> (ErrorKind, bool) {
         }
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        | StatusCode::SERVICE_UNAVAILABLE => (ErrorKind::Unexpected, true),
+        StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Timeout, true),
         _ => (ErrorKind::Unexpected, false),
     }
 }
@@ -89,7 +89,9 @@ impl oio::PageList for SwiftLister {
                     // we'll change "2023-10-28T19:18:11.682610" to "2023-10-28T19:18:11.682610Z"
                     last_modified.push('Z');
                     meta.set_last_modified(parse_datetime_from_rfc3339(last_modified.as_str())?);
-                    meta.set_content_type(content_type.as_str());
+                    if !content_type.is_empty() {
+                        meta.set_content_type(content_type.as_str());
+                    }
 
                     oio::Entry::with(name, meta)
                 }
@@ -39,7 +39,7 @@ impl SwiftWriter {
 
 #[async_trait]
 impl oio::OneShotWrite for SwiftWriter {
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let bs = bs.bytes(bs.remaining());
 
         let resp = self
@@ -51,8 +51,9 @@ impl oio::OneShotWrite for SwiftWriter {
 
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
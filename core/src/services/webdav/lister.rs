@@ -125,7 +125,10 @@ impl ListOpResponse {
         }
 
         if let Some(v) = getcontenttype {
-            m.set_content_type(v);
+            // Treat empty content type as unknown.
+            if !v.is_empty() {
+                m.set_content_type(v);
+            }
         }
 
         if let Some(v) = getetag {
@@ -40,7 +40,7 @@ impl WebhdfsWriter {
 #[async_trait]
 impl oio::OneShotWrite for WebhdfsWriter {
     /// Using `bytes` instead of `vectored_bytes` to allow request to be redirected.
-    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<()> {
+    async fn write_once(&self, bs: &dyn WriteBuf) -> Result<Metadata> {
         let bs = bs.bytes(bs.remaining());
 
         let req = self.backend.webhdfs_create_object_request(
@@ -55,8 +55,9 @@ impl oio::OneShotWrite for WebhdfsWriter {
         let status = resp.status();
         match status {
             StatusCode::CREATED | StatusCode::OK => {
+                let meta = parse_write_metadata(resp.headers())?;
                 resp.into_body().consume().await?;
-                Ok(())
+                Ok(meta)
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -61,12 +61,18 @@ pub struct Capability {
     pub stat_with_if_match: bool,
     /// If operator supports stat with if none match.
     pub stat_with_if_none_match: bool,
+    /// If operator supports stat with if modified since.
+    pub stat_with_if_modified_since: bool,
+    /// If operator supports stat with if unmodified since.
+    pub stat_with_if_unmodified_since: bool,
     /// if operator supports read with override cache control.
     pub stat_with_override_cache_control: bool,
     /// if operator supports read with override content disposition.
     pub stat_with_override_content_disposition: bool,
     /// if operator supports read with override content type.
     pub stat_with_override_content_type: bool,
+    /// If operator supports stat with returning raw response headers.
+    pub stat_with_raw_headers: bool,
 
     /// If operator supports read.
     pub read: bool,
@@ -80,6 +86,10 @@ pub struct Capability {
     pub read_with_if_match: bool,
     /// If operator supports read with if none match.
     pub read_with_if_none_match: bool,
+    /// If operator supports read with if modified since.
+    pub read_with_if_modified_since: bool,
+    /// If operator supports read with if unmodified since.
+    pub read_with_if_unmodified_since: bool,
     /// if operator supports read with override cache control.
     pub read_with_override_cache_control: bool,
     /// if operator supports read with override content disposition.
@@ -99,8 +109,26 @@ pub struct Capability {
     pub write_with_content_type: bool,
     /// If operator supports write with content disposition.
     pub write_with_content_disposition: bool,
+    /// If operator supports write with content encoding.
+    pub write_with_content_encoding: bool,
+    /// If operator supports write with content md5.
+    pub write_with_content_md5: bool,
     /// If operator supports write with cache control.
     pub write_with_cache_control: bool,
+    /// If operator supports write with storage class.
+    pub write_with_storage_class: bool,
+    /// If operator supports write with if not exists.
+    pub write_with_if_not_exists: bool,
+    /// If operator supports write with if match.
+    pub write_with_if_match: bool,
+    /// If operator supports write with if none match.
+    pub write_with_if_none_match: bool,
+    /// If operator supports write with resuming a multipart upload.
+    pub write_with_resume: bool,
+    /// If operator supports write with verifying the content md5 of every part.
+    pub write_with_verify_parts: bool,
+    /// If operator supports write with expires.
+    pub write_with_expires: bool,
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -123,12 +151,22 @@ pub struct Capability {
 
     /// If operator supports delete.
     pub delete: bool,
+    /// If operator supports delete with if match.
+    pub delete_with_if_match: bool,
 
     /// If operator supports copy.
     pub copy: bool,
+    /// If operator supports copy with if none match.
+    pub copy_with_if_none_match: bool,
+    /// If operator supports copy with metadata directive and content type.
+    pub copy_with_metadata_directive: bool,
+    /// If operator supports copy with preserving last modified of the source.
+    pub copy_with_preserve_last_modified: bool,
 
     /// If operator supports rename.
     pub rename: bool,
+    /// If operator supports rename dir atomically.
+    pub rename_dir: bool,
 
     /// If operator supports list.
     pub list: bool,
@@ -138,6 +176,10 @@ pub struct Capability {
     pub list_with_start_after: bool,
     /// If backend supports list with recursive.
     pub list_with_recursive: bool,
+    /// If backend supports list with versions.
+    pub list_with_versions: bool,
+    /// If backend supports list with custom delimiter.
+    pub list_with_delimiter: bool,
 
     /// If operator supports presign.
     pub presign: bool,
@@ -147,6 +189,15 @@ pub struct Capability {
     pub presign_stat: bool,
     /// If operator supports presign write.
     pub presign_write: bool,
+    /// If operator supports presign delete.
+    pub presign_delete: bool,
+    /// If operator supports presign upload part of multipart upload.
+    pub presign_upload_part: bool,
+    /// If operator supports presign post policy for browser based form upload.
+    pub presign_post: bool,
+
+    /// If operator supports restore archived objects.
+    pub restore: bool,
 
     /// If operator supports batch.
     pub batch: bool,
@@ -190,6 +241,9 @@ impl Debug for Capability {
         if self.presign {
             s.push("Presign");
         }
+        if self.restore {
+            s.push("Restore");
+        }
         if self.batch {
             s.push("Batch");
         }
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+use crate::*;
+
+/// Compression algorithm used to compress or decompress content.
+///
+/// Compression and decompression are only available while the `compress`
+/// feature is enabled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Compression {
+    /// Gzip, `Content-Encoding: gzip`.
+    Gzip,
+    /// Zstandard, `Content-Encoding: zstd`.
+    Zstd,
+    /// Detect the algorithm from `Content-Encoding` of the object.
+    ///
+    /// Only available for read, content without a supported `Content-Encoding`
+    /// will be returned as is.
+    Auto,
+}
+
+impl Compression {
+    /// Parse compression from the value of `Content-Encoding`.
+    ///
+    /// Returns `None` if the encoding is not supported.
+    pub fn from_content_encoding(v: &str) -> Option<Self> {
+        match v.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(Compression::Gzip),
+            "zstd" => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Get the value of `Content-Encoding` for this compression.
+    ///
+    /// Returns `None` for [`Compression::Auto`].
+    pub fn content_encoding(&self) -> Option<&'static str> {
+        match self {
+            Compression::Gzip => Some("gzip"),
+            Compression::Zstd => Some("zstd"),
+            Compression::Auto => None,
+        }
+    }
+}
+
+/// Wrap the reader with decompression if needed.
+pub(crate) fn new_decompress_reader(
+    r: oio::Reader,
+    compression: Option<Compression>,
+) -> Result<oio::Reader> {
+    match compression {
+        #[cfg(feature = "compress")]
+        Some(compression) => Ok(Box::new(oio::DecompressReader::new(r, compression)?)),
+        #[cfg(not(feature = "compress"))]
+        Some(_) => Err(Error::new(
+            ErrorKind::Unsupported,
+            "decompress requires the compress feature to be enabled",
+        )),
+        None => Ok(r),
+    }
+}
+
+/// Prepare the write args for compression.
+///
+/// Returns the args to be sent to services and the compression to apply:
+///
+/// - Content length will be cleared since the size of compressed content is unknown.
+/// - Content encoding will be set, [`ErrorKind::Unsupported`] will be returned if the
+///   service doesn't support it since the content can't be recognized as compressed.
+pub(crate) fn prepare_compress(
+    info: &AccessorInfo,
+    args: OpWrite,
+) -> Result<(OpWrite, Option<Compression>)> {
+    let Some(compression) = args.compress() else {
+        return Ok((args, None));
+    };
+    if !cfg!(feature = "compress") {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "compress requires the compress feature to be enabled",
+        ));
+    }
+    let Some(encoding) = compression.content_encoding() else {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "compression auto is not supported for writing",
+        ));
+    };
+
+    if !info.full_capability().write_with_content_encoding {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "compress requires the service to support write with content encoding",
+        )
+        .with_context("service", info.scheme()));
+    }
+
+    let args = args
+        .without_content_length()
+        .with_content_encoding(encoding);
+    Ok((args, Some(compression)))
+}
+
+/// Wrap the writer with compression if needed.
+pub(crate) fn new_compress_writer(
+    w: oio::Writer,
+    compression: Option<Compression>,
+) -> Result<oio::Writer> {
+    match compression {
+        #[cfg(feature = "compress")]
+        Some(compression) => Ok(Box::new(oio::CompressWriter::new(w, compression)?)),
+        #[cfg(not(feature = "compress"))]
+        Some(_) => Err(Error::new(
+            ErrorKind::Unsupported,
+            "compress requires the compress feature to be enabled",
+        )),
+        None => Ok(w),
+    }
+}
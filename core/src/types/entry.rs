@@ -145,6 +145,38 @@ impl Entry {
         &self.metadata
     }
 
+    /// Content type of this entry.
+    ///
+    /// It's a shortcut of `entry.metadata().content_type()`, the entry must be listed
+    /// with [`Metakey::ContentType`], otherwise it will panic.
+    ///
+    /// - `Some(v)` means the content type is returned by services.
+    /// - `None` means services don't have content type for this entry, e.g. dirs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let entries = op
+    ///     .list_with("dir/")
+    ///     .metakey(Metakey::Mode | Metakey::ContentType)
+    ///     .await?;
+    /// for entry in entries {
+    ///     if entry.content_type() == Some("image/png") {
+    ///         println!("Handling image {}", entry.path())
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn content_type(&self) -> Option<&str> {
+        self.metadata.content_type()
+    }
+
     /// Consume this entry to get it's path and metadata.
     ///
     /// # Notes
@@ -189,4 +221,19 @@ impl Entry {
     pub fn into_parts(self) -> (String, Metadata) {
         (self.path, self.metadata)
     }
+
+    /// Consume this entry to get it's path.
+    pub fn into_path(self) -> String {
+        self.path
+    }
+
+    /// Consume this entry to get it's metadata without cloning.
+    ///
+    /// # Notes
+    ///
+    /// Metadata only guaranteed to have results of `metakey` (which default to `Metakey::Mode`),
+    /// please refer to [`Entry::metadata`] for more details.
+    pub fn into_metadata(self) -> Metadata {
+        self.metadata
+    }
 }
@@ -105,6 +105,19 @@ pub enum ErrorKind {
     ///
     /// For example, user try to seek to a negative position
     InvalidInput,
+    /// The checksum of content is mismatch.
+    ///
+    /// For example, the md5 of content read from service is not the same as
+    /// the `Content-MD5` returned by service.
+    ChecksumMismatch,
+    /// The requested range can't be satisfied.
+    ///
+    /// For example, user try to read from an offset that is beyond the end of file.
+    RangeNotSatisfied,
+    /// The operation is timeout.
+    ///
+    /// For example, the operation doesn't finish within the given timeout.
+    Timeout,
 }
 
 impl ErrorKind {
@@ -137,6 +150,9 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::ContentTruncated => "ContentTruncated",
             ErrorKind::ContentIncomplete => "ContentIncomplete",
             ErrorKind::InvalidInput => "InvalidInput",
+            ErrorKind::ChecksumMismatch => "ChecksumMismatch",
+            ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::Timeout => "Timeout",
         }
     }
 }
@@ -419,6 +435,8 @@ impl From<Error> for io::Error {
             ErrorKind::NotFound => io::ErrorKind::NotFound,
             ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
             ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+            ErrorKind::ChecksumMismatch => io::ErrorKind::InvalidData,
+            ErrorKind::Timeout => io::ErrorKind::TimedOut,
             _ => io::ErrorKind::Other,
         };
 
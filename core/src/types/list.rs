@@ -22,11 +22,16 @@ use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
+use futures::future;
 use futures::Stream;
 use futures::StreamExt;
+use futures::TryStreamExt;
 
 use crate::raw::oio::List;
+use crate::raw::oio::ListExt;
 use crate::raw::*;
 use crate::*;
 
@@ -44,6 +49,14 @@ pub struct Lister {
     lister: Option<oio::Lister>,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// pattern is used to filter entries before stat them.
+    pattern: Option<ListPattern>,
+    /// remaining is the number of entries that still can be returned if `limit_total` is set.
+    remaining: Option<usize>,
+    /// meta_filter is used to filter entries by content length or last modified after stat them.
+    meta_filter: Option<MetaFilter>,
+    /// only_files is used to skip non-file entries before stat them.
+    only_files: bool,
 
     /// tasks is used to store tasks that are run in concurrent.
     tasks: ConcurrentFutures<StatTask>,
@@ -124,20 +137,106 @@ unsafe impl Sync for Lister {}
 impl Lister {
     /// Create a new lister.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
+        let (args, meta_filter) = MetaFilter::parse(args);
         let required_metakey = args.metakey();
         let concurrent = cmp::max(1, args.concurrent());
+        let pattern = args.pattern().map(|v| ListPattern::new(path, v));
+        let remaining = args.limit_total();
+        let sorted = args.sorted();
+        let start_before = args.start_before().map(|v| v.to_string());
+        let (only_files, only_dirs) = (args.only_files(), args.only_dirs());
+        if only_files && only_dirs {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "only_files and only_dirs can't be used together",
+            )
+            .with_operation("list")
+            .with_context("path", path));
+        }
+        let args = limit_args_for(
+            args,
+            pattern.is_some()
+                || start_before.is_some()
+                || meta_filter.is_some()
+                || only_files
+                || only_dirs,
+        );
 
         let (_, lister) = acc.list(path, args).await?;
+        let lister: oio::Lister = if only_dirs {
+            Box::new(oio::OnlyDirsLister::new(lister, path))
+        } else {
+            lister
+        };
+        let lister: oio::Lister = match start_before {
+            Some(v) => Box::new(oio::StartBeforeLister::new(lister, &v)),
+            None => lister,
+        };
+        let lister: oio::Lister = if sorted {
+            Box::new(oio::SortedLister::new(lister))
+        } else {
+            lister
+        };
 
         Ok(Self {
             acc,
-            lister: Some(lister),
+            // No need to poll lister anymore if nothing could be returned.
+            lister: (remaining != Some(0)).then_some(lister),
             required_metakey,
+            pattern,
+            remaining,
+            meta_filter,
+            only_files,
 
             tasks: ConcurrentFutures::new(concurrent),
             errored: false,
         })
     }
+
+    /// Count the number of entries left in this lister.
+    ///
+    /// Unlike collecting the lister, `count` only tallies the entries returned by
+    /// underlying services without stat them or building [`Entry`], so it's cheap
+    /// even for huge prefixes.
+    pub async fn count(mut self) -> Result<usize> {
+        if self.errored {
+            return Ok(0);
+        }
+        // Meta filter requires the metadata of every entry, we have to
+        // go through the whole stream in this case.
+        if self.meta_filter.is_some() {
+            return self
+                .try_fold(0, |count, _| future::ready(Ok(count + 1)))
+                .await;
+        }
+
+        // Entries that already pushed into tasks should be counted too.
+        let mut count = self.tasks.len();
+        let Some(mut lister) = self.lister.take() else {
+            return Ok(count);
+        };
+
+        while self.remaining != Some(0) {
+            let Some(oe) = lister.next().await? else {
+                break;
+            };
+            if let Some(pattern) = &self.pattern {
+                if !pattern.matches(oe.path()) {
+                    continue;
+                }
+            }
+            if self.only_files && !oe.mode().is_file() {
+                continue;
+            }
+
+            count += 1;
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+        }
+
+        Ok(count)
+    }
 }
 
 impl Stream for Lister {
@@ -149,45 +248,150 @@ impl Stream for Lister {
             return Poll::Ready(None);
         }
 
-        // Trying to pull more tasks if there are more space.
-        if self.tasks.has_remaining() {
-            if let Some(lister) = self.lister.as_mut() {
-                match lister.poll_next(cx) {
-                    Poll::Pending => {}
-                    Poll::Ready(Ok(Some(oe))) => {
-                        let (path, metadata) = oe.into_entry().into_parts();
-                        if metadata.contains_metakey(self.required_metakey) {
-                            self.tasks.push(StatTask::Known(Some((path, metadata))));
-                        } else {
-                            let acc = self.acc.clone();
-                            let fut = async move {
-                                let res = acc.stat(&path, OpStat::default()).await;
-                                (path, res.map(|rp| rp.into_metadata()))
-                            };
-                            self.tasks.push(StatTask::Stating(Box::pin(fut)));
-                        }
+        let this = &mut *self;
+
+        loop {
+            // Trying to pull more tasks if there are more space.
+            if this.tasks.has_remaining() {
+                if let Some(lister) = this.lister.as_mut() {
+                    loop {
+                        match lister.poll_next(cx) {
+                            Poll::Pending => {}
+                            Poll::Ready(Ok(Some(oe))) => {
+                                let (path, metadata) = oe.into_entry().into_parts();
+                                // Skip entries that don't match the pattern before stat them.
+                                if let Some(pattern) = &this.pattern {
+                                    if !pattern.matches(&path) {
+                                        continue;
+                                    }
+                                }
+                                if this.only_files && !metadata.mode().is_file() {
+                                    continue;
+                                }
+
+                                if metadata.contains_metakey(this.required_metakey) {
+                                    this.tasks.push(StatTask::Known(Some((path, metadata))));
+                                } else {
+                                    let acc = this.acc.clone();
+                                    let args = stat_args_for(&metadata);
+                                    let fut = async move {
+                                        let res = acc.stat(&path, args).await;
+                                        (path, res.map(|rp| rp.into_metadata()))
+                                    };
+                                    this.tasks.push(StatTask::Stating(Box::pin(fut)));
+                                }
+
+                                // Drop the lister to stop fetching more pages once we
+                                // have collected enough entries.
+                                //
+                                // Entries could still be dropped by meta filter, so
+                                // we will count them while returning instead.
+                                if this.meta_filter.is_none() {
+                                    if let Some(remaining) = this.remaining.as_mut() {
+                                        *remaining -= 1;
+                                        if *remaining == 0 {
+                                            this.lister = None;
+                                        }
+                                    }
+                                }
+                            }
+                            Poll::Ready(Ok(None)) => {
+                                this.lister = None;
+                            }
+                            Poll::Ready(Err(err)) => {
+                                this.errored = true;
+                                return Poll::Ready(Some(Err(err)));
+                            }
+                        };
+                        break;
                     }
-                    Poll::Ready(Ok(None)) => {
-                        self.lister = None;
+                }
+            }
+
+            // Try to poll tasks
+            if let Some((path, rp)) = ready!(this.tasks.poll_next_unpin(cx)) {
+                let metadata = known_metadata(rp?, this.required_metakey);
+                if let Some(meta_filter) = &this.meta_filter {
+                    // Pull more entries if this one is filtered out.
+                    if !meta_filter.matches(&metadata) {
+                        continue;
                     }
-                    Poll::Ready(Err(err)) => {
-                        self.errored = true;
-                        return Poll::Ready(Some(Err(err)));
+                    if let Some(remaining) = this.remaining.as_mut() {
+                        *remaining -= 1;
+                        if *remaining == 0 {
+                            this.lister = None;
+                            this.tasks.clear();
+                        }
                     }
-                };
+                }
+                return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
             }
+
+            return if this.lister.is_some() {
+                Poll::Pending
+            } else {
+                Poll::Ready(None)
+            };
         }
+    }
 
-        // Try to poll tasks
-        if let Some((path, rp)) = ready!(self.tasks.poll_next_unpin(cx)) {
-            let metadata = rp?;
-            return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
+    /// The lower bound is the number of entries that already fetched but not returned.
+    ///
+    /// The upper bound is only known after all pages have been fetched, or `limit_total`
+    /// has been set.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.errored {
+            return (0, Some(0));
         }
 
-        if self.lister.is_some() {
-            Poll::Pending
-        } else {
-            Poll::Ready(None)
+        let fetched = self.tasks.len();
+        // Fetched entries could still be dropped by meta filter.
+        if self.meta_filter.is_some() {
+            let upper = match (&self.lister, self.remaining) {
+                (None, Some(remaining)) => Some(cmp::min(fetched, remaining)),
+                (None, None) => Some(fetched),
+                (Some(_), remaining) => remaining,
+            };
+            return (0, upper);
+        }
+        match (&self.lister, self.remaining) {
+            (None, _) => (fetched, Some(fetched)),
+            (Some(_), Some(remaining)) => (fetched, Some(fetched + remaining)),
+            (Some(_), None) => (fetched, None),
+        }
+    }
+}
+
+/// Walker is designed to walk all files under given path in an asynchronous
+/// manner.
+///
+/// Users can construct Walker by [`Operator::walk`] or [`Operator::walk_with`].
+///
+/// - Walker implements `Stream<Item = Result<Entry>>`.
+/// - Walker will list recursively and only return files, dirs will be skipped no
+///   matter services returns them or not.
+pub struct Walker {
+    lister: Lister,
+}
+
+impl Walker {
+    /// Create a new walker.
+    pub(crate) async fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
+        let lister = Lister::create(acc, path, args.with_recursive(true)).await?;
+
+        Ok(Self { lister })
+    }
+}
+
+impl Stream for Walker {
+    type Item = Result<Entry>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.lister.poll_next_unpin(cx)) {
+                Some(Ok(entry)) if entry.metadata().is_dir() => continue,
+                v => return Poll::Ready(v),
+            }
         }
     }
 }
@@ -203,6 +407,14 @@ pub struct BlockingLister {
     acc: FusedAccessor,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// pattern is used to filter entries before stat them.
+    pattern: Option<ListPattern>,
+    /// remaining is the number of entries that still can be returned if `limit_total` is set.
+    remaining: Option<usize>,
+    /// meta_filter is used to filter entries by content length or last modified after stat them.
+    meta_filter: Option<MetaFilter>,
+    /// only_files is used to skip non-file entries before stat them.
+    only_files: bool,
 
     lister: oio::BlockingLister,
     errored: bool,
@@ -216,17 +428,100 @@ unsafe impl Sync for BlockingLister {}
 impl BlockingLister {
     /// Create a new lister.
     pub(crate) fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
+        let (args, meta_filter) = MetaFilter::parse(args);
         let required_metakey = args.metakey();
+        let pattern = args.pattern().map(|v| ListPattern::new(path, v));
+        let remaining = args.limit_total();
+        let sorted = args.sorted();
+        let start_before = args.start_before().map(|v| v.to_string());
+        let (only_files, only_dirs) = (args.only_files(), args.only_dirs());
+        if only_files && only_dirs {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "only_files and only_dirs can't be used together",
+            )
+            .with_operation("list")
+            .with_context("path", path));
+        }
+        let args = limit_args_for(
+            args,
+            pattern.is_some()
+                || start_before.is_some()
+                || meta_filter.is_some()
+                || only_files
+                || only_dirs,
+        );
         let (_, lister) = acc.blocking_list(path, args)?;
+        let lister: oio::BlockingLister = if only_dirs {
+            Box::new(oio::OnlyDirsLister::new(lister, path))
+        } else {
+            lister
+        };
+        let lister: oio::BlockingLister = match start_before {
+            Some(v) => Box::new(oio::StartBeforeLister::new(lister, &v)),
+            None => lister,
+        };
+        let lister: oio::BlockingLister = if sorted {
+            Box::new(oio::SortedLister::new(lister))
+        } else {
+            lister
+        };
 
         Ok(Self {
             acc,
             required_metakey,
+            pattern,
+            remaining,
+            meta_filter,
+            only_files,
 
             lister,
             errored: false,
         })
     }
+
+    /// Count the number of entries left in this lister.
+    ///
+    /// Unlike collecting the lister, `count` only tallies the entries returned by
+    /// underlying services without stat them or building [`Entry`], so it's cheap
+    /// even for huge prefixes.
+    pub fn count(mut self) -> Result<usize> {
+        if self.errored {
+            return Ok(0);
+        }
+        // Meta filter requires the metadata of every entry, we have to
+        // go through the whole iterator in this case.
+        if self.meta_filter.is_some() {
+            let mut count = 0;
+            for entry in self.by_ref() {
+                entry?;
+                count += 1;
+            }
+            return Ok(count);
+        }
+
+        let mut count = 0;
+        while self.remaining != Some(0) {
+            let Some(oe) = self.lister.next()? else {
+                break;
+            };
+            if let Some(pattern) = &self.pattern {
+                if !pattern.matches(oe.path()) {
+                    continue;
+                }
+            }
+            if self.only_files && !oe.mode().is_file() {
+                continue;
+            }
+
+            count += 1;
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+        }
+
+        Ok(count)
+    }
 }
 
 /// TODO: we can implement next_chunk.
@@ -234,40 +529,221 @@ impl Iterator for BlockingLister {
     type Item = Result<Entry>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Returns `None` if we have errored.
+        loop {
+            // Returns `None` if we have errored.
+            if self.errored {
+                return None;
+            }
+            // Returns `None` if we have returned enough entries.
+            if self.remaining == Some(0) {
+                return None;
+            }
+
+            let (path, metadata) = loop {
+                let entry = match self.lister.next() {
+                    Ok(Some(entry)) => entry,
+                    Ok(None) => return None,
+                    Err(err) => {
+                        self.errored = true;
+                        return Some(Err(err));
+                    }
+                };
+
+                let (path, metadata) = entry.into_entry().into_parts();
+                // Skip entries that don't match the pattern before stat them.
+                match &self.pattern {
+                    Some(pattern) if !pattern.matches(&path) => continue,
+                    _ if self.only_files && !metadata.mode().is_file() => continue,
+                    _ => break (path, metadata),
+                }
+            };
+
+            let metadata = if metadata.contains_metakey(self.required_metakey) {
+                metadata
+            } else {
+                match self.acc.blocking_stat(&path, stat_args_for(&metadata)) {
+                    Ok(rp) => known_metadata(rp.into_metadata(), self.required_metakey),
+                    Err(err) => {
+                        self.errored = true;
+                        return Some(Err(err));
+                    }
+                }
+            };
+            // Skip entries that don't match the meta filter after stat them.
+            if let Some(meta_filter) = &self.meta_filter {
+                if !meta_filter.matches(&metadata) {
+                    continue;
+                }
+            }
+
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            return Some(Ok(Entry::new(path, metadata)));
+        }
+    }
+
+    /// The upper bound is only known if `limit_total` has been set.
+    fn size_hint(&self) -> (usize, Option<usize>) {
         if self.errored {
-            return None;
+            return (0, Some(0));
         }
 
-        let entry = match self.lister.next() {
-            Ok(Some(entry)) => entry,
-            Ok(None) => return None,
-            Err(err) => {
-                self.errored = true;
-                return Some(Err(err));
-            }
+        (0, self.remaining)
+    }
+}
+
+/// ListPattern is used to filter entries by glob pattern.
+///
+/// The pattern is matched against the path relative to the listing path.
+struct ListPattern {
+    base: String,
+    pattern: String,
+}
+
+impl ListPattern {
+    fn new(base: &str, pattern: &str) -> Self {
+        Self {
+            base: base.to_string(),
+            pattern: pattern.to_string(),
+        }
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        let rel = if self.base == "/" {
+            path
+        } else {
+            path.strip_prefix(&self.base).unwrap_or(path)
         };
 
-        let (path, metadata) = entry.into_entry().into_parts();
-        if metadata.contains_metakey(self.required_metakey) {
-            return Some(Ok(Entry::new(path, metadata)));
+        !rel.is_empty() && glob_match(&self.pattern, rel)
+    }
+}
+
+/// MetaFilter is used to filter entries by content length and last modified.
+///
+/// Dirs don't have a size or last modified, so they will always be returned.
+struct MetaFilter {
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    modified_after: Option<DateTime<Utc>>,
+    modified_before: Option<DateTime<Utc>>,
+}
+
+impl MetaFilter {
+    /// Build the meta filter from list args.
+    ///
+    /// `Metakey::Mode` and the metakey of enabled filters are required to filter entries,
+    /// so they will be added into the metakey of returned args.
+    fn parse(args: OpList) -> (OpList, Option<Self>) {
+        let filter = Self {
+            min_size: args.min_size(),
+            max_size: args.max_size(),
+            modified_after: args.modified_after(),
+            modified_before: args.modified_before(),
+        };
+
+        if !filter.by_size() && !filter.by_modified() {
+            return (args, None);
+        }
+
+        let mut metakey = args.metakey() | Metakey::Mode;
+        if filter.by_size() {
+            metakey |= Metakey::ContentLength;
         }
+        if filter.by_modified() {
+            metakey |= Metakey::LastModified;
+        }
+        (args.with_metakey(metakey), Some(filter))
+    }
 
-        let metadata = match self.acc.blocking_stat(&path, OpStat::default()) {
-            Ok(rp) => rp.into_metadata(),
-            Err(err) => {
-                self.errored = true;
-                return Some(Err(err));
+    fn by_size(&self) -> bool {
+        self.min_size.is_some() || self.max_size.is_some()
+    }
+
+    fn by_modified(&self) -> bool {
+        self.modified_after.is_some() || self.modified_before.is_some()
+    }
+
+    fn matches(&self, metadata: &Metadata) -> bool {
+        if metadata.is_dir() {
+            return true;
+        }
+
+        if self.by_size() {
+            let size = metadata.content_length();
+            if !(self.min_size.map_or(true, |v| size >= v)
+                && self.max_size.map_or(true, |v| size <= v))
+            {
+                return false;
             }
-        };
-        Some(Ok(Entry::new(path, metadata)))
+        }
+
+        if !self.by_modified() {
+            return true;
+        }
+        // Entries without last modified can't be proved to be outside the window,
+        // so we will keep them.
+        match metadata.last_modified() {
+            Some(modified) => {
+                self.modified_after.map_or(true, |v| modified > v)
+                    && self.modified_before.map_or(true, |v| modified < v)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Cap the page size by `limit_total` so that we will not fetch more entries than needed.
+///
+/// Entries could be skipped by filters like pattern and all entries are required for sorting,
+/// so we will keep the page size as is in those cases.
+fn limit_args_for(args: OpList, filtered: bool) -> OpList {
+    match args.limit_total() {
+        Some(total) if !filtered && !args.sorted() => {
+            let limit = args.limit().map_or(total, |v| cmp::min(v, total));
+            args.with_limit(cmp::max(1, limit))
+        }
+        _ => args,
     }
 }
 
+/// Mark the required metakey as known for the metadata returned by stat.
+///
+/// Stat returns all metadata that services have, the metakey that is still
+/// missing means services doesn't support it. We mark it as known so users
+/// will get `None` instead of visiting a not set metadata.
+fn known_metadata(metadata: Metadata, required_metakey: FlagSet<Metakey>) -> Metadata {
+    let metakey = metadata.metakey() | required_metakey;
+    metadata.with_metakey(metakey)
+}
+
+/// Build the stat args for entry that doesn't contain the required metakey.
+///
+/// If the entry carries a version (like listing with `versions(true)`), we should
+/// stat that version instead of the current one.
+fn stat_args_for(metadata: &Metadata) -> OpStat {
+    if metadata.metakey().contains(Metakey::Version) {
+        if let Some(v) = metadata.version() {
+            return OpStat::new().with_version(v);
+        }
+    }
+
+    OpStat::new()
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    use async_trait::async_trait;
     use futures::future;
     use futures::StreamExt;
+    use futures::TryStreamExt;
 
     use super::*;
     use crate::services::Azblob;
@@ -304,4 +780,236 @@ mod tests {
 
         Ok(())
     }
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        pages: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Lister = oio::Lister;
+        type BlockingLister = oio::BlockingLister;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().list = true;
+            am.full_capability_mut().list_with_limit = true;
+
+            am
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            let l = MockPageList {
+                path: path.to_string(),
+                limit: args.limit().unwrap_or(1000),
+                pages: self.pages.clone(),
+            };
+
+            Ok((RpList::default(), Box::new(oio::PageLister::new(l))))
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE)
+                    .with_content_length(1024)
+                    .with_last_modified(mock_last_modified()),
+            ))
+        }
+    }
+
+    fn mock_last_modified() -> DateTime<Utc> {
+        (SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)).into()
+    }
+
+    /// MockPageList will return 1000 files in pages.
+    struct MockPageList {
+        path: String,
+        limit: usize,
+        pages: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl oio::PageList for MockPageList {
+        async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+            self.pages.fetch_add(1, Ordering::SeqCst);
+
+            let start: usize = ctx.token.parse().unwrap_or_default();
+            let end = cmp::min(start + self.limit, 1000);
+            for i in start..end {
+                let path = format!("{}file-{i:04}", self.path);
+                ctx.entries
+                    .push_back(oio::Entry::new(&path, Metadata::new(EntryMode::FILE)));
+            }
+
+            ctx.token = end.to_string();
+            ctx.done = end >= 1000;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_limit_total() -> Result<()> {
+        let acc = MockService::default();
+        let pages = acc.pages.clone();
+        let acc: FusedAccessor = Arc::new(acc);
+
+        let lister =
+            Lister::create(acc.clone(), "dir/", OpList::new().with_limit_total(50)).await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert_eq!(entries.len(), 50);
+        assert_eq!(entries[49].path(), "dir/file-0049");
+        assert_eq!(pages.load(Ordering::SeqCst), 1);
+
+        pages.store(0, Ordering::SeqCst);
+        let lister = Lister::create(
+            acc,
+            "dir/",
+            OpList::new().with_limit(10).with_limit_total(50),
+        )
+        .await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert_eq!(entries.len(), 50);
+        assert_eq!(pages.load(Ordering::SeqCst), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_unsupported_metakey() -> Result<()> {
+        let acc: FusedAccessor = Arc::new(MockService::default());
+
+        let lister = Lister::create(
+            acc,
+            "dir/",
+            OpList::new()
+                .with_limit_total(10)
+                .with_metakey(Metakey::ContentLength | Metakey::Etag),
+        )
+        .await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert_eq!(entries.len(), 10);
+        for entry in entries {
+            let meta = entry.metadata();
+            assert!(meta.metakey().contains(Metakey::Etag));
+            assert_eq!(meta.content_length(), 1024);
+            assert_eq!(meta.etag(), None);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_count() -> Result<()> {
+        let acc: FusedAccessor = Arc::new(MockService::default());
+
+        let lister = Lister::create(acc.clone(), "dir/", OpList::new()).await?;
+        assert_eq!(lister.size_hint(), (0, None));
+        assert_eq!(lister.count().await?, 1000);
+
+        let lister =
+            Lister::create(acc.clone(), "dir/", OpList::new().with_limit_total(50)).await?;
+        assert_eq!(lister.size_hint(), (0, Some(50)));
+        assert_eq!(lister.count().await?, 50);
+
+        let lister = Lister::create(acc, "dir/", OpList::new().with_pattern("file-00*")).await?;
+        assert_eq!(lister.count().await?, 100);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_size_filter() -> Result<()> {
+        let acc: FusedAccessor = Arc::new(MockService::default());
+
+        let lister = Lister::create(acc.clone(), "dir/", OpList::new().with_min_size(2048)).await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert!(entries.is_empty());
+
+        // limit_total should count entries after filtering.
+        let lister = Lister::create(
+            acc.clone(),
+            "dir/",
+            OpList::new()
+                .with_min_size(1024)
+                .with_max_size(1024)
+                .with_limit_total(10)
+                .with_concurrent(4),
+        )
+        .await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert_eq!(entries.len(), 10);
+        assert_eq!(entries[0].metadata().content_length(), 1024);
+
+        let lister = Lister::create(acc, "dir/", OpList::new().with_max_size(1024)).await?;
+        assert_eq!(lister.count().await?, 1000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_modified_filter() -> Result<()> {
+        let acc: FusedAccessor = Arc::new(MockService::default());
+        let last_modified = mock_last_modified();
+
+        let lister = Lister::create(
+            acc.clone(),
+            "dir/",
+            OpList::new().with_modified_after(last_modified),
+        )
+        .await?;
+        assert_eq!(lister.count().await?, 0);
+
+        let lister = Lister::create(
+            acc.clone(),
+            "dir/",
+            OpList::new()
+                .with_modified_after(last_modified - chrono::Duration::seconds(1))
+                .with_modified_before(last_modified + chrono::Duration::seconds(1))
+                .with_limit_total(10),
+        )
+        .await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert_eq!(entries.len(), 10);
+        assert_eq!(entries[0].metadata().last_modified(), Some(last_modified));
+
+        let lister = Lister::create(
+            acc,
+            "dir/",
+            OpList::new().with_modified_before(last_modified),
+        )
+        .await?;
+        assert_eq!(lister.count().await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_only_files_and_dirs() -> Result<()> {
+        let acc: FusedAccessor = Arc::new(MockService::default());
+
+        let lister =
+            Lister::create(acc.clone(), "dir/", OpList::new().with_only_files(true)).await?;
+        assert_eq!(lister.count().await?, 1000);
+
+        // All entries are files, no dirs could be derived.
+        let lister =
+            Lister::create(acc.clone(), "dir/", OpList::new().with_only_dirs(true)).await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        assert!(entries.is_empty());
+
+        let res = Lister::create(
+            acc,
+            "dir/",
+            OpList::new().with_only_files(true).with_only_dirs(true),
+        )
+        .await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+        Ok(())
+    }
 }
@@ -38,13 +38,19 @@ pub struct Metadata {
 
     cache_control: Option<String>,
     content_disposition: Option<String>,
+    content_encoding: Option<String>,
     content_length: Option<u64>,
     content_md5: Option<String>,
     content_range: Option<BytesContentRange>,
     content_type: Option<String>,
     etag: Option<String>,
     last_modified: Option<DateTime<Utc>>,
+    expires: Option<DateTime<Utc>>,
+    restore_status: Option<RestoreStatus>,
+    storage_class: Option<String>,
     version: Option<String>,
+    is_deleted: bool,
+    is_latest: Option<bool>,
 }
 
 impl Metadata {
@@ -68,9 +74,15 @@ impl Metadata {
             content_type: None,
             content_range: None,
             last_modified: None,
+            expires: None,
             etag: None,
             content_disposition: None,
+            content_encoding: None,
+            restore_status: None,
+            storage_class: None,
             version: None,
+            is_deleted: false,
+            is_latest: None,
         }
     }
 
@@ -422,6 +434,9 @@ impl Metadata {
     /// - "attachment"
     /// - "attachment; filename=\"filename.jpg\""
     ///
+    /// Returns `None` if the value is not set on write or the service doesn't
+    /// support content disposition.
+    ///
     /// # Panics
     ///
     /// This value is only available when calling on result of `stat` or `list` with
@@ -470,6 +485,140 @@ impl Metadata {
         self
     }
 
+    /// Content-Encoding of this entry.
+    ///
+    /// `Content-Encoding` is defined by [RFC 7231](https://httpwg.org/specs/rfc7231.html#header.content-encoding).
+    /// Refer to [MDN Content-Encoding](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding) for more information.
+    ///
+    /// OpenDAL will return this value AS-IS like `gzip` or `br`, users should decide
+    /// whether to decode the content by themselves.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::ContentEncoding`], otherwise it will panic.
+    pub fn content_encoding(&self) -> Option<&str> {
+        debug_assert!(
+            self.metakey.contains(Metakey::ContentEncoding)
+                || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: content_encoding, maybe a bug"
+        );
+
+        self.content_encoding.as_deref()
+    }
+
+    /// Set Content-Encoding of this entry.
+    pub fn with_content_encoding(mut self, v: String) -> Self {
+        self.content_encoding = Some(v);
+        self.metakey |= Metakey::ContentEncoding;
+        self
+    }
+
+    /// Set Content-Encoding of this entry.
+    pub fn set_content_encoding(&mut self, v: &str) -> &mut Self {
+        self.content_encoding = Some(v.to_string());
+        self.metakey |= Metakey::ContentEncoding;
+        self
+    }
+
+    /// Expires of this entry.
+    ///
+    /// `Expires` is defined by [RFC 7234](https://httpwg.org/specs/rfc7234.html#header.expires)
+    /// Refer to [MDN Expires](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expires) for more information.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Expires`], otherwise it will panic.
+    pub fn expires(&self) -> Option<DateTime<Utc>> {
+        debug_assert!(
+            self.metakey.contains(Metakey::Expires) || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: expires, maybe a bug"
+        );
+
+        self.expires
+    }
+
+    /// Set expires of this entry.
+    pub fn set_expires(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.expires = Some(v);
+        self.metakey |= Metakey::Expires;
+        self
+    }
+
+    /// Set expires of this entry.
+    pub fn with_expires(mut self, v: DateTime<Utc>) -> Self {
+        self.expires = Some(v);
+        self.metakey |= Metakey::Expires;
+        self
+    }
+
+    /// Storage class of this entry.
+    ///
+    /// Storage class is returned AS-IS from services, like `STANDARD_IA` for AWS S3
+    /// and `NEARLINE` for GCS.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::StorageClass`], otherwise it will panic.
+    pub fn storage_class(&self) -> Option<&str> {
+        debug_assert!(
+            self.metakey.contains(Metakey::StorageClass)
+                || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: storage_class, maybe a bug"
+        );
+
+        self.storage_class.as_deref()
+    }
+
+    /// Set storage class of this entry.
+    pub fn with_storage_class(mut self, v: String) -> Self {
+        self.storage_class = Some(v);
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
+
+    /// Set storage class of this entry.
+    pub fn set_storage_class(&mut self, v: &str) -> &mut Self {
+        self.storage_class = Some(v.to_string());
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
+
+    /// Restore status of this entry.
+    ///
+    /// Restore status tells whether an archived entry can be read directly. Refer
+    /// to [`RestoreStatus`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::RestoreStatus`], otherwise it will panic.
+    pub fn restore_status(&self) -> Option<RestoreStatus> {
+        debug_assert!(
+            self.metakey.contains(Metakey::RestoreStatus)
+                || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: restore_status, maybe a bug"
+        );
+
+        self.restore_status
+    }
+
+    /// Set restore status of this entry.
+    pub fn with_restore_status(mut self, v: RestoreStatus) -> Self {
+        self.restore_status = Some(v);
+        self.metakey |= Metakey::RestoreStatus;
+        self
+    }
+
+    /// Set restore status of this entry.
+    pub fn set_restore_status(&mut self, v: RestoreStatus) -> &mut Self {
+        self.restore_status = Some(v);
+        self.metakey |= Metakey::RestoreStatus;
+        self
+    }
+
     /// Version of this entry.
     ///
     /// Version is a string that can be used to identify the version of this entry.
@@ -510,6 +659,60 @@ impl Metadata {
         self.metakey |= Metakey::Version;
         self
     }
+
+    /// Returns `true` if this entry is a delete marker.
+    ///
+    /// Delete markers are only returned while listing with `versions(true)` or stat with
+    /// `version` on services with object versioning like AWS S3. They mark that the object
+    /// has been deleted at this version and don't have any content.
+    pub fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
+    /// Returns `true` if this entry is a delete marker.
+    ///
+    /// This is the same as [`Metadata::is_deleted`], named after the delete marker of
+    /// object versioning to be used along with [`Metadata::is_latest`].
+    pub fn is_delete_marker(&self) -> bool {
+        self.is_deleted()
+    }
+
+    /// Set whether this entry is a delete marker.
+    pub fn with_is_deleted(mut self, v: bool) -> Self {
+        self.is_deleted = v;
+        self
+    }
+
+    /// Set whether this entry is a delete marker.
+    pub fn set_is_deleted(&mut self, v: bool) -> &mut Self {
+        self.is_deleted = v;
+        self
+    }
+
+    /// Returns whether this entry is the latest version of the object.
+    ///
+    /// - `Some(true)` means this entry is the current version.
+    /// - `Some(false)` means this entry is a noncurrent version.
+    /// - `None` means the service doesn't tell, for example, `stat` with a
+    ///   specific version on AWS S3.
+    ///
+    /// A delete marker could be the latest version as well, please check
+    /// [`Metadata::is_delete_marker`] together.
+    pub fn is_latest(&self) -> Option<bool> {
+        self.is_latest
+    }
+
+    /// Set whether this entry is the latest version of the object.
+    pub fn with_is_latest(mut self, v: bool) -> Self {
+        self.is_latest = Some(v);
+        self
+    }
+
+    /// Set whether this entry is the latest version of the object.
+    pub fn set_is_latest(&mut self, v: bool) -> &mut Self {
+        self.is_latest = Some(v);
+        self
+    }
 }
 
 flags! {
@@ -537,6 +740,8 @@ flags! {
         CacheControl,
         /// Key for content disposition.
         ContentDisposition,
+        /// Key for content encoding.
+        ContentEncoding,
         /// Key for content length.
         ContentLength,
         /// Key for content md5.
@@ -547,8 +752,14 @@ flags! {
         ContentType,
         /// Key for etag.
         Etag,
+        /// Key for expires.
+        Expires,
         /// Key for last last modified.
         LastModified,
+        /// Key for restore status.
+        RestoreStatus,
+        /// Key for storage class.
+        StorageClass,
         /// Key for version.
         Version,
     }
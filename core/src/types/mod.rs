@@ -25,17 +25,28 @@ mod metadata;
 pub use metadata::Metadata;
 pub use metadata::Metakey;
 
+mod restore;
+pub use restore::RestoreStatus;
+
+mod compression;
+pub use compression::Compression;
+
+mod transform;
+pub use transform::Transform;
+
 mod reader;
 pub use reader::BlockingReader;
 pub use reader::Reader;
 
 mod writer;
 pub use writer::BlockingWriter;
+pub use writer::ResumeToken;
 pub use writer::Writer;
 
 mod list;
 pub use list::BlockingLister;
 pub use list::Lister;
+pub use list::Walker;
 
 mod operator;
 pub use operator::operator_functions;
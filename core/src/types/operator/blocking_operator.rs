@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::Duration;
+
 use bytes::Bytes;
+use flagset::FlagSet;
+use http::HeaderMap;
 
 use super::operator_functions::*;
 use crate::raw::oio::BlockingRead;
@@ -87,6 +91,8 @@ pub struct BlockingOperator {
     accessor: FusedAccessor,
 
     limit: usize,
+    default_metakey: FlagSet<Metakey>,
+    content_type_from_extension: bool,
 }
 
 impl BlockingOperator {
@@ -104,7 +110,12 @@ impl BlockingOperator {
             .full_capability()
             .batch_max_operations
             .unwrap_or(1000);
-        Self { accessor, limit }
+        Self {
+            accessor,
+            limit,
+            default_metakey: Metakey::Mode.into(),
+            content_type_from_extension: false,
+        }
     }
 
     /// Get current operator's limit
@@ -121,6 +132,58 @@ impl BlockingOperator {
         op
     }
 
+    /// Get current operator's default metakey for list operations.
+    pub fn default_metakey(&self) -> FlagSet<Metakey> {
+        self.default_metakey
+    }
+
+    /// Specify the default metakey for all list operations.
+    ///
+    /// The default metakey will be used by [`BlockingOperator::list_with`] and
+    /// [`BlockingOperator::lister_with`] unless it's overridden by an explicit `metakey` call.
+    ///
+    /// Default: `Metakey::Mode`
+    pub fn with_default_metakey(&self, metakey: impl Into<FlagSet<Metakey>>) -> Self {
+        let mut op = self.clone();
+        op.default_metakey = metakey.into();
+        op
+    }
+
+    /// Get whether current operator guesses content type from path extension.
+    pub fn content_type_from_extension(&self) -> bool {
+        self.content_type_from_extension
+    }
+
+    /// Specify whether to guess content type from path extension for all write operations.
+    ///
+    /// If enabled, the content type guessed from the extension of path will be used by
+    /// [`BlockingOperator::write_with`] and [`BlockingOperator::writer_with`] unless it's overridden by an explicit `content_type` call.
+    ///
+    /// # Notes
+    ///
+    /// The built-in extension map is only available while the `content-type-guess` feature
+    /// is enabled, otherwise no content type will be guessed.
+    ///
+    /// Default: `false`
+    pub fn with_content_type_from_extension(&self, v: bool) -> Self {
+        let mut op = self.clone();
+        op.content_type_from_extension = v;
+        op
+    }
+
+    /// Build the default args of write operations for given path.
+    fn default_write_args(&self, path: &str) -> OpWrite {
+        let args = OpWrite::default();
+        if !self.content_type_from_extension {
+            return args;
+        }
+
+        match guess_content_type(path) {
+            Some(v) => args.with_content_type(v),
+            None => args,
+        }
+    }
+
     /// Get information of underlying accessor.
     ///
     /// # Examples
@@ -138,6 +201,29 @@ impl BlockingOperator {
     pub fn info(&self) -> OperatorInfo {
         OperatorInfo::new(self.accessor.info())
     }
+
+    /// Get the effective capability that will be used for operations on the given path.
+    ///
+    /// For now, it always equals to `info().full_capability()`. Layers or services
+    /// that route paths to different backends may return path specific capability
+    /// in the future, so capability-aware code should prefer this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// if op.effective_capability("path/to/file").write_can_append {
+    ///     // Append to the file.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn effective_capability(&self, _path: &str) -> Capability {
+        self.info().full_capability()
+    }
 }
 
 /// # Operator blocking API.
@@ -277,6 +363,31 @@ impl BlockingOperator {
         ))
     }
 
+    /// Get the metadata of given path along with the raw response headers.
+    ///
+    /// Refer to [`Operator::stat_raw`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let (meta, headers) = op.stat_raw("test")?;
+    /// let _ = headers.get("x-amz-server-side-encryption");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stat_raw(&self, path: &str) -> Result<(Metadata, HeaderMap)> {
+        let path = normalize_path(path);
+
+        let rp = self
+            .inner()
+            .blocking_stat(&path, OpStat::new().with_raw_headers(true))?;
+        let (meta, headers) = rp.into_parts();
+        Ok((meta, headers.unwrap_or_default()))
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -301,6 +412,35 @@ impl BlockingOperator {
         }
     }
 
+    /// Check if this path exists and is a dir.
+    ///
+    /// The path follows the same semantics as [`BlockingOperator::stat`], so dirs
+    /// should be checked with a trailing `/` like `is_dir("abc/")`.
+    pub fn is_dir(&self, path: &str) -> Result<bool> {
+        let r = self.stat(path);
+        match r {
+            Ok(meta) => Ok(meta.mode() == EntryMode::DIR),
+            Err(err) => match err.kind() {
+                ErrorKind::NotFound | ErrorKind::NotADirectory => Ok(false),
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Check if this path exists and is a file.
+    ///
+    /// The path follows the same semantics as [`BlockingOperator::stat`].
+    pub fn is_file(&self, path: &str) -> Result<bool> {
+        let r = self.stat(path);
+        match r {
+            Ok(meta) => Ok(meta.mode() == EntryMode::FILE),
+            Err(err) => match err.kind() {
+                ErrorKind::NotFound | ErrorKind::IsADirectory => Ok(false),
+                _ => Err(err),
+            },
+        }
+    }
+
     /// Create a dir at given path.
     ///
     /// # Notes
@@ -399,19 +539,35 @@ impl BlockingOperator {
                 }
 
                 let range = args.range();
-                let (size_hint, range) = if let Some(size) = range.size() {
+                // Empty range will not be sent to services, validate it against the
+                // content length instead.
+                let (size_hint, range) = if let Some(size) = range.size().filter(|v| *v > 0) {
                     (size, range)
                 } else {
+                    let mut stat_args = OpStat::default();
+                    if let Some(v) = args.version() {
+                        stat_args = stat_args.with_version(v);
+                    }
                     let size = inner
-                        .blocking_stat(&path, OpStat::default())?
+                        .blocking_stat(&path, stat_args)?
                         .into_metadata()
                         .content_length();
-                    let range = range.complete(size);
+                    let range = range.validate(size).map_err(|err| {
+                        err.with_operation("BlockingOperator::read_with")
+                            .with_context("service", inner.info().scheme().into_static())
+                            .with_context("path", &path)
+                    })?;
+                    // Nothing to read, e.g. the file is empty or the
+                    // range starts right at the end.
+                    if range.size() == Some(0) {
+                        return Ok(Vec::new());
+                    }
                     (range.size().unwrap(), range)
                 };
 
                 let (_, mut s) = inner.blocking_read(&path, args.with_range(range))?;
-                let mut buf = Vec::with_capacity(size_hint as usize);
+                let mut buf = Vec::new();
+                let _ = buf.try_reserve_exact(size_hint as usize);
                 s.read_to_end(&mut buf)?;
 
                 Ok(buf)
@@ -554,13 +710,16 @@ impl BlockingOperator {
         Ok(())
     }
 
-    /// Rename a file from `from` to `to`.
+    /// Copy the path and all nested dirs and files recursively from `from` to `to`.
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
-    /// - `to` will be overwritten if it exists.
-    /// - If `from` and `to` are the same, a `IsSameFile` error will occur.
+    /// - If `from` is a file, `copy_all` behaves the same as [`BlockingOperator::copy`].
+    /// - If `from` is a dir, all entries under `from` will be copied to the corresponding
+    ///   path under `to`. `to` will be created even if `from` is an empty dir.
+    /// - `from` will be treated as a dir if there are entries under it, even if the dir
+    ///   itself doesn't exist, e.g. implicit dirs on `s3`.
+    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
     ///
     /// # Examples
     ///
@@ -569,25 +728,125 @@ impl BlockingOperator {
     /// # use opendal::BlockingOperator;
     ///
     /// # fn test(op: BlockingOperator) -> Result<()> {
-    /// op.rename("path/to/file", "path/to/file2")?;
+    /// op.copy_all("path/to/dir/", "path/to/dir2/")?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn rename(&self, from: &str, to: &str) -> Result<()> {
+    pub fn copy_all(&self, from: &str, to: &str) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
+        if from.trim_end_matches('/') == to.trim_end_matches('/') {
             return Err(
-                Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("BlockingOperator::move")
+                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
+                    .with_operation("BlockingOperator::copy_all")
                     .with_context("service", self.info().scheme())
-                    .with_context("from", from),
+                    .with_context("from", from)
+                    .with_context("to", to),
             );
         }
 
+        if !self.is_dir_for_all(&from)? {
+            return self.copy(&from, &to);
+        }
+
+        let from = format!("{}/", from.trim_end_matches('/'));
+        let to = format!("{}/", to.trim_end_matches('/'));
+
+        if to.starts_with(&from) {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "to path is inside from path, copy will never finish",
+            )
+            .with_operation("BlockingOperator::copy_all")
+            .with_context("service", self.info().scheme())
+            .with_context("from", from)
+            .with_context("to", to));
+        }
+
+        let create_dir = self.info().full_capability().create_dir;
+        if create_dir {
+            self.create_dir(&to)?;
+        }
+
+        let obs = self
+            .lister_with(&from)
+            .recursive(true)
+            .metakey(Metakey::Mode)
+            .call()?;
+        for entry in obs {
+            let entry = entry?;
+            // Entries listed under `from` must start with `from`.
+            let target = format!("{to}{}", &entry.path()[from.len()..]);
+
+            if entry.metadata().mode() == EntryMode::DIR {
+                if create_dir {
+                    self.create_dir(&target)?;
+                }
+            } else {
+                self.inner()
+                    .blocking_copy(entry.path(), &target, OpCopy::new())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check whether the path should be handled as a dir by recursive operations
+    /// like [`BlockingOperator::copy_all`].
+    ///
+    /// Refer to `Operator::is_dir_for_all` for more details.
+    fn is_dir_for_all(&self, path: &str) -> Result<bool> {
+        let err = match self.stat(path) {
+            Ok(meta) => return Ok(meta.mode() == EntryMode::DIR),
+            Err(err) if err.kind() == ErrorKind::NotFound => err,
+            Err(err) => return Err(err),
+        };
+
+        let dir = format!("{}/", path.trim_end_matches('/'));
+        let lister = match self.lister(&dir) {
+            Ok(lister) => lister,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Err(err),
+            Err(e) => return Err(e),
+        };
+        for entry in lister {
+            // Some services will return the dir itself.
+            if entry?.path() != dir {
+                return Ok(true);
+            }
+        }
+
+        Err(err)
+    }
+
+    /// Rename a file or dir from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be both files or both dirs.
+    /// - `to` will be overwritten if it exists.
+    /// - If `from` and `to` are the same, a `IsSameFile` error will occur.
+    /// - Renaming dirs requires service supports renaming dir atomically, check
+    ///   `rename_dir` in [`Capability`]. Otherwise, an `Unsupported` error will occur,
+    ///   please use [`BlockingOperator::rename_all`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::BlockingOperator;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// op.rename("path/to/file", "path/to/file2")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let from = normalize_path(from);
         let to = normalize_path(to);
 
-        if !validate_path(&to, EntryMode::FILE) {
+        let is_dir = from.ends_with('/');
+        if !is_dir && to.ends_with('/') {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation("BlockingOperator::move")
@@ -595,6 +854,16 @@ impl BlockingOperator {
                     .with_context("to", to),
             );
         }
+        if is_dir && !to.ends_with('/') {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "from path is a directory but to path is not",
+            )
+            .with_operation("BlockingOperator::move")
+            .with_context("service", self.info().scheme())
+            .with_context("from", from)
+            .with_context("to", to));
+        }
 
         if from == to {
             return Err(
@@ -606,11 +875,80 @@ impl BlockingOperator {
             );
         }
 
+        if is_dir && !self.info().full_capability().rename_dir {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "service doesn't support rename dir atomically, use rename_all instead",
+            )
+            .with_operation("BlockingOperator::move")
+            .with_context("service", self.info().scheme())
+            .with_context("from", from));
+        }
+
         self.inner().blocking_rename(&from, &to, OpRename::new())?;
 
         Ok(())
     }
 
+    /// Rename the path and all nested dirs and files recursively from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - If `from` is a file, `rename_all` behaves the same as [`BlockingOperator::rename`].
+    /// - If `from` is a dir and service supports renaming dir atomically (like `fs`),
+    ///   the dir will be renamed by service directly.
+    /// - Otherwise, we will copy all entries via [`BlockingOperator::copy_all`] and then
+    ///   remove `from` via [`BlockingOperator::remove_all`].
+    /// - `from` will be treated as a dir if there are entries under it, even if the dir
+    ///   itself doesn't exist, e.g. implicit dirs on `s3`.
+    /// - Renaming a dir won't replace an existing `to` as a whole, refer to
+    ///   [`Operator::rename_all`] for more details.
+    ///
+    /// ## Partial failure
+    ///
+    /// While falling back to copy and delete, `rename_all` is not atomic. It's safe to
+    /// call `rename_all` again to resume.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::BlockingOperator;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// op.rename_all("path/to/dir/", "path/to/dir2/")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_all(&self, from: &str, to: &str) -> Result<()> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        if from.trim_end_matches('/') == to.trim_end_matches('/') {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
+                    .with_operation("BlockingOperator::rename_all")
+                    .with_context("service", self.info().scheme())
+                    .with_context("from", from)
+                    .with_context("to", to),
+            );
+        }
+
+        if !self.is_dir_for_all(&from)? {
+            return self.rename(&from, &to);
+        }
+
+        let from = format!("{}/", from.trim_end_matches('/'));
+        let to = format!("{}/", to.trim_end_matches('/'));
+
+        if self.info().full_capability().rename_dir {
+            return self.rename(&from, &to);
+        }
+
+        self.copy_all(&from, &to)?;
+        self.remove_all(&from)
+    }
+
     /// Write data with option described in OpenDAL [RFC-0661][`crate::docs::rfcs::rfc_0661_path_in_accessor`]
     ///
     /// # Notes
@@ -638,10 +976,12 @@ impl BlockingOperator {
 
         let bs = bs.into();
 
+        let args = self.default_write_args(&path);
+
         FunctionWrite(OperatorFunction::new(
             self.inner().clone(),
             path,
-            (OpWrite::default(), bs),
+            (args, bs),
             |inner, path, (args, mut bs)| {
                 if !validate_path(&path, EntryMode::FILE) {
                     return Err(
@@ -711,10 +1051,12 @@ impl BlockingOperator {
     pub fn writer_with(&self, path: &str) -> FunctionWriter {
         let path = normalize_path(path);
 
+        let args = self.default_write_args(&path);
+
         FunctionWriter(OperatorFunction::new(
             self.inner().clone(),
             path,
-            OpWrite::default(),
+            args,
             |inner, path, args| {
                 let path = normalize_path(&path);
 
@@ -794,9 +1136,12 @@ impl BlockingOperator {
     ///
     /// remove_via will remove files via the given vector iterators.
     ///
+    /// We will delete by chunks with given batch limit on the iterator.
+    ///
     /// # Notes
     ///
-    /// We don't support batch delete now.
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead. Otherwise, paths will be deleted one by one.
     ///
     /// # Examples
     ///
@@ -811,15 +1156,46 @@ impl BlockingOperator {
     /// # }
     /// ```
     pub fn remove_via(&self, input: impl Iterator<Item = String>) -> Result<()> {
-        for path in input {
-            self.delete(&path)?;
+        let input = input.map(|v| normalize_path(&v));
+
+        if !self.info().full_capability().batch {
+            for path in input {
+                self.inner().blocking_delete(&path, OpDelete::default())?;
+            }
+            return Ok(());
+        }
+
+        let mut input = input.peekable();
+        while input.peek().is_some() {
+            let batches = input
+                .by_ref()
+                .take(self.limit().max(1))
+                .map(|v| (v, OpDelete::default().into()))
+                .collect();
+            self.remove_batch(batches)?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete given paths in one batch request.
+    fn remove_batch(&self, batches: Vec<(String, BatchOperation)>) -> Result<()> {
+        let results = self
+            .inner()
+            .blocking_batch(OpBatch::new(batches))?
+            .into_results();
+
+        // TODO: return error here directly seems not a good idea?
+        for (_, result) in results {
+            let _ = result?;
         }
         Ok(())
     }
 
     /// # Notes
     ///
-    /// We don't support batch delete now.
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead.
     ///
     /// # Examples
     ///
@@ -842,7 +1218,12 @@ impl BlockingOperator {
     ///
     /// # Notes
     ///
-    /// We don't support batch delete now.
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead. Children are always deleted before the directory itself.
+    ///
+    /// Entries are removed based on the listing result only, nested files and
+    /// dirs will never be stated. If path ends with `/`, it will be listed
+    /// directly without stat.
     ///
     /// # Examples
     ///
@@ -851,32 +1232,53 @@ impl BlockingOperator {
     /// # use futures::io;
     /// # use opendal::BlockingOperator;
     /// # fn test(op: BlockingOperator) -> Result<()> {
-    /// op.remove_all("path/to/dir")?;
+    /// op.remove_all("path/to/dir/")?;
     /// # Ok(())
     /// # }
     /// ```
     pub fn remove_all(&self, path: &str) -> Result<()> {
-        let meta = match self.stat(path) {
-            Ok(metadata) => metadata,
+        // Path ends with `/` must be a dir, there is no need to stat it.
+        if !path.ends_with('/') {
+            let meta = match self.stat(path) {
+                Ok(metadata) => metadata,
 
-            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
 
-            Err(e) => return Err(e),
-        };
+                Err(e) => return Err(e),
+            };
 
-        if meta.mode() != EntryMode::DIR {
-            return self.delete(path);
+            if meta.mode() != EntryMode::DIR {
+                return self.delete(path);
+            }
         }
 
-        let obs = self.lister_with(path).recursive(true).call()?;
+        let obs = match self
+            .lister_with(path)
+            .recursive(true)
+            .metakey(Metakey::Mode)
+            .call()
+        {
+            Ok(obs) => obs,
+
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+
+            Err(e) => return Err(e),
+        };
 
-        for v in obs {
-            match v {
-                Ok(entry) => {
-                    self.inner()
-                        .blocking_delete(entry.path(), OpDelete::new())?;
+        if self.info().full_capability().batch {
+            let mut batches = Vec::with_capacity(self.limit());
+            for v in obs {
+                batches.push((v?.path().to_string(), OpDelete::default().into()));
+                if batches.len() >= self.limit() {
+                    self.remove_batch(std::mem::take(&mut batches))?;
                 }
-                Err(e) => return Err(e),
+            }
+            if !batches.is_empty() {
+                self.remove_batch(batches)?;
+            }
+        } else {
+            for v in obs {
+                self.inner().blocking_delete(v?.path(), OpDelete::new())?;
             }
         }
 
@@ -1020,7 +1422,7 @@ impl BlockingOperator {
         FunctionList(OperatorFunction::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_metakey(self.default_metakey),
             |inner, path, args| {
                 let lister = BlockingLister::create(inner, &path, args)?;
 
@@ -1184,14 +1586,124 @@ impl BlockingOperator {
         FunctionLister(OperatorFunction::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_metakey(self.default_metakey),
             |inner, path, args| BlockingLister::create(inner, &path, args),
         ))
     }
+
+    /// Presign an operation.
+    ///
+    /// This is the blocking version of [`Operator::presign`]. The request is
+    /// generated by the underlying service the same way as the async one, so
+    /// the returned [`PresignedRequest`] matches it for the same inputs.
+    ///
+    /// Presign post is not supported here, please use `Operator::presign_post`
+    /// instead.
+    ///
+    /// # Notes
+    ///
+    /// Services that don't support blocking natively should be wrapped with
+    /// [`layers::BlockingLayer`] so that the presign is driven by its runtime
+    /// handle.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::raw::OpDelete;
+    /// use opendal::raw::OpPresign;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let signed_req = op.presign(
+    ///     "test.txt",
+    ///     OpPresign::new(OpDelete::new(), Duration::from_secs(3600)),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn presign(&self, path: &str, op: OpPresign) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        if let PresignOperation::Post(_) = op.operation() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "presign post can't be represented as a request, use presign_post instead",
+            )
+            .with_operation("BlockingOperator::presign")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        let rp = self.inner().blocking_presign(&path, op)?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for stat(head).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let signed_req = op.presign_stat("test", Duration::from_secs(3600))?;
+    /// let req = http::Request::builder()
+    ///     .method(signed_req.method())
+    ///     .uri(signed_req.uri())
+    ///     .body(())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign(path, OpPresign::new(OpStat::new(), expire))
+    }
+
+    /// Presign an operation for read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let signed_req = op.presign_read("test.txt", Duration::from_secs(3600))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign(path, OpPresign::new(OpRead::new(), expire))
+    }
+
+    /// Presign an operation for write.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let signed_req = op.presign_write("test.txt", Duration::from_secs(3600))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn presign_write(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign(path, OpPresign::new(OpWrite::new(), expire))
+    }
 }
 
 impl From<BlockingOperator> for Operator {
     fn from(v: BlockingOperator) -> Self {
-        Operator::from_inner(v.accessor).with_limit(v.limit)
+        Operator::from_inner(v.accessor)
+            .with_limit(v.limit)
+            .with_default_metakey(v.default_metakey)
+            .with_content_type_from_extension(v.content_type_from_extension)
     }
 }
@@ -56,4 +56,29 @@ impl OperatorInfo {
     pub fn native_capability(&self) -> Capability {
         self.0.native_capability()
     }
+
+    /// Check if this operator can write conditionally, that is, writing
+    /// will fail if the target file already exists.
+    ///
+    /// It's a shortcut of `write_with_if_not_exists` in [`Capability`].
+    pub fn can_write_conditional(&self) -> bool {
+        let cap = self.full_capability();
+        cap.write && cap.write_with_if_not_exists
+    }
+
+    /// Check if this operator can list all versions of files.
+    ///
+    /// It's a shortcut of `list_with_versions` in [`Capability`].
+    pub fn can_list_versions(&self) -> bool {
+        let cap = self.full_capability();
+        cap.list && cap.list_with_versions
+    }
+
+    /// Check if this operator can delete files in batch.
+    ///
+    /// It's a shortcut of `batch_delete` in [`Capability`].
+    pub fn can_batch_delete(&self) -> bool {
+        let cap = self.full_capability();
+        cap.batch && cap.batch_delete
+    }
 }
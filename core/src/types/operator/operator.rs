@@ -15,20 +15,33 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cmp;
+use std::collections::HashMap;
+use std::ops::RangeBounds;
 use std::time::Duration;
 
 use bytes::Buf;
 use bytes::Bytes;
+use flagset::FlagSet;
+use futures::future;
 use futures::stream;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use http::HeaderMap;
 
 use super::BlockingOperator;
 use crate::operator_futures::*;
 use crate::raw::oio::ReadExt;
 use crate::raw::oio::WriteExt;
 use crate::raw::*;
+use crate::types::compression::new_compress_writer;
+use crate::types::compression::new_decompress_reader;
+use crate::types::compression::prepare_compress;
+use crate::types::transform::new_transform_reader;
+use crate::types::transform::new_transform_writer;
+use crate::types::transform::prepare_transform;
+use crate::types::transform::take_read_transform;
 use crate::*;
 
 /// Operator is the entry for all public async APIs.
@@ -69,6 +82,10 @@ pub struct Operator {
 
     // limit is usually the maximum size of data that operator will handle in one operation
     limit: usize,
+    // default_metakey is the metakey used by list operations if not specified.
+    default_metakey: FlagSet<Metakey>,
+    // content_type_from_extension controls whether to guess content type for write operations.
+    content_type_from_extension: bool,
 }
 
 /// # Operator basic API.
@@ -83,7 +100,12 @@ impl Operator {
             .full_capability()
             .batch_max_operations
             .unwrap_or(1000);
-        Self { accessor, limit }
+        Self {
+            accessor,
+            limit,
+            default_metakey: Metakey::Mode.into(),
+            content_type_from_extension: false,
+        }
     }
 
     pub(super) fn into_inner(self) -> FusedAccessor {
@@ -105,6 +127,95 @@ impl Operator {
         op
     }
 
+    /// Get current operator's default metakey for list operations.
+    pub fn default_metakey(&self) -> FlagSet<Metakey> {
+        self.default_metakey
+    }
+
+    /// Specify the default metakey for all list operations.
+    ///
+    /// The default metakey will be used by [`Operator::list_with`] and [`Operator::lister_with`]
+    /// unless it's overridden by an explicit `metakey` call.
+    ///
+    /// Default: `Metakey::Mode`
+    pub fn with_default_metakey(&self, metakey: impl Into<FlagSet<Metakey>>) -> Self {
+        let mut op = self.clone();
+        op.default_metakey = metakey.into();
+        op
+    }
+
+    /// Get whether current operator guesses content type from path extension.
+    pub fn content_type_from_extension(&self) -> bool {
+        self.content_type_from_extension
+    }
+
+    /// Specify whether to guess content type from path extension for all write operations.
+    ///
+    /// If enabled, the content type guessed from the extension of path will be used by
+    /// [`Operator::write_with`] and [`Operator::writer_with`] unless it's overridden by an explicit `content_type` call.
+    ///
+    /// # Notes
+    ///
+    /// The built-in extension map is only available while the `content-type-guess` feature
+    /// is enabled, otherwise no content type will be guessed.
+    ///
+    /// Default: `false`
+    pub fn with_content_type_from_extension(&self, v: bool) -> Self {
+        let mut op = self.clone();
+        op.content_type_from_extension = v;
+        op
+    }
+
+    /// Build the default args of write operations for given path.
+    fn default_write_args(&self, path: &str) -> OpWrite {
+        let args = OpWrite::default();
+        if !self.content_type_from_extension {
+            return args;
+        }
+
+        match guess_content_type(path) {
+            Some(v) => args.with_content_type(v),
+            None => args,
+        }
+    }
+
+    /// Create a new operator whose `stat` results will be cached in memory.
+    ///
+    /// At most `capacity` paths will be cached, the least recently used one will be
+    /// evicted first. Cached metadata will be expired after `ttl`.
+    ///
+    /// # Notes
+    ///
+    /// - Only `stat` (and `is_exist`) without conditions or version will be cached.
+    /// - `write`, `delete`, `copy`, `rename` and `batch` via the returned operator
+    ///   will invalidate the cache of affected paths. Changes made by others will
+    ///   only be visible after `ttl`.
+    /// - `read` and `list` will bypass the cache.
+    /// - The cache is shared between all clones of the returned operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use std::time::Duration;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let op = op.with_stat_cache(1024, Duration::from_secs(60));
+    /// let meta = op.stat("path/to/file").await?;
+    /// // This stat will be served from cache.
+    /// let meta = op.stat("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_stat_cache(&self, capacity: usize, ttl: Duration) -> Self {
+        self.clone()
+            .layer(layers::StatCacheLayer::new(capacity, ttl))
+            .with_limit(self.limit)
+            .with_default_metakey(self.default_metakey)
+            .with_content_type_from_extension(self.content_type_from_extension)
+    }
+
     /// Get information of underlying accessor.
     ///
     /// # Examples
@@ -124,11 +235,72 @@ impl Operator {
         OperatorInfo::new(self.accessor.info())
     }
 
+    /// Get the effective capability that will be used for operations on the given path.
+    ///
+    /// For now, it always equals to `info().full_capability()`. Layers or services
+    /// that route paths to different backends may return path specific capability
+    /// in the future, so capability-aware code should prefer this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// if op.effective_capability("path/to/file").write_can_append {
+    ///     // Append to the file.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn effective_capability(&self, _path: &str) -> Capability {
+        self.info().full_capability()
+    }
+
     /// Create a new blocking operator.
     ///
     /// This operation is nearly no cost.
     pub fn blocking(&self) -> BlockingOperator {
-        BlockingOperator::from_inner(self.accessor.clone()).with_limit(self.limit)
+        BlockingOperator::from_inner(self.accessor.clone())
+            .with_limit(self.limit)
+            .with_default_metakey(self.default_metakey)
+            .with_content_type_from_extension(self.content_type_from_extension)
+    }
+
+    /// Create a new blocking operator bound to given runtime's handle.
+    ///
+    /// If the underlying service doesn't support blocking natively, a
+    /// [`BlockingLayer`](crate::layers::BlockingLayer) with this handle will be
+    /// added, so it's fine to call blocking APIs from threads that are not
+    /// managed by tokio.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # fn test(op: Operator, rt: tokio::runtime::Runtime) -> Result<()> {
+    /// let bop = op.blocking_with(rt.handle().clone());
+    /// let _ = bop.read("test")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_with(&self, handle: tokio::runtime::Handle) -> BlockingOperator {
+        let accessor = if self.info().full_capability().blocking {
+            self.accessor.clone()
+        } else {
+            self.clone()
+                .layer(layers::BlockingLayer::with_handle(handle))
+                .into_inner()
+        };
+
+        BlockingOperator::from_inner(accessor)
+            .with_limit(self.limit)
+            .with_default_metakey(self.default_metakey)
+            .with_content_type_from_extension(self.content_type_from_extension)
     }
 }
 
@@ -150,12 +322,94 @@ impl Operator {
     /// # }
     /// ```
     pub async fn check(&self) -> Result<()> {
-        let mut ds = self.lister("/").await?;
+        self.check_with().await
+    }
+
+    /// Check if this operator can work correctly with options.
+    ///
+    /// By default, we will send a `list` request to path and return any errors we met.
+    /// Users can enable `round_trip` to write, read and delete a temporary file to make
+    /// sure the operator has full permission.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.check_with().round_trip(true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_with(&self) -> FutureCheck {
+        let fut = FutureCheck(OperatorFuture::new(
+            self.inner().clone(),
+            "/".to_string(),
+            CheckArgs {
+                op: self.clone(),
+                round_trip: false,
+            },
+            |_, path, args| {
+                let fut = async move {
+                    let CheckArgs { op, round_trip } = args;
+
+                    let mut ds = op.lister_with(&path).metakey(Metakey::Mode).await?;
+                    match ds.next().await {
+                        Some(Err(e)) if e.kind() != ErrorKind::NotFound => {
+                            return Err(e.with_context("check", "list"))
+                        }
+                        _ => {}
+                    }
+
+                    if round_trip {
+                        op.check_round_trip().await?;
+                    }
+                    Ok(())
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
 
-        match ds.next().await {
-            Some(Err(e)) if e.kind() != ErrorKind::NotFound => Err(e),
-            _ => Ok(()),
+    /// Write, read and delete a temporary file to check the permission of operator.
+    ///
+    /// The temporary file will be deleted even if write or read failed.
+    async fn check_round_trip(&self) -> Result<()> {
+        let path = format!(".opendal_check_{}", uuid::Uuid::new_v4());
+        let content = Bytes::from_static(b"opendal check");
+
+        let res = async {
+            self.write(&path, content.clone())
+                .await
+                .map_err(|err| err.with_context("check", "write"))?;
+
+            let bs = self
+                .read(&path)
+                .await
+                .map_err(|err| err.with_context("check", "read"))?;
+            if bs != content {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "content read is not the same as written",
+                )
+                .with_operation("Operator::check")
+                .with_context("service", self.info().scheme())
+                .with_context("path", &path)
+                .with_context("check", "read"));
+            }
+            Ok(())
         }
+        .await;
+
+        // Always try to clean up the temporary file.
+        let deleted = self
+            .delete(&path)
+            .await
+            .map_err(|err| err.with_context("check", "delete"));
+
+        res.and(deleted)
     }
 
     /// Get given path's metadata.
@@ -289,8 +543,31 @@ impl Operator {
             OpStat::default(),
             |inner, path, args| {
                 let fut = async move {
-                    let rp = inner.stat(&path, args).await?;
-                    Ok(rp.into_metadata())
+                    let infer_dir = args.infer_dir();
+                    let has_time_condition =
+                        args.if_modified_since().is_some() || args.if_unmodified_since().is_some();
+                    match inner.stat(&path, args).await {
+                        Ok(rp) => Ok(rp.into_metadata()),
+                        Err(err)
+                            if infer_dir
+                                && err.kind() == ErrorKind::NotFound
+                                && path.ends_with('/')
+                                && inner.info().full_capability().list =>
+                        {
+                            // Inferred dir doesn't have last modified to evaluate the conditions.
+                            if has_time_condition {
+                                return Err(Error::new(
+                                    ErrorKind::Unsupported,
+                                    "infer dir doesn't support if_modified_since or if_unmodified_since",
+                                )
+                                .with_operation("stat")
+                                .with_context("service", inner.info().scheme())
+                                .with_context("path", &path));
+                            }
+                            infer_dir_via_list(inner, &path, err).await
+                        }
+                        Err(err) => Err(err),
+                    }
                 };
 
                 Box::pin(fut)
@@ -300,6 +577,41 @@ impl Operator {
         fut
     }
 
+    /// Get the metadata of given path along with the raw response headers.
+    ///
+    /// This is an escape hatch to access service specific headers like SSE or
+    /// replication status that [`Metadata`] doesn't model yet.
+    ///
+    /// # Notes
+    ///
+    /// - Only HTTP based services with `stat_with_raw_headers` capability support
+    ///   this, others will return [`ErrorKind::Unsupported`].
+    /// - The returned headers could be empty if no response has been received, for
+    ///   example, while stat a dir path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let (meta, headers) = op.stat_raw("test").await?;
+    /// let _ = headers.get("x-amz-server-side-encryption");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_raw(&self, path: &str) -> Result<(Metadata, HeaderMap)> {
+        let path = normalize_path(path);
+
+        let rp = self
+            .inner()
+            .stat(&path, OpStat::new().with_raw_headers(true))
+            .await?;
+        let (meta, headers) = rp.into_parts();
+        Ok((meta, headers.unwrap_or_default()))
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -327,6 +639,109 @@ impl Operator {
         }
     }
 
+    /// Check if this path exists and is a dir.
+    ///
+    /// The path follows the same semantics as [`Operator::stat`], so dirs should be
+    /// checked with a trailing `/` like `is_dir("abc/")`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let _ = op.is_dir("test/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn is_dir(&self, path: &str) -> Result<bool> {
+        let r = self.stat(path).await;
+        match r {
+            Ok(meta) => Ok(meta.mode() == EntryMode::DIR),
+            Err(err) => match err.kind() {
+                ErrorKind::NotFound | ErrorKind::NotADirectory => Ok(false),
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Check if this path exists and is a file.
+    ///
+    /// The path follows the same semantics as [`Operator::stat`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let _ = op.is_file("test").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn is_file(&self, path: &str) -> Result<bool> {
+        let r = self.stat(path).await;
+        match r {
+            Ok(meta) => Ok(meta.mode() == EntryMode::FILE),
+            Err(err) => match err.kind() {
+                ErrorKind::NotFound | ErrorKind::IsADirectory => Ok(false),
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Get metadata of paths from given stream.
+    ///
+    /// Results will be returned as `(path, Result<Metadata>)` so that failed stat
+    /// on one path won't affect others.
+    ///
+    /// # Notes
+    ///
+    /// At most [`Operator::limit`] stat will be running at the same time, and
+    /// results are returned in the order of completion instead of input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use futures::stream;
+    /// use futures::StreamExt;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = stream::iter(vec!["abc".to_string(), "def".to_string()]);
+    /// let mut s = op.stat_many(paths);
+    /// while let Some((path, meta)) = s.next().await {
+    ///     println!("{path}: {:?}", meta.map(|m| m.content_length()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stat_many(
+        &self,
+        paths: impl Stream<Item = String>,
+    ) -> impl Stream<Item = (String, Result<Metadata>)> {
+        let op = self.clone();
+        let limit = cmp::max(1, self.limit());
+
+        paths
+            .map(move |path| {
+                let op = op.clone();
+                async move {
+                    let res = op.stat(&path).await;
+                    (path, res)
+                }
+            })
+            .buffer_unordered(limit)
+    }
+
     /// Create a dir at given path.
     ///
     /// # Notes
@@ -353,21 +768,59 @@ impl Operator {
     /// # }
     /// ```
     pub async fn create_dir(&self, path: &str) -> Result<()> {
+        self.create_dir_with(path).await
+    }
+
+    /// Create a dir at given path with extra options.
+    ///
+    /// # Notes
+    ///
+    /// Options only take effect on services that model dirs as zero-byte marker
+    /// objects like `s3`, they will be ignored by other services like `fs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_dir_with("path/to/dir/")
+    ///     .content_type("application/x-directory")
+    ///     .user_metadata(HashMap::from([("owner".to_string(), "opendal".to_string())]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_dir_with(&self, path: &str) -> FutureCreateDir {
         let path = normalize_path(path);
 
-        if !validate_path(&path, EntryMode::DIR) {
-            return Err(Error::new(
-                ErrorKind::NotADirectory,
-                "the path trying to create should end with `/`",
-            )
-            .with_operation("create_dir")
-            .with_context("service", self.inner().info().scheme())
-            .with_context("path", &path));
-        }
+        let fut = FutureCreateDir(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpCreateDir::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::DIR) {
+                        return Err(Error::new(
+                            ErrorKind::NotADirectory,
+                            "the path trying to create should end with `/`",
+                        )
+                        .with_operation("create_dir")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
 
-        self.inner().create_dir(&path, OpCreateDir::new()).await?;
+                    inner.create_dir(&path, args).await?;
 
-        Ok(())
+                    Ok(())
+                };
+                Box::pin(fut)
+            },
+        ));
+
+        fut
     }
 
     /// Read the whole path into a bytes.
@@ -412,7 +865,7 @@ impl Operator {
     pub fn read_with(&self, path: &str) -> FutureRead {
         let path = normalize_path(path);
 
-        let fut = FutureRead(OperatorFuture::new(
+        let fut = OperatorFuture::new(
             self.inner().clone(),
             path,
             OpRead::default(),
@@ -428,50 +881,306 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
+                    let transform = take_read_transform(&args)?;
                     let range = args.range();
-                    let (size_hint, range) = if let Some(size) = range.size() {
-                        (size, range)
+                    let verify_checksum = args.verify_checksum() && range.is_full();
+                    let verify_length = args.verify_length();
+                    // Empty range will not be sent to services, but the metadata is
+                    // still required to validate the range and the conditions.
+                    let meta = if range.size().is_none()
+                        || range.size() == Some(0)
+                        || args.decompress() == Some(Compression::Auto)
+                        || verify_length
+                    {
+                        let mut stat_args = OpStat::default();
+                        if let Some(v) = args.version() {
+                            stat_args = stat_args.with_version(v);
+                        }
+                        Some(inner.stat(&path, stat_args).await?.into_metadata())
                     } else {
-                        let size = inner
-                            .stat(&path, OpStat::default())
-                            .await?
-                            .into_metadata()
-                            .content_length();
-                        let range = range.complete(size);
-                        (range.size().unwrap(), range)
+                        None
+                    };
+                    let (size_hint, range, content_md5) = match &meta {
+                        Some(meta) => {
+                            let range = range.validate(meta.content_length()).map_err(|err| {
+                                err.with_operation("read")
+                                    .with_context("service", inner.info().scheme())
+                                    .with_context("path", &path)
+                            })?;
+                            // Nothing to read, e.g. the file is empty or the
+                            // range starts right at the end.
+                            if range.size() == Some(0) {
+                                // No request will be sent, so the conditions must be
+                                // evaluated against the metadata instead.
+                                check_read_conditions(&args, meta).map_err(|err| {
+                                    err.with_operation("read")
+                                        .with_context("service", inner.info().scheme())
+                                        .with_context("path", &path)
+                                })?;
+                                return Ok(Vec::new());
+                            }
+                            let content_md5 = if verify_checksum {
+                                meta.content_md5().map(|v| v.to_string())
+                            } else {
+                                None
+                            };
+                            (range.size().unwrap(), range, content_md5)
+                        }
+                        None => (
+                            range
+                                .size()
+                                .expect("range size must be known if metadata is not fetched"),
+                            range,
+                            None,
+                        ),
                     };
+                    let compression = match args.decompress() {
+                        Some(Compression::Auto) => meta
+                            .as_ref()
+                            .and_then(|meta| meta.content_encoding())
+                            .and_then(Compression::from_content_encoding),
+                        v => v,
+                    };
+
+                    let progress = args.progress().cloned();
+                    // Short reads can only be detected if the range has been resolved
+                    // against the content length.
+                    let resolved = meta.is_some();
+                    let etag = meta.as_ref().and_then(|meta| meta.etag()).map(String::from);
+
+                    let plan = match range.offset() {
+                        Some(offset)
+                            if args.auto_concurrent()
+                                && content_md5.is_none()
+                                && compression.is_none()
+                                && transform.is_none()
+                                && inner.info().full_capability().read_with_range =>
+                        {
+                            auto_concurrent_plan(size_hint, args.chunk(), args.memory_budget())
+                                .map(|(chunk, concurrent)| (offset, chunk, concurrent))
+                        }
+                        _ => None,
+                    };
+                    if let Some((offset, chunk, concurrent)) = plan {
+                        let mut chunks = stream::iter((0..size_hint).step_by(chunk))
+                            .map(|start| {
+                                let inner = inner.clone();
+                                let path = path.clone();
+                                let size = cmp::min(chunk as u64, size_hint - start);
+                                let args = args
+                                    .clone()
+                                    .with_range(BytesRange::new(Some(offset + start), Some(size)));
+                                let etag = etag.clone();
+                                async move {
+                                    let (_, mut r) = inner.read(&path, args.clone()).await?;
+                                    let mut buf = Vec::with_capacity(size as usize);
+                                    r.read_to_end(&mut buf).await?;
+                                    if resolved {
+                                        resume_short_read(
+                                            &inner,
+                                            &path,
+                                            args,
+                                            etag.as_deref(),
+                                            &mut buf,
+                                            verify_length,
+                                        )
+                                        .await?;
+                                    }
+                                    Ok::<_, Error>(buf)
+                                }
+                            })
+                            .buffered(concurrent);
+
+                        let mut buf = Vec::new();
+                        let _ = buf.try_reserve_exact(size_hint as usize);
+                        while let Some(bs) = chunks.try_next().await? {
+                            buf.extend_from_slice(&bs);
+                            if let Some(progress) = &progress {
+                                progress.call(buf.len() as u64, Some(size_hint));
+                            }
+                        }
+                        return Ok(buf);
+                    }
 
-                    let (_, mut s) = inner.read(&path, args.with_range(range)).await?;
-                    let mut buf = Vec::with_capacity(size_hint as usize);
+                    let args = args.with_range(range);
+                    let (_, s) = inner.read(&path, args.clone()).await?;
+                    let s: oio::Reader = match content_md5 {
+                        Some(md5) => Box::new(oio::ChecksumReader::new(s, &md5)),
+                        None => s,
+                    };
+                    let s: oio::Reader = match progress {
+                        Some(progress) => {
+                            Box::new(oio::ProgressReader::new(s, progress, Some(size_hint)))
+                        }
+                        None => s,
+                    };
+                    // The size of content will be changed by transform or decompress, so
+                    // short reads can't be detected anymore.
+                    let transformed = compression.is_some() || transform.is_some();
+                    // Transform is applied first so that the content could be decrypted
+                    // before decompressing.
+                    let s = new_transform_reader(s, transform);
+                    let mut s = new_decompress_reader(s, compression)?;
+                    // The size hint is only an upper bound if the range is not resolved,
+                    // which could be too large to allocate, e.g. `0..u64::MAX`.
+                    let mut buf = Vec::new();
+                    let _ = buf.try_reserve_exact(size_hint as usize);
                     s.read_to_end(&mut buf).await?;
+                    if resolved && !transformed {
+                        resume_short_read(
+                            &inner,
+                            &path,
+                            args,
+                            etag.as_deref(),
+                            &mut buf,
+                            verify_length,
+                        )
+                        .await?;
+                    }
 
                     Ok(buf)
                 };
 
                 Box::pin(fut)
             },
-        ));
+        );
 
-        fut
+        FutureRead(fut, self.limit())
     }
 
-    /// Create a new reader which can read the whole path.
+    /// Read the whole path only if its etag doesn't match the given one.
+    ///
+    /// Returns `Ok(None)` if the file is not modified, which is signaled by services
+    /// via `304 Not Modified`. This is useful for caches that revalidate their content.
+    ///
+    /// # Notes
+    ///
+    /// Services that don't support `read_with_if_none_match` will return
+    /// [`ErrorKind::Unsupported`].
     ///
     /// # Examples
     ///
-    /// ```no_run
+    /// ```
     /// # use std::io::Result;
     /// # use opendal::Operator;
-    /// # use futures::TryStreamExt;
-    /// # use opendal::Scheme;
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let r = op.reader("path/to/file").await?;
+    /// match op.read_if_modified("path/to/file", "\"etag\"").await? {
+    ///     Some(bs) => println!("file changed: {} bytes", bs.len()),
+    ///     None => println!("file not modified"),
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn reader(&self, path: &str) -> Result<Reader> {
-        self.reader_with(path).await
+    pub async fn read_if_modified(&self, path: &str, etag: &str) -> Result<Option<Vec<u8>>> {
+        if !self.info().full_capability().read_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "service doesn't support read with if none match",
+            )
+            .with_operation("Operator::read_if_modified")
+            .with_context("service", self.info().scheme())
+            .with_context("path", path));
+        }
+
+        match self.read_with(path).if_none_match(etag).await {
+            Ok(bs) => Ok(Some(bs)),
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Read the whole path into the given buffer.
+    ///
+    /// `buf` will be cleared before reading, and its capacity will be reused.
+    /// Returns the number of bytes read.
+    ///
+    /// This function is useful to amortize allocations while reading lots of
+    /// small files in a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut buf = Vec::new();
+    /// for path in ["path/to/file_a", "path/to/file_b"] {
+    ///     let n = op.read_into(path, &mut buf).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_into(&self, path: &str, buf: &mut Vec<u8>) -> Result<usize> {
+        self.range_read_into(path, .., buf).await
+    }
+
+    /// Read the specified range of path into the given buffer.
+    ///
+    /// `buf` will be cleared before reading, and its capacity will be reused.
+    /// Returns the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut buf = Vec::with_capacity(1024);
+    /// let n = op.range_read_into("path/to/file", 0..1024, &mut buf).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn range_read_into(
+        &self,
+        path: &str,
+        range: impl RangeBounds<u64>,
+        buf: &mut Vec<u8>,
+    ) -> Result<usize> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "read path is a directory")
+                    .with_operation("Operator::read_into")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let range: BytesRange = range.into();
+
+        buf.clear();
+        if let Some(size) = range.size() {
+            let _ = buf.try_reserve(size as usize);
+        }
+
+        let (_, mut s) = self
+            .inner()
+            .read(&path, OpRead::new().with_range(range))
+            .await?;
+        s.read_to_end(buf).await
+    }
+
+    /// Create a new reader which can read the whole path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # use futures::TryStreamExt;
+    /// # use opendal::Scheme;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = op.reader("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reader(&self, path: &str) -> Result<Reader> {
+        self.reader_with(path).await
     }
 
     /// Create a new reader with extra options
@@ -519,6 +1228,9 @@ impl Operator {
 
     /// Write bytes into path.
     ///
+    /// Returns the metadata of the written file, see [`Writer::close`] for
+    /// the fields that could be returned.
+    ///
     /// # Notes
     ///
     /// - Write will make sure all bytes has been written, or an error will be returned.
@@ -534,11 +1246,14 @@ impl Operator {
     ///
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.write("path/to/file", vec![0; 4096]).await?;
+    /// let meta = op.write("path/to/file", vec![0; 4096]).await?;
+    /// if let Some(etag) = meta.etag() {
+    ///     op.stat_with("path/to/file").if_match(etag).await?;
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn write(&self, path: &str, bs: impl Into<Bytes>) -> Result<()> {
+    pub async fn write(&self, path: &str, bs: impl Into<Bytes>) -> Result<Metadata> {
         let bs = bs.into();
         self.write_with(path, bs).await
     }
@@ -565,50 +1280,227 @@ impl Operator {
     /// # }
     /// ```
     pub async fn copy(&self, from: &str, to: &str) -> Result<()> {
+        self.copy_with(from, to).await
+    }
+
+    /// Copy a file from `from` to `to` with extra options.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file.
+    /// - If `from` and `to` are the same, an `IsSameFile` error will occur unless
+    ///   metadata is replaced, which allows updating metadata like content type
+    ///   without re-uploading.
+    /// - Services that don't support copy will return `Unsupported`, use
+    ///   [`FutureCopy::allow_fallback`] to copy via read and write instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::raw::MetadataDirective;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.copy_with("path/to/file", "path/to/file2")
+    ///     .if_none_match("*")
+    ///     .await?;
+    /// op.copy_with("path/to/file", "path/to/file")
+    ///     .metadata_directive(MetadataDirective::Replace)
+    ///     .content_type("text/plain")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_with(&self, from: &str, to: &str) -> FutureCopy {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("Operator::copy")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from),
-            );
-        }
+        let fut = FutureCopy(OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (OpCopy::default(), to),
+            |inner, from, (args, to)| {
+                let fut = async move {
+                    if !validate_path(&from, EntryMode::FILE) {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "from path is a directory",
+                        )
+                        .with_operation("Operator::copy")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from));
+                    }
 
-        let to = normalize_path(to);
+                    if !validate_path(&to, EntryMode::FILE) {
+                        return Err(
+                            Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                                .with_operation("Operator::copy")
+                                .with_context("service", inner.info().scheme())
+                                .with_context("to", to),
+                        );
+                    }
 
-        if !validate_path(&to, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "to path is a directory")
-                    .with_operation("Operator::copy")
-                    .with_context("service", self.info().scheme())
-                    .with_context("to", to),
-            );
-        }
+                    let replace = args.metadata_directive() == Some(MetadataDirective::Replace)
+                        || args.content_type().is_some();
+                    if from == to && !replace {
+                        return Err(Error::new(
+                            ErrorKind::IsSameFile,
+                            "from and to paths are same",
+                        )
+                        .with_operation("Operator::copy")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from)
+                        .with_context("to", to));
+                    }
+
+                    if args.allow_fallback() && !inner.info().full_capability().copy {
+                        return copy_via_read_write(inner, &from, &to, args)
+                            .await
+                            .map_err(|err| {
+                                err.with_operation("Operator::copy")
+                                    .with_context("from", from)
+                                    .with_context("to", to)
+                            });
+                    }
+
+                    inner.copy(&from, &to, args).await?;
+
+                    Ok(())
+                };
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
+
+    /// Copy the path and all nested dirs and files recursively from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - If `from` is a file, `copy_all` behaves the same as [`Operator::copy`].
+    /// - If `from` is a dir, all entries under `from` will be copied to the corresponding
+    ///   path under `to`. `to` will be created even if `from` is an empty dir.
+    /// - `from` will be treated as a dir if there are entries under it, even if the dir
+    ///   itself doesn't exist, e.g. implicit dirs on `s3`.
+    /// - Files are copied via service's native `copy`, at most [`Operator::limit`] copy
+    ///   will be running at the same time.
+    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.copy_all("path/to/dir/", "path/to/dir2/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_all(&self, from: &str, to: &str) -> Result<()> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if from == to {
+        if from.trim_end_matches('/') == to.trim_end_matches('/') {
             return Err(
                 Error::new(ErrorKind::IsSameFile, "from and to paths are same")
-                    .with_operation("Operator::copy")
+                    .with_operation("Operator::copy_all")
                     .with_context("service", self.info().scheme())
                     .with_context("from", from)
                     .with_context("to", to),
             );
         }
 
-        self.inner().copy(&from, &to, OpCopy::new()).await?;
+        if !self.is_dir_for_all(&from).await? {
+            return self.copy(&from, &to).await;
+        }
 
-        Ok(())
+        let from = format!("{}/", from.trim_end_matches('/'));
+        let to = format!("{}/", to.trim_end_matches('/'));
+
+        if to.starts_with(&from) {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "to path is inside from path, copy will never finish",
+            )
+            .with_operation("Operator::copy_all")
+            .with_context("service", self.info().scheme())
+            .with_context("from", from)
+            .with_context("to", to));
+        }
+
+        let create_dir = self.info().full_capability().create_dir;
+        if create_dir {
+            self.create_dir(&to).await?;
+        }
+
+        let from = from.as_str();
+        let to = to.as_str();
+        self.lister_with(from)
+            .recursive(true)
+            .metakey(Metakey::Mode)
+            .await?
+            .try_for_each_concurrent(self.limit, |entry| async move {
+                // Entries listed under `from` must start with `from`.
+                let target = format!("{to}{}", &entry.path()[from.len()..]);
+
+                if entry.metadata().mode() == EntryMode::DIR {
+                    if create_dir {
+                        self.create_dir(&target).await?;
+                    }
+                } else {
+                    self.inner()
+                        .copy(entry.path(), &target, OpCopy::new())
+                        .await?;
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    /// Check whether the path should be handled as a dir by recursive operations
+    /// like [`Operator::copy_all`].
+    ///
+    /// Services like `s3` have implicit dirs that only exist as the prefix of other
+    /// files, stat on them will return `NotFound`. So we will list the path as a dir
+    /// to check if there are any entries under it before reporting `NotFound`.
+    async fn is_dir_for_all(&self, path: &str) -> Result<bool> {
+        let err = match self.stat(path).await {
+            Ok(meta) => return Ok(meta.mode() == EntryMode::DIR),
+            Err(err) if err.kind() == ErrorKind::NotFound => err,
+            Err(err) => return Err(err),
+        };
+
+        let dir = format!("{}/", path.trim_end_matches('/'));
+        let mut lister = match self.lister(&dir).await {
+            Ok(lister) => lister,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Err(err),
+            Err(e) => return Err(e),
+        };
+        while let Some(entry) = lister.try_next().await? {
+            // Some services will return the dir itself.
+            if entry.path() != dir {
+                return Ok(true);
+            }
+        }
+
+        Err(err)
     }
 
-    /// Rename a file from `from` to `to`.
+    /// Rename a file or dir from `from` to `to`.
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
+    /// - `from` and `to` must be both files or both dirs.
     /// - `to` will be overwritten if it exists.
     /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    /// - Renaming dirs requires service supports renaming dir atomically, check
+    ///   `rename_dir` in [`Capability`]. Otherwise, an `Unsupported` error will occur,
+    ///   please use [`Operator::rename_all`] instead.
     ///
     /// # Examples
     ///
@@ -623,41 +1515,172 @@ impl Operator {
     /// # }
     /// ```
     pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        self.rename_with(from, to).await
+    }
+
+    /// Rename a file or dir from `from` to `to` with extra options.
+    ///
+    /// # Notes
+    ///
+    /// - Services that don't support rename will return `Unsupported`, use
+    ///   [`FutureRename::allow_fallback`] to rename files via copy and delete instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename_with("path/to/file", "path/to/file2")
+    ///     .allow_fallback(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_with(&self, from: &str, to: &str) -> FutureRename {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("Operator::move_")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from),
-            );
-        }
+        let fut = FutureRename(OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (OpRename::default(), to),
+            |inner, from, (args, to)| {
+                let fut = async move {
+                    let is_dir = from.ends_with('/');
+                    if !is_dir && to.ends_with('/') {
+                        return Err(
+                            Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                                .with_operation("Operator::move_")
+                                .with_context("service", inner.info().scheme())
+                                .with_context("to", to),
+                        );
+                    }
+                    if is_dir && !to.ends_with('/') {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "from path is a directory but to path is not",
+                        )
+                        .with_operation("Operator::move_")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from)
+                        .with_context("to", to));
+                    }
 
-        let to = normalize_path(to);
+                    if from == to {
+                        return Err(Error::new(
+                            ErrorKind::IsSameFile,
+                            "from and to paths are same",
+                        )
+                        .with_operation("Operator::move_")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from)
+                        .with_context("to", to));
+                    }
 
-        if !validate_path(&to, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "to path is a directory")
-                    .with_operation("Operator::move_")
-                    .with_context("service", self.info().scheme())
-                    .with_context("to", to),
-            );
-        }
+                    if is_dir && !inner.info().full_capability().rename_dir {
+                        return Err(Error::new(
+                            ErrorKind::Unsupported,
+                            "service doesn't support rename dir atomically, use rename_all instead",
+                        )
+                        .with_operation("Operator::move_")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from));
+                    }
+
+                    if args.allow_fallback() && !inner.info().full_capability().rename {
+                        return rename_via_copy_delete(inner, &from, &to)
+                            .await
+                            .map_err(|err| {
+                                err.with_operation("Operator::move_")
+                                    .with_context("from", from)
+                                    .with_context("to", to)
+                            });
+                    }
+
+                    inner.rename(&from, &to, args).await?;
+
+                    Ok(())
+                };
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
+
+    /// Rename the path and all nested dirs and files recursively from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - If `from` is a file, `rename_all` behaves the same as [`Operator::rename`].
+    /// - If `from` is a dir and service supports renaming dir atomically (like `fs`),
+    ///   the dir will be renamed by service directly.
+    /// - Otherwise, we will copy all entries via [`Operator::copy_all`] and then remove
+    ///   `from` via [`Operator::remove_all`].
+    /// - `from` will be treated as a dir if there are entries under it, even if the dir
+    ///   itself doesn't exist, e.g. implicit dirs on `s3`.
+    ///
+    /// ## Existing `to`
+    ///
+    /// Renaming a dir won't replace an existing `to` as a whole:
+    ///
+    /// - While falling back to copy and delete, entries of `from` are merged into `to`.
+    ///   Files with the same path will be overwritten, other entries under `to` are kept.
+    /// - While renaming by service directly, the behavior depends on the service, for
+    ///   example, `fs` will return an error if `to` is a non-empty dir.
+    ///
+    /// ## Partial failure
+    ///
+    /// While falling back to copy and delete, `rename_all` is not atomic:
+    ///
+    /// - If copy failed, `from` will be kept as is, but `to` may contain part of the files.
+    /// - If delete failed, all files have been copied to `to`, but `from` may still contain
+    ///   part of the files.
+    ///
+    /// It's safe to call `rename_all` again to resume.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename_all("path/to/dir/", "path/to/dir2/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_all(&self, from: &str, to: &str) -> Result<()> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if from == to {
+        if from.trim_end_matches('/') == to.trim_end_matches('/') {
             return Err(
                 Error::new(ErrorKind::IsSameFile, "from and to paths are same")
-                    .with_operation("Operator::move_")
+                    .with_operation("Operator::rename_all")
                     .with_context("service", self.info().scheme())
                     .with_context("from", from)
                     .with_context("to", to),
             );
         }
 
-        self.inner().rename(&from, &to, OpRename::new()).await?;
+        if !self.is_dir_for_all(&from).await? {
+            return self.rename(&from, &to).await;
+        }
+
+        let from = format!("{}/", from.trim_end_matches('/'));
+        let to = format!("{}/", to.trim_end_matches('/'));
 
-        Ok(())
+        if self.info().full_capability().rename_dir {
+            return self.rename(&from, &to).await;
+        }
+
+        self.copy_all(&from, &to).await?;
+        self.remove_all(&from).await
     }
 
     /// Write multiple bytes into path.
@@ -714,10 +1737,12 @@ impl Operator {
     pub fn writer_with(&self, path: &str) -> FutureWriter {
         let path = normalize_path(path);
 
+        let args = self.default_write_args(&path);
+
         let fut = FutureWriter(OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpWrite::default(),
+            args,
             |inner, path, args| {
                 let fut = async move {
                     if !validate_path(&path, EntryMode::FILE) {
@@ -766,10 +1791,12 @@ impl Operator {
         let path = normalize_path(path);
         let bs = bs.into();
 
+        let args = self.default_write_args(&path);
+
         let fut = FutureWrite(OperatorFuture::new(
             self.inner().clone(),
             path,
-            (OpWrite::default(), bs),
+            (args, bs),
             |inner, path, (args, mut bs)| {
                 let fut = async move {
                     if !validate_path(&path, EntryMode::FILE) {
@@ -782,7 +1809,30 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
-                    let (_, mut w) = inner.write(&path, args).await?;
+                    // Split input into parts so that they can be uploaded concurrently.
+                    let concurrent = args.concurrent();
+                    let args = if concurrent > 1
+                        && args.buffer().is_none()
+                        && inner.info().full_capability().write_can_multi
+                    {
+                        let part_size = (bs.len() + concurrent - 1) / concurrent;
+                        args.with_buffer(cmp::max(part_size, 1))
+                    } else {
+                        args
+                    };
+
+                    let progress = args.progress().cloned();
+                    let (args, compression) = prepare_compress(&inner.info(), args)?;
+                    let (args, transform) = prepare_transform(args);
+                    let (_, w) = inner.write(&path, args).await?;
+                    // Content will be compressed before transforming, so that it could be
+                    // encrypted after compressing.
+                    let w = new_transform_writer(w, transform);
+                    let mut w = new_compress_writer(w, compression)?;
+                    if let Some(progress) = progress {
+                        let total = Some(bs.len() as u64);
+                        w = Box::new(oio::ProgressWriter::new(w, progress, total));
+                    }
                     while bs.remaining() > 0 {
                         let n = w.write(&bs).await?;
                         bs.advance(n);
@@ -790,7 +1840,7 @@ impl Operator {
 
                     w.close().await?;
 
-                    Ok(())
+                    Ok(w.metadata())
                 };
                 Box::pin(fut)
             },
@@ -798,19 +1848,69 @@ impl Operator {
         fut
     }
 
-    /// Delete the given path.
+    /// write_many will write many objects via the given stream and report the
+    /// result of every path.
+    ///
+    /// Objects will be written concurrently with at most [`Operator::limit`]
+    /// in flight, which is useful to flush a batch of small objects.
     ///
     /// # Notes
     ///
-    /// - Deleting a file that does not exist won't return errors.
+    /// write_many won't abort while meeting errors, users can retry the failed
+    /// paths only. The results are returned in the order of completion instead
+    /// of the input.
     ///
     /// # Examples
     ///
     /// ```
     /// # use anyhow::Result;
-    /// # use futures::io;
     /// # use opendal::Operator;
-    /// # #[tokio::main]
+    /// use bytes::Bytes;
+    /// use futures::stream;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let stream = stream::iter(vec![
+    ///     ("abc".to_string(), Bytes::from("Hello")),
+    ///     ("def".to_string(), Bytes::from("World")),
+    /// ]);
+    /// let failed: Vec<_> = op
+    ///     .write_many(stream)
+    ///     .await
+    ///     .into_iter()
+    ///     .filter(|(_, res)| res.is_err())
+    ///     .map(|(path, _)| path)
+    ///     .collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_many(
+        &self,
+        input: impl Stream<Item = (String, Bytes)>,
+    ) -> Vec<(String, Result<Metadata>)> {
+        input
+            .map(|(path, bs)| async move {
+                let result = self.write_with(&path, bs).await;
+                (path, result)
+            })
+            .buffer_unordered(cmp::max(1, self.limit))
+            .collect()
+            .await
+    }
+
+    /// Delete the given path.
+    ///
+    /// # Notes
+    ///
+    /// - Deleting a file that does not exist won't return errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::io;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
     /// op.delete("test").await?;
     /// # Ok(())
@@ -859,12 +1959,85 @@ impl Operator {
         fut
     }
 
+    /// Restore an archived file so that it can be read.
+    ///
+    /// # Notes
+    ///
+    /// - Restore is an async operation on service side, this function will return
+    ///   as soon as the restore request has been accepted. Users can check the
+    ///   progress by [`Metadata::restore_status`] returned by `stat`.
+    /// - Services without archival tiers will return `Unsupported` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore(&self, path: &str) -> Result<()> {
+        self.restore_with(path).await
+    }
+
+    /// Restore an archived file with extra options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore_with("path/to/file")
+    ///     .days(7)
+    ///     .tier("Bulk")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore_with(&self, path: &str) -> FutureRestore {
+        let path = normalize_path(path);
+
+        let fut = FutureRestore(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpRestore::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::FILE) {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "restore path is a directory",
+                        )
+                        .with_operation("Operator::restore")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+
+                    let _ = inner.restore(&path, args).await?;
+                    Ok(())
+                };
+
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
+
     ///
     /// # Notes
     ///
     /// If underlying services support delete in batch, we will use batch
     /// delete instead.
     ///
+    /// remove will return the first error it meets and abort. Use
+    /// [`Operator::remove_via_report`] if you want to know the result of every path.
+    ///
     /// # Examples
     ///
     /// ```
@@ -894,6 +2067,10 @@ impl Operator {
     /// If underlying services support delete in batch, we will use batch
     /// delete instead.
     ///
+    /// remove_via will return the first error it meets and abort, paths that
+    /// haven't been deleted yet will be left as is. Use [`Operator::remove_via_report`]
+    /// if you want to know the result of every path.
+    ///
     /// # Examples
     ///
     /// ```
@@ -942,6 +2119,250 @@ impl Operator {
         Ok(())
     }
 
+    /// Delete exactly the given entries, which are usually returned by a [`Lister`].
+    ///
+    /// Unlike [`Operator::remove_via`], the version of every entry will be kept, so
+    /// entries returned by `lister_with(path).versions(true)` will delete the specific
+    /// versions instead of the latest one.
+    ///
+    /// # Notes
+    ///
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead.
+    ///
+    /// delete_entries will return the first error it meets and abort, entries that
+    /// haven't been deleted yet will be left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use futures::stream;
+    /// use futures::TryStreamExt;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let entries: Vec<_> = op
+    ///     .lister_with("path/to/dir/")
+    ///     .versions(true)
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
+    /// op.delete_entries(stream::iter(entries)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_entries(&self, input: impl Stream<Item = Entry> + Unpin) -> Result<()> {
+        let input = input.map(|entry| {
+            let mut op = OpDelete::default();
+            let meta = entry.metadata();
+            // Entries listed without versions don't carry version at all.
+            if meta.contains_metakey(Metakey::Version) {
+                if let Some(version) = meta.version() {
+                    op = op.with_version(version);
+                }
+            }
+            (entry.path().to_string(), op)
+        });
+
+        if self.info().full_capability().batch {
+            let mut input = input
+                .map(|(path, op)| (path, op.into()))
+                .chunks(self.limit());
+
+            while let Some(batches) = input.next().await {
+                let results = self
+                    .inner()
+                    .batch(OpBatch::new(batches))
+                    .await?
+                    .into_results();
+
+                // TODO: return error here directly seems not a good idea?
+                for (_, result) in results {
+                    let _ = result?;
+                }
+            }
+        } else {
+            input
+                .map(Ok)
+                .try_for_each_concurrent(self.limit, |(path, op)| async move {
+                    let _ = self.inner().delete(&path, op).await?;
+                    Ok::<(), Error>(())
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// remove_via_report will remove files via the given stream and report
+    /// the result of every path.
+    ///
+    /// Unlike [`Operator::remove_via`], remove_via_report won't abort while
+    /// meeting errors. Users can retry the failed paths only.
+    ///
+    /// # Notes
+    ///
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead. If the whole batch request failed, we will fallback
+    /// to delete paths in this batch one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::io;
+    /// # use opendal::Operator;
+    /// use futures::stream;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let stream = stream::iter(vec!["abc".to_string(), "def".to_string()]);
+    /// let failed: Vec<_> = op
+    ///     .remove_via_report(stream)
+    ///     .await
+    ///     .into_iter()
+    ///     .filter(|(_, res)| res.is_err())
+    ///     .map(|(path, _)| path)
+    ///     .collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_via_report(
+        &self,
+        input: impl Stream<Item = String> + Unpin,
+    ) -> Vec<(String, Result<()>)> {
+        let input = input.map(|v| normalize_path(&v));
+
+        if !self.info().full_capability().batch {
+            return self.remove_each(input).await;
+        }
+
+        let mut reports = Vec::new();
+        let mut input = input.chunks(self.limit());
+
+        while let Some(paths) = input.next().await {
+            let batches = paths
+                .iter()
+                .map(|v| (v.clone(), OpDelete::default().into()))
+                .collect();
+
+            match self.inner().batch(OpBatch::new(batches)).await {
+                Ok(rp) => reports.extend(
+                    rp.into_results()
+                        .into_iter()
+                        .map(|(path, result)| (path, result.map(|_| ()))),
+                ),
+                Err(_) => reports.extend(self.remove_each(stream::iter(paths)).await),
+            }
+        }
+
+        reports
+    }
+
+    /// Delete paths one by one concurrently and collect their results.
+    async fn remove_each(&self, input: impl Stream<Item = String>) -> Vec<(String, Result<()>)> {
+        input
+            .map(|path| async move {
+                let result = self
+                    .inner()
+                    .delete(&path, OpDelete::default())
+                    .await
+                    .map(|_| ());
+                (path, result)
+            })
+            .buffer_unordered(cmp::max(1, self.limit))
+            .collect()
+            .await
+    }
+
+    /// Execute operations in batch and report the result of every operation.
+    ///
+    /// Operations will be split into chunks by [`Operator::limit`] and the
+    /// service's `batch_max_operations`. If one whole batch request failed,
+    /// the error will be returned directly; otherwise every operation has its
+    /// own result, users can retry the failed ones only.
+    ///
+    /// # Notes
+    ///
+    /// Services that support batch and their max operations per batch:
+    ///
+    /// - `s3`: 1000, can be changed via `batch_max_operations`
+    /// - `oss`: 1000, can be changed via `batch_max_operations`
+    /// - `azblob`: 256, can be changed via `batch_max_operations`
+    /// - `gcs`: 100
+    /// - `dropbox`: 1000
+    ///
+    /// For services that don't support batch, operations will be executed one by
+    /// one concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::raw::BatchOperation;
+    /// use opendal::raw::OpDelete;
+    /// use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let ops = vec![
+    ///     ("abc".to_string(), BatchOperation::Delete(OpDelete::new())),
+    ///     ("def".to_string(), BatchOperation::Delete(OpDelete::new())),
+    /// ];
+    /// for (path, result) in op.batch(ops).await? {
+    ///     if let Err(err) = result {
+    ///         println!("{path} failed: {err}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn batch(
+        &self,
+        ops: Vec<(String, BatchOperation)>,
+    ) -> Result<Vec<(String, Result<BatchedReply>)>> {
+        let ops: Vec<_> = ops
+            .into_iter()
+            .map(|(path, op)| (normalize_path(&path), op))
+            .collect();
+
+        let cap = self.info().full_capability();
+        if !cap.batch {
+            let results = stream::iter(ops)
+                .map(|(path, op)| async move {
+                    let result = match op {
+                        BatchOperation::Delete(op) => self
+                            .inner()
+                            .delete(&path, op)
+                            .await
+                            .map(BatchedReply::Delete),
+                    };
+                    (path, result)
+                })
+                .buffered(cmp::max(1, self.limit))
+                .collect()
+                .await;
+            return Ok(results);
+        }
+
+        let size = cap
+            .batch_max_operations
+            .map_or(self.limit(), |v| v.min(self.limit()))
+            .max(1);
+
+        let mut results = Vec::with_capacity(ops.len());
+        let mut ops = ops.into_iter().peekable();
+        while ops.peek().is_some() {
+            let batch: Vec<_> = ops.by_ref().take(size).collect();
+            let rp = self.inner().batch(OpBatch::new(batch)).await?;
+            results.extend(rp.into_results());
+        }
+
+        Ok(results)
+    }
+
     /// Remove the path and all nested dirs and files recursively.
     ///
     /// # Notes
@@ -949,6 +2370,11 @@ impl Operator {
     /// If underlying services support delete in batch, we will use batch
     /// delete instead.
     ///
+    /// Entries are removed based on the listing result only, nested files and
+    /// dirs will never be stated. If path ends with `/`, it will be listed
+    /// directly without stat, so removing a dir only costs one recursive
+    /// listing plus the delete requests.
+    ///
     /// # Examples
     ///
     /// ```
@@ -958,28 +2384,102 @@ impl Operator {
     /// #
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.remove_all("path/to/dir").await?;
+    /// op.remove_all("path/to/dir/").await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn remove_all(&self, path: &str) -> Result<()> {
-        let meta = match self.stat(path).await {
-            // If object exists.
-            Ok(metadata) => metadata,
+        self.remove_all_with(path).await?;
 
-            // If object not found, return success.
-            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Ok(())
+    }
+
+    /// Remove the path and all nested dirs and files recursively with extra options.
+    ///
+    /// Returns the paths that would be removed if `dry_run` is enabled, otherwise
+    /// returns an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = op.remove_all_with("path/to/dir/").dry_run(true).await?;
+    /// for path in paths {
+    ///     println!("{path} will be removed");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_all_with(&self, path: &str) -> FutureRemoveAll {
+        let path = normalize_path(path);
+
+        let fut = FutureRemoveAll(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpRemoveAll::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    let op = Operator::from_inner(inner);
+                    op.remove_all_inner(&path, args.dry_run()).await
+                };
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
+
+    /// Remove the path recursively, or only collect the paths to remove if `dry_run` is set.
+    async fn remove_all_inner(&self, path: &str, dry_run: bool) -> Result<Vec<String>> {
+        // Path ends with `/` must be a dir, there is no need to stat it.
+        if !path.ends_with('/') {
+            let meta = match self.stat(path).await {
+                // If object exists.
+                Ok(metadata) => metadata,
+
+                // If object not found, return success.
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+
+                // Pass on any other error.
+                Err(e) => return Err(e),
+            };
+
+            if meta.mode() != EntryMode::DIR {
+                if dry_run {
+                    return Ok(vec![path.to_string()]);
+                }
+                self.delete(path).await?;
+                return Ok(vec![]);
+            }
+        }
+
+        let obs = match self
+            .lister_with(path)
+            .recursive(true)
+            .metakey(Metakey::Mode)
+            .await
+        {
+            Ok(obs) => obs,
+
+            // If dir not found, return success.
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
 
             // Pass on any other error.
             Err(e) => return Err(e),
         };
 
-        if meta.mode() != EntryMode::DIR {
-            return self.delete(path).await;
+        if dry_run {
+            let mut paths: Vec<String> = obs.map_ok(|v| v.path().to_string()).try_collect().await?;
+            if !paths.iter().any(|v| v == path) {
+                paths.push(path.to_string());
+            }
+            return Ok(paths);
         }
 
-        let obs = self.lister_with(path).recursive(true).await?;
-
         if self.info().full_capability().batch {
             let mut obs = obs.try_chunks(self.limit());
 
@@ -1002,14 +2502,45 @@ impl Operator {
                 }
             }
         } else {
-            obs.try_for_each(|v| async move { self.delete(v.path()).await })
-                .await?;
+            // Files can be removed concurrently, but dirs must be removed after
+            // all entries inside them, so we collect dirs and remove them later.
+            let mut dirs = vec![];
+            obs.try_filter_map(|v| {
+                let v = if v.metadata().mode().is_dir() {
+                    dirs.push(v.path().to_string());
+                    None
+                } else {
+                    Some(v)
+                };
+                future::ready(Ok(v))
+            })
+            .try_for_each_concurrent(self.limit, |v| async move { self.delete(v.path()).await })
+            .await?;
+
+            // Remove dirs level by level from the deepest one, so that nested dirs
+            // are always removed before their parents.
+            dirs.sort_by_key(|v| cmp::Reverse(v.matches('/').count()));
+            let mut dirs = dirs.as_slice();
+            while let Some(dir) = dirs.first() {
+                let depth = dir.matches('/').count();
+                let idx = dirs
+                    .iter()
+                    .position(|v| v.matches('/').count() != depth)
+                    .unwrap_or(dirs.len());
+                let (level, rest) = dirs.split_at(idx);
+
+                stream::iter(level)
+                    .map(Ok)
+                    .try_for_each_concurrent(self.limit, |v| async move { self.delete(v).await })
+                    .await?;
+                dirs = rest;
+            }
         }
 
         // Remove the directory itself.
         self.delete(path).await?;
 
-        Ok(())
+        Ok(vec![])
     }
 
     /// List entries within a given directory.
@@ -1149,7 +2680,7 @@ impl Operator {
         let fut = FutureList(OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_metakey(self.default_metakey),
             |inner, path, args| {
                 let fut = async move {
                     let lister = Lister::create(inner, &path, args).await?;
@@ -1162,40 +2693,91 @@ impl Operator {
         fut
     }
 
-    /// List entries within a given directory as a stream.
-    ///
-    /// This function will create a new handle to list entries.
+    /// List entries within a given directory into a map indexed by path.
     ///
-    /// An error will be returned if given path doesn't end with `/`.
+    /// The listing shares the same [`Lister`] as [`Operator::list`] and is
+    /// collected straight into the returned map, so only the final map is
+    /// kept in memory. If the same path is returned more than once, the
+    /// first one wins.
     ///
     /// # Notes
     ///
-    /// ## Listing recursively
-    ///
-    /// This function only read the children of the given directory. To read
-    /// all entries recursively, use [`Operator::lister_with`] and `recursive(true)`
-    /// instead.
-    ///
     /// ## Metadata
     ///
-    /// The only metadata that is guaranteed to be available is the `Mode`.
-    /// For fetching more metadata, please use [`Operator::lister_with`] and `metakey`.
+    /// The metadata is fetched with the operator's default metakey, set via
+    /// [`Operator::with_default_metakey`].
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use anyhow::Result;
-    /// # use futures::io;
-    /// use futures::TryStreamExt;
-    /// use opendal::EntryMode;
     /// use opendal::Metakey;
     /// use opendal::Operator;
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut ds = op.lister("path/to/dir/").await?;
-    /// while let Some(mut de) = ds.try_next().await? {
-    ///     match de.metadata().mode() {
-    ///         EntryMode::FILE => {
+    /// let entries = op
+    ///     .with_default_metakey(Metakey::Mode | Metakey::ContentLength)
+    ///     .list_map("path/to/dir/")
+    ///     .await?;
+    /// if let Some(meta) = entries.get("path/to/dir/file") {
+    ///     println!("file size: {}", meta.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_map(&self, path: &str) -> Result<HashMap<String, Metadata>> {
+        let path = normalize_path(path);
+
+        let lister = Lister::create(
+            self.inner().clone(),
+            &path,
+            OpList::default().with_metakey(self.default_metakey),
+        )
+        .await?;
+
+        lister
+            .try_fold(HashMap::new(), |mut entries, entry| {
+                let (path, meta) = entry.into_parts();
+                entries.entry(path).or_insert(meta);
+                future::ready(Ok(entries))
+            })
+            .await
+    }
+
+    /// List entries within a given directory as a stream.
+    ///
+    /// This function will create a new handle to list entries.
+    ///
+    /// An error will be returned if given path doesn't end with `/`.
+    ///
+    /// # Notes
+    ///
+    /// ## Listing recursively
+    ///
+    /// This function only read the children of the given directory. To read
+    /// all entries recursively, use [`Operator::lister_with`] and `recursive(true)`
+    /// instead.
+    ///
+    /// ## Metadata
+    ///
+    /// The only metadata that is guaranteed to be available is the `Mode`.
+    /// For fetching more metadata, please use [`Operator::lister_with`] and `metakey`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # use futures::io;
+    /// use futures::TryStreamExt;
+    /// use opendal::EntryMode;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op.lister("path/to/dir/").await?;
+    /// while let Some(mut de) = ds.try_next().await? {
+    ///     match de.metadata().mode() {
+    ///         EntryMode::FILE => {
     ///             println!("Handling file")
     ///         }
     ///         EntryMode::DIR => {
@@ -1317,7 +2899,7 @@ impl Operator {
         let fut = FutureLister(OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_metakey(self.default_metakey),
             |inner, path, args| {
                 let fut = async move { Lister::create(inner, &path, args).await };
                 Box::pin(fut)
@@ -1325,10 +2907,136 @@ impl Operator {
         ));
         fut
     }
+
+    /// Walk all files under given path recursively as a stream.
+    ///
+    /// Walker only returns files, dirs will be skipped no matter services return them
+    /// or not.
+    ///
+    /// [`ErrorKind::NotADirectory`] will be returned if given path doesn't end with `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op.walk("path/to/dir/").await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!("Handling file {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn walk(&self, path: &str) -> Result<Walker> {
+        self.walk_with(path).await
+    }
+
+    /// Walk all files under given path recursively as a stream with options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op
+    ///     .walk_with("path/to/dir/")
+    ///     .metakey(Metakey::ContentLength | Metakey::LastModified)
+    ///     .await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!(
+    ///         "Handling file {} with size {}",
+    ///         entry.path(),
+    ///         entry.metadata().content_length()
+    ///     )
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk_with(&self, path: &str) -> FutureWalk {
+        let path = normalize_path(path);
+
+        let fut = FutureWalk(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpList::default().with_metakey(self.default_metakey),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::DIR) {
+                        return Err(Error::new(
+                            ErrorKind::NotADirectory,
+                            "the path trying to walk should end with `/`",
+                        )
+                        .with_operation("walk")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+
+                    Walker::create(inner, &path, args).await
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
 }
 
 /// Operator presign API.
 impl Operator {
+    /// Presign an arbitrary operation described by [`OpPresign`].
+    ///
+    /// This is the generic entry point of all presign APIs, `presign_xxx`
+    /// are shortcuts built on top of it. Users can use it to presign
+    /// operations that don't have a dedicated helper yet.
+    ///
+    /// # Notes
+    ///
+    /// [`PresignOperation::Post`] can't be represented by a [`PresignedRequest`],
+    /// please use [`Operator::presign_post`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::raw::OpDelete;
+    /// use opendal::raw::OpPresign;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign(
+    ///             "test.txt",
+    ///             OpPresign::new(OpDelete::new(), Duration::from_secs(3600)),
+    ///         )
+    ///         .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn presign(&self, path: &str, op: OpPresign) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        if let PresignOperation::Post(_) = op.operation() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "presign post can't be represented as a request, use presign_post instead",
+            )
+            .with_operation("Operator::presign")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
     /// Presign an operation for stat(head).
     ///
     /// # Example
@@ -1351,12 +3059,8 @@ impl Operator {
     /// # }
     /// ```
     pub async fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
-        let path = normalize_path(path);
-
-        let op = OpPresign::new(OpStat::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
+        self.presign(path, OpPresign::new(OpStat::new(), expire))
+            .await
     }
 
     /// Presign an operation for stat(head).
@@ -1421,12 +3125,8 @@ impl Operator {
     /// curl "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" -O /tmp/test.txt
     /// ```
     pub async fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
-        let path = normalize_path(path);
-
-        let op = OpPresign::new(OpRead::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
+        self.presign(path, OpPresign::new(OpRead::new(), expire))
+            .await
     }
 
     /// Presign an operation for read option described in OpenDAL [RFC-1735][`crate::docs::rfcs::rfc_1735_operation_extension`].
@@ -1456,10 +3156,16 @@ impl Operator {
         let fut = FuturePresignRead(OperatorFuture::new(
             self.inner().clone(),
             path,
-            (OpRead::default(), expire),
-            |inner, path, (args, dur)| {
+            (OpRead::default(), expire, vec![], vec![]),
+            |inner, path, (args, dur, headers, query)| {
                 let fut = async move {
-                    let op = OpPresign::new(args, dur);
+                    let mut op = OpPresign::new(args, dur);
+                    for (k, v) in headers {
+                        op = op.with_header(&k, &v);
+                    }
+                    for (k, v) in query {
+                        op = op.with_query(&k, &v);
+                    }
                     let rp = inner.presign(&path, op).await?;
                     Ok(rp.into_presigned_request())
                 };
@@ -1529,7 +3235,84 @@ impl Operator {
         let fut = FuturePresignWrite(OperatorFuture::new(
             self.inner().clone(),
             path,
-            (OpWrite::default(), expire),
+            (OpWrite::default(), expire, vec![], vec![]),
+            |inner, path, (args, dur, headers, query)| {
+                let fut = async move {
+                    let mut op = OpPresign::new(args, dur);
+                    for (k, v) in headers {
+                        op = op.with_header(&k, &v);
+                    }
+                    for (k, v) in query {
+                        op = op.with_query(&k, &v);
+                    }
+                    let rp = inner.presign(&path, op).await?;
+                    Ok(rp.into_presigned_request())
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
+
+    /// Presign an operation for delete.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_delete("test.txt", Duration::from_secs(3600)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `DELETE`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
+    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    ///
+    /// We can delete this file via `curl` or other tools without credential:
+    ///
+    /// ```shell
+    /// curl -X DELETE "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>"
+    /// ```
+    pub async fn presign_delete(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign_delete_with(path, expire).await
+    }
+
+    /// Presign an operation for delete with option described in OpenDAL [RFC-0661][`crate::docs::rfcs::rfc_0661_path_in_accessor`]
+    ///
+    /// You can pass `OpDelete` to this method to specify the version to delete.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_delete_with("test", Duration::from_secs(3600))
+    ///                        .version("example_version").await?;
+    ///     let req = http::Request::builder()
+    ///         .method(signed_req.method())
+    ///         .uri(signed_req.uri())
+    ///         .body(())?;
+    ///
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_delete_with(&self, path: &str, expire: Duration) -> FuturePresignDelete {
+        let path = normalize_path(path);
+
+        let fut = FuturePresignDelete(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpDelete::default(), expire),
             |inner, path, (args, dur)| {
                 let fut = async move {
                     let op = OpPresign::new(args, dur);
@@ -1541,4 +3324,475 @@ impl Operator {
         ));
         fut
     }
+
+    /// Presign an operation for uploading a part of an initiated multipart upload.
+    ///
+    /// `upload_id` is the id returned by service while initiating the multipart upload,
+    /// and `part_number` will be sent to service as is. For s3, `part_number` must be
+    /// between `[1, 10000]`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_upload_part("test.txt", "example_upload_id", 1, Duration::from_secs(3600))
+    ///         .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `PUT`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?partNumber=1&uploadId=example_upload_id&X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
+    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    ///
+    /// We can upload this part via `curl` or other tools without credential:
+    ///
+    /// ```shell
+    /// curl -X PUT "https://s3.amazonaws.com/examplebucket/test.txt?partNumber=1&uploadId=example_upload_id&X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" --data-binary @part-1
+    /// ```
+    ///
+    /// The `ETag` returned in response is required to complete the multipart upload.
+    pub async fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.presign(
+            path,
+            OpPresign::new(OpUploadPart::new(upload_id, part_number), expire),
+        )
+        .await
+    }
+
+    /// Presign a POST policy for browser based form upload.
+    ///
+    /// Unlike presigned write which is a `PUT` request, the returned form
+    /// can be used by HTML forms directly. Conditions like
+    /// [`PostPolicyCondition::ContentLengthRange`] will be enforced by
+    /// services while uploading.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::raw::PostPolicyCondition;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_post = op
+    ///         .presign_post(
+    ///             "test.txt",
+    ///             Duration::from_secs(3600),
+    ///             vec![PostPolicyCondition::ContentLengthRange(0, 1024 * 1024)],
+    ///         )
+    ///         .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_post.uri()`: `https://s3.amazonaws.com/examplebucket/`
+    /// - `signed_post.fields()`: `[("key", "test.txt"), ("x-amz-algorithm", "AWS4-HMAC-SHA256"), ..., ("policy", "<policy>"), ("x-amz-signature", "<signature-value>")]`
+    ///
+    /// We can upload this file via `curl` or HTML form without credential:
+    ///
+    /// ```shell
+    /// curl -X POST "https://s3.amazonaws.com/examplebucket/" -F "key=test.txt" ... -F "file=@test.txt"
+    /// ```
+    pub async fn presign_post(
+        &self,
+        path: &str,
+        expire: Duration,
+        conditions: Vec<PostPolicyCondition>,
+    ) -> Result<PresignedPost> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpPresignPost::new(conditions), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_post())
+    }
+}
+
+/// Objects not larger than this will be read in one request with auto concurrent.
+const AUTO_CONCURRENT_THRESHOLD: u64 = 8 * 1024 * 1024;
+/// The max concurrency picked by auto concurrent.
+const AUTO_CONCURRENT_MAX: usize = 16;
+/// The chunk size picked by auto concurrent will be in `[MIN, MAX]`.
+const AUTO_CONCURRENT_MIN_CHUNK: u64 = 2 * 1024 * 1024;
+const AUTO_CONCURRENT_MAX_CHUNK: u64 = 16 * 1024 * 1024;
+/// The default memory budget of chunks in flight for auto concurrent.
+const AUTO_CONCURRENT_MEMORY_BUDGET: usize = 64 * 1024 * 1024;
+
+/// Pick `(chunk, concurrent)` for reading `size` bytes with auto concurrent.
+///
+/// Returns `None` if the content should be read in one request.
+fn auto_concurrent_plan(
+    size: u64,
+    chunk: Option<usize>,
+    budget: Option<usize>,
+) -> Option<(usize, usize)> {
+    if size <= AUTO_CONCURRENT_THRESHOLD {
+        return None;
+    }
+
+    let budget = budget.unwrap_or(AUTO_CONCURRENT_MEMORY_BUDGET);
+    let chunk = match chunk {
+        Some(v) => v,
+        None => cmp::min(
+            (size / AUTO_CONCURRENT_MAX as u64)
+                .clamp(AUTO_CONCURRENT_MIN_CHUNK, AUTO_CONCURRENT_MAX_CHUNK) as usize,
+            budget,
+        ),
+    };
+    if chunk == 0 {
+        return None;
+    }
+
+    let chunks = ((size + chunk as u64 - 1) / chunk as u64) as usize;
+    let concurrent = (budget / chunk).min(AUTO_CONCURRENT_MAX).min(chunks);
+    if concurrent <= 1 {
+        return None;
+    }
+    Some((chunk, concurrent))
+}
+
+/// Evaluate the conditions of `args` against the metadata of the file like services do.
+///
+/// Conditions that can't be evaluated since the metadata is missing will be treated
+/// as passed.
+fn check_read_conditions(args: &OpRead, meta: &Metadata) -> Result<()> {
+    let etag = meta.etag();
+    let last_modified = meta.last_modified();
+
+    let matched = match (args.if_match(), etag) {
+        (Some("*"), _) => true,
+        (Some(v), Some(etag)) => v == etag,
+        _ => true,
+    } && match (args.if_none_match(), etag) {
+        (Some("*"), _) => false,
+        (Some(v), Some(etag)) => v != etag,
+        _ => true,
+    } && match (args.if_modified_since(), last_modified) {
+        (Some(t), Some(lm)) => lm > t,
+        _ => true,
+    } && match (args.if_unmodified_since(), last_modified) {
+        (Some(t), Some(lm)) => lm <= t,
+        _ => true,
+    };
+
+    if matched {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorKind::ConditionNotMatch,
+            "the condition of read doesn't match",
+        ))
+    }
+}
+
+/// Read the remaining range again if services returned less data than `args.range()`.
+///
+/// The range must have been resolved against the content length. The remaining range
+/// will be read as long as it makes progress, with `If-Match` set to `etag` if supported
+/// so that content of different versions won't be mixed up. If `verify_length` is enabled
+/// and the length of `buf` still doesn't match, an `Unexpected` error will be returned.
+async fn resume_short_read(
+    inner: &FusedAccessor,
+    path: &str,
+    mut args: OpRead,
+    etag: Option<&str>,
+    buf: &mut Vec<u8>,
+    verify_length: bool,
+) -> Result<()> {
+    let range = args.range();
+    let (Some(offset), Some(size)) = (range.offset(), range.size()) else {
+        return Ok(());
+    };
+    if let Some(etag) = etag {
+        if args.if_match().is_none() && inner.info().full_capability().read_with_if_match {
+            args = args.with_if_match(etag);
+        }
+    }
+
+    while (buf.len() as u64) < size && inner.info().full_capability().read_with_range {
+        let read = buf.len() as u64;
+        let args = args
+            .clone()
+            .with_range(BytesRange::new(Some(offset + read), Some(size - read)));
+        let (_, mut r) = inner.read(path, args).await?;
+        if r.read_to_end(buf).await? == 0 {
+            break;
+        }
+    }
+
+    let actual = buf.len() as u64;
+    if verify_length && actual != size {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            &format!("read got unexpected length, expect: {size}, actual: {actual}"),
+        )
+        .with_operation("read")
+        .with_context("service", inner.info().scheme())
+        .with_context("path", path)
+        .with_context("range", range.to_string()));
+    }
+    Ok(())
+}
+
+/// Check if there is any entry under the dir path via list.
+///
+/// The given `err` will be returned if no entry is found.
+async fn infer_dir_via_list(inner: FusedAccessor, path: &str, err: Error) -> Result<Metadata> {
+    let (_, mut l) = match inner.list(path, OpList::new().with_limit(1)).await {
+        Ok(v) => v,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Err(err),
+        Err(e) => return Err(e),
+    };
+
+    match oio::ListExt::next(&mut l).await {
+        Ok(Some(_)) => Ok(Metadata::new(EntryMode::DIR)),
+        Ok(None) => Err(err),
+        Err(e) if e.kind() == ErrorKind::NotFound => Err(err),
+        Err(e) => Err(e),
+    }
+}
+
+/// Rename a file by copying `from` into `to` and then deleting `from`.
+///
+/// This is the fallback of `rename` for services that don't support rename natively.
+async fn rename_via_copy_delete(inner: FusedAccessor, from: &str, to: &str) -> Result<()> {
+    let cap = inner.info().full_capability();
+    if !cap.copy || !cap.delete {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "rename fallback requires service to support copy and delete",
+        ));
+    }
+
+    inner.copy(from, to, OpCopy::new()).await?;
+
+    if let Err(err) = inner.delete(from, OpDelete::new()).await {
+        // Clean up the copy so that we don't leave two files behind.
+        return match inner.delete(to, OpDelete::new()).await {
+            Ok(_) => Err(err),
+            Err(e) => Err(err.with_context("cleanup", e.to_string())),
+        };
+    }
+
+    Ok(())
+}
+
+/// Copy a file by streaming the content from `from` into `to`.
+///
+/// This is the fallback of `copy` for services that don't support copy natively.
+async fn copy_via_read_write(
+    inner: FusedAccessor,
+    from: &str,
+    to: &str,
+    args: OpCopy,
+) -> Result<()> {
+    if from == to {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "copy fallback doesn't support copying to the same path",
+        ));
+    }
+    if args.preserve_last_modified() {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "copy fallback doesn't support preserving last modified",
+        ));
+    }
+
+    let mut op = OpWrite::new();
+    match args.if_none_match() {
+        None => {}
+        Some("*") => op = op.with_if_not_exists(true),
+        Some(_) => {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "copy fallback only supports if none match with `*`",
+            ))
+        }
+    }
+
+    let content_type = match args.content_type() {
+        Some(v) => Some(v.to_string()),
+        None if args.metadata_directive() == Some(MetadataDirective::Replace) => None,
+        None => inner
+            .stat(from, OpStat::new())
+            .await?
+            .into_metadata()
+            .content_type()
+            .map(|v| v.to_string()),
+    };
+    if let Some(v) = content_type {
+        op = op.with_content_type(&v);
+    }
+
+    let r = Reader::create(inner.clone(), from, OpRead::new()).await?;
+    let mut w = Writer::create(inner, to, op).await?;
+    if let Err(err) = w.sink(r.into_bytes_stream()).await {
+        // Ignore the abort error since the copy has been failed already.
+        let _ = w.abort().await;
+        return Err(err);
+    }
+    w.close().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// RemoveAllService will list 100 dirs with 100 files inside each of them,
+    /// and count the requests sent to it.
+    #[derive(Debug, Default)]
+    struct RemoveAllService {
+        batch: bool,
+
+        stat: Arc<AtomicUsize>,
+        list: Arc<AtomicUsize>,
+        delete: Arc<AtomicUsize>,
+        batch_delete: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Accessor for RemoveAllService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Lister = oio::Lister;
+        type BlockingLister = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().stat = true;
+            am.full_capability_mut().list = true;
+            am.full_capability_mut().list_with_recursive = true;
+            am.full_capability_mut().delete = true;
+            am.full_capability_mut().batch = self.batch;
+            am.full_capability_mut().batch_delete = self.batch;
+
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            self.stat.fetch_add(1, Ordering::SeqCst);
+            Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+        }
+
+        async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Lister)> {
+            let l = MockPageList {
+                path: path.to_string(),
+                pages: self.list.clone(),
+            };
+
+            Ok((RpList::default(), Box::new(oio::PageLister::new(l))))
+        }
+
+        async fn delete(&self, _: &str, _: OpDelete) -> Result<RpDelete> {
+            self.delete.fetch_add(1, Ordering::SeqCst);
+            Ok(RpDelete::default())
+        }
+
+        async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+            self.batch_delete.fetch_add(1, Ordering::SeqCst);
+            let results = args
+                .into_operation()
+                .into_iter()
+                .map(|(path, _)| (path, Ok(RpDelete::default().into())))
+                .collect();
+            Ok(RpBatch::new(results))
+        }
+    }
+
+    /// MockPageList will return 1000 entries per page.
+    struct MockPageList {
+        path: String,
+        pages: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl oio::PageList for MockPageList {
+        async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+            self.pages.fetch_add(1, Ordering::SeqCst);
+
+            let start: usize = ctx.token.parse().unwrap_or_default();
+            for i in start..start + 10 {
+                let dir = format!("{}dir-{i:02}/", self.path);
+                for j in 0..100 {
+                    let path = format!("{dir}file-{j:02}");
+                    ctx.entries
+                        .push_back(oio::Entry::new(&path, Metadata::new(EntryMode::FILE)));
+                }
+                ctx.entries
+                    .push_back(oio::Entry::new(&dir, Metadata::new(EntryMode::DIR)));
+            }
+
+            ctx.token = (start + 10).to_string();
+            ctx.done = start + 10 >= 100;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remove_all_with_batch() -> Result<()> {
+        let acc = RemoveAllService {
+            batch: true,
+            ..Default::default()
+        };
+        let (stat, list, delete, batch_delete) = (
+            acc.stat.clone(),
+            acc.list.clone(),
+            acc.delete.clone(),
+            acc.batch_delete.clone(),
+        );
+        let op = Operator::from_inner(Arc::new(acc));
+
+        op.remove_all("path/to/dir/").await?;
+
+        // 10000 files and 100 dirs are listed in 10 pages and deleted in 11 batches,
+        // only the given dir itself is deleted alone.
+        assert_eq!(stat.load(Ordering::SeqCst), 0);
+        assert_eq!(list.load(Ordering::SeqCst), 10);
+        assert_eq!(delete.load(Ordering::SeqCst), 1);
+        assert_eq!(batch_delete.load(Ordering::SeqCst), 11);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_all_without_batch() -> Result<()> {
+        let acc = RemoveAllService::default();
+        let (stat, list, delete) = (acc.stat.clone(), acc.list.clone(), acc.delete.clone());
+        let op = Operator::from_inner(Arc::new(acc));
+
+        // Only the given path will be stated if it doesn't end with `/`.
+        op.remove_all("path/to/dir").await?;
+
+        assert_eq!(stat.load(Ordering::SeqCst), 1);
+        assert_eq!(list.load(Ordering::SeqCst), 10);
+        assert_eq!(delete.load(Ordering::SeqCst), 10101);
+
+        Ok(())
+    }
 }
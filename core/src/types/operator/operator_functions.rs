@@ -20,6 +20,7 @@
 //! By using functions, users can add more options for operation.
 
 use std::ops::RangeBounds;
+use std::time::SystemTime;
 
 use bytes::Bytes;
 use flagset::FlagSet;
@@ -117,6 +118,30 @@ impl FunctionWrite {
         self
     }
 
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_content_encoding(v), bs));
+        self
+    }
+
+    /// Set the content md5 of option
+    ///
+    /// The content md5 should be the base64 encoded md5 digest of the whole content,
+    /// it's the caller's responsibility to compute it correctly. Services that support
+    /// it will reject the corrupted upload with [`ErrorKind::ChecksumMismatch`].
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support content md5 will ignore this hint.
+    /// [`ErrorKind::Unsupported`] will be returned if the content is too large to be
+    /// uploaded in one request.
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_content_md5(v), bs));
+        self
+    }
+
     /// Set the content type of option
     pub fn cache_control(mut self, v: &str) -> Self {
         self.0 = self
@@ -125,6 +150,56 @@ impl FunctionWrite {
         self
     }
 
+    /// Set the storage class of option
+    ///
+    /// The storage class will be passed to services as is, for example,
+    /// `STANDARD_IA` for s3 and `NEARLINE` for gcs.
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support storage class will ignore this hint.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_storage_class(v), bs));
+        self
+    }
+
+    /// Set the expires of option
+    ///
+    /// The object will be sent with `Expires` header set to the given time, and
+    /// it could be read back via [`Metadata::expires`].
+    ///
+    /// ## NOTE
+    ///
+    /// - `Expires` is an HTTP caching header that tells clients when the cached content
+    ///   becomes stale. It's NOT a TTL: services like `s3` won't delete the object or
+    ///   reject reads after that time, please use lifecycle rules of the service instead.
+    /// - HTTP date only has second precision, so the given time will be truncated to seconds.
+    /// - Service will return `Unsupported` if it doesn't support expires, unless
+    ///   `allow_unsupported` is set.
+    pub fn expires(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_expires(v.into()), bs));
+        self
+    }
+
+    /// Set the allow_unsupported of option
+    ///
+    /// If set, the following options will be ignored instead of returning
+    /// `Unsupported` error if the service doesn't support them:
+    ///
+    /// - [`expires`](Self::expires)
+    ///
+    /// Other options are not affected by this flag.
+    pub fn allow_unsupported(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_allow_unsupported(v), bs));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
@@ -179,12 +254,79 @@ impl FunctionWriter {
         self
     }
 
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_encoding(v));
+        self
+    }
+
+    /// Set the content md5 of option
+    ///
+    /// The content md5 should be the base64 encoded md5 digest of the whole content,
+    /// it's the caller's responsibility to compute it correctly. Services that support
+    /// it will reject the corrupted upload with [`ErrorKind::ChecksumMismatch`].
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support content md5 will ignore this hint.
+    /// Content md5 only takes effect if the content is uploaded in one request,
+    /// [`ErrorKind::Unsupported`] will be returned if the content has to be uploaded
+    /// in multipart or the upload is resumed.
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_md5(v));
+        self
+    }
+
     /// Set the content type of option
     pub fn cache_control(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_cache_control(v));
         self
     }
 
+    /// Set the storage class of option
+    ///
+    /// The storage class will be passed to services as is, for example,
+    /// `STANDARD_IA` for s3 and `NEARLINE` for gcs.
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support storage class will ignore this hint.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_storage_class(v));
+        self
+    }
+
+    /// Set the expires of option
+    ///
+    /// The object will be sent with `Expires` header set to the given time, and
+    /// it could be read back via [`Metadata::expires`].
+    ///
+    /// ## NOTE
+    ///
+    /// - `Expires` is an HTTP caching header that tells clients when the cached content
+    ///   becomes stale. It's NOT a TTL: services like `s3` won't delete the object or
+    ///   reject reads after that time, please use lifecycle rules of the service instead.
+    /// - HTTP date only has second precision, so the given time will be truncated to seconds.
+    /// - Service will return `Unsupported` if it doesn't support expires, unless
+    ///   `allow_unsupported` is set.
+    pub fn expires(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_expires(v.into()));
+        self
+    }
+
+    /// Set the allow_unsupported of option
+    ///
+    /// If set, the following options will be ignored instead of returning
+    /// `Unsupported` error if the service doesn't support them:
+    ///
+    /// - [`expires`](Self::expires)
+    ///
+    /// Other options are not affected by this flag.
+    pub fn allow_unsupported(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_allow_unsupported(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingWriter> {
@@ -204,6 +346,16 @@ impl FunctionDelete {
         self
     }
 
+    /// Set the If-Match for this operation.
+    ///
+    /// The object will only be deleted if its etag matches, otherwise
+    /// `ConditionNotMatch` will be returned. Services that don't support
+    /// conditional delete will return `Unsupported`.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_match(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
@@ -226,6 +378,106 @@ impl FunctionList {
         self
     }
 
+    /// The limit_total is used to cap the total entries returned by this list.
+    ///
+    /// Different from `limit` which only controls the page size of every request,
+    /// lister will stop fetching more pages once `limit_total` entries have been
+    /// returned. It works along with `recursive` and `start_after`.
+    pub fn limit_total(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit_total(v));
+        self
+    }
+
+    /// The min_size is used to only return entries whose content length is larger
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// The max_size is used to only return entries whose content length is smaller
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// The modified_after is used to only return entries whose last modified is
+    /// after `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_after(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_after(v.into()));
+        self
+    }
+
+    /// The modified_before is used to only return entries whose last modified is
+    /// before `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_before(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_before(v.into()));
+        self
+    }
+
+    /// The only_files is used to only return file entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_dirs`.
+    pub fn only_files(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_files(v));
+        self
+    }
+
+    /// The only_dirs is used to only return dir entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_files`.
+    ///
+    /// # Notes
+    ///
+    /// Services like s3 don't return dir entries while listing recursively,
+    /// dirs will be derived from the parent paths of listed files instead.
+    pub fn only_dirs(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_dirs(v));
+        self
+    }
+
+    /// The sorted is used to control whether entries should be returned in sorted path order.
+    ///
+    /// Default to `false`, entries are returned in the order of underlying services.
+    ///
+    /// ## NOTE
+    ///
+    /// To guarantee a globally sorted result (especially with `recursive(true)`), all
+    /// entries under the path will be buffered in memory and sorted before returning.
+    /// The memory usage grows with the number of entries, so please use it along with
+    /// a prefix that is not too large.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -244,6 +496,23 @@ impl FunctionList {
         self
     }
 
+    /// The glob pattern is used to filter entries before they are returned.
+    ///
+    /// The pattern is matched against the path relative to the listing path, and
+    /// supports `*`, `?` and `**`. For example, `**/*.parquet` matches all parquet
+    /// files under the listing path.
+    ///
+    /// ## NOTE
+    ///
+    /// The filtering happens at client side, entries are still listed from services.
+    /// Only entries returned by the underlying lister can be matched, so patterns
+    /// with `/` or `**` should be used along with `recursive(true)` to match nested
+    /// entries.
+    pub fn pattern(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_pattern(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -251,12 +520,28 @@ impl FunctionList {
     /// - `Some(v)` means exist.
     /// - `None` means services doesn't have this meta.
     ///
-    /// The default metakey is `Metakey::Mode`.
+    /// The default metakey is `Metakey::Mode`, which could be changed by `with_default_metakey`
+    /// on operator. The metakey set here will override the default one.
     pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
 
+    /// Versions is used to control whether the list operation should return all versions of
+    /// objects.
+    ///
+    /// - If `false`, only the current version of objects will be listed.
+    /// - If `true`, all versions of objects will be listed, including delete markers which can
+    ///   be told apart via [`Metadata::is_deleted`].
+    ///
+    /// Service will return `Unsupported` if it doesn't support list with versions.
+    ///
+    /// Default to `false`.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Vec<Entry>> {
@@ -279,6 +564,106 @@ impl FunctionLister {
         self
     }
 
+    /// The limit_total is used to cap the total entries returned by this list.
+    ///
+    /// Different from `limit` which only controls the page size of every request,
+    /// lister will stop fetching more pages once `limit_total` entries have been
+    /// returned. It works along with `recursive` and `start_after`.
+    pub fn limit_total(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit_total(v));
+        self
+    }
+
+    /// The min_size is used to only return entries whose content length is larger
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// The max_size is used to only return entries whose content length is smaller
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// The modified_after is used to only return entries whose last modified is
+    /// after `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_after(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_after(v.into()));
+        self
+    }
+
+    /// The modified_before is used to only return entries whose last modified is
+    /// before `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_before(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_before(v.into()));
+        self
+    }
+
+    /// The only_files is used to only return file entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_dirs`.
+    pub fn only_files(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_files(v));
+        self
+    }
+
+    /// The only_dirs is used to only return dir entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_files`.
+    ///
+    /// # Notes
+    ///
+    /// Services like s3 don't return dir entries while listing recursively,
+    /// dirs will be derived from the parent paths of listed files instead.
+    pub fn only_dirs(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_dirs(v));
+        self
+    }
+
+    /// The sorted is used to control whether entries should be returned in sorted path order.
+    ///
+    /// Default to `false`, entries are returned in the order of underlying services.
+    ///
+    /// ## NOTE
+    ///
+    /// To guarantee a globally sorted result (especially with `recursive(true)`), all
+    /// entries under the path will be buffered in memory and sorted before returning.
+    /// The memory usage grows with the number of entries, so please use it along with
+    /// a prefix that is not too large.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -297,6 +682,23 @@ impl FunctionLister {
         self
     }
 
+    /// The glob pattern is used to filter entries before they are returned.
+    ///
+    /// The pattern is matched against the path relative to the listing path, and
+    /// supports `*`, `?` and `**`. For example, `**/*.parquet` matches all parquet
+    /// files under the listing path.
+    ///
+    /// ## NOTE
+    ///
+    /// The filtering happens at client side, entries are still listed from services.
+    /// Only entries returned by the underlying lister can be matched, so patterns
+    /// with `/` or `**` should be used along with `recursive(true)` to match nested
+    /// entries.
+    pub fn pattern(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_pattern(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -304,12 +706,28 @@ impl FunctionLister {
     /// - `Some(v)` means exist.
     /// - `None` means services doesn't have this meta.
     ///
-    /// The default metakey is `Metakey::Mode`.
+    /// The default metakey is `Metakey::Mode`, which could be changed by `with_default_metakey`
+    /// on operator. The metakey set here will override the default one.
     pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
 
+    /// Versions is used to control whether the list operation should return all versions of
+    /// objects.
+    ///
+    /// - If `false`, only the current version of objects will be listed.
+    /// - If `true`, all versions of objects will be listed, including delete markers which can
+    ///   be told apart via [`Metadata::is_deleted`].
+    ///
+    /// Service will return `Unsupported` if it doesn't support list with versions.
+    ///
+    /// Default to `false`.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingLister> {
@@ -329,6 +747,75 @@ impl FunctionRead {
         self
     }
 
+    /// Set the chunk size for this operation.
+    ///
+    /// If chunk is set, the read will be split into multiple range requests, each of them
+    /// reads at most `chunk` bytes. Larger chunks reduce the number of requests on high-latency
+    /// links, while smaller chunks reduce memory usage of every request.
+    ///
+    /// # Notes
+    ///
+    /// - By default, there is no chunk and the whole range will be read in one request.
+    /// - Chunk only takes effect on services that read via range requests like `s3` and `gcs`.
+    /// - Some services have a maximum size for one request, please don't set it too large.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_chunk(v));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file doesn't match.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_match(v));
+        self
+    }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file matches, which
+    /// means the file is not modified.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_none_match(v));
+        self
+    }
+
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_modified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_modified_since`]: crate::operator_futures::FutureStat::if_modified_since
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_unmodified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_unmodified_since`]: crate::operator_futures::FutureStat::if_unmodified_since
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
+    }
+
+    /// Set the version for this operation.
+    pub fn version(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_version(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Vec<u8>> {
@@ -348,6 +835,33 @@ impl FunctionReader {
         self
     }
 
+    /// Set the chunk size for this operation.
+    ///
+    /// If chunk is set, the read will be split into multiple range requests, each of them
+    /// reads at most `chunk` bytes. Larger chunks reduce the number of requests on high-latency
+    /// links, while smaller chunks reduce memory usage of every request.
+    ///
+    /// # Notes
+    ///
+    /// - By default, there is no chunk and the whole range will be read in one request.
+    /// - Chunk only takes effect on services that read via range requests like `s3` and `gcs`.
+    /// - Some services have a maximum size for one request, please don't set it too large.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_chunk(v));
+        self
+    }
+
+    /// Set the read-ahead buffer size for this operation.
+    ///
+    /// If buffer is set, the reader will prefetch up to `buffer` bytes and serve
+    /// following sequential reads and seeks inside the buffered window from memory.
+    ///
+    /// Reads larger than `buffer` will bypass the buffer.
+    pub fn buffer(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_buffer(v));
+        self
+    }
+
     /// Sets the content-disposition header that should be send back by the remote read operation.
     pub fn override_content_disposition(mut self, content_disposition: &str) -> Self {
         self.0 = self
@@ -373,17 +887,52 @@ impl FunctionReader {
     }
 
     /// Set the If-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file doesn't match.
     pub fn if_match(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_if_match(v));
         self
     }
 
     /// Set the If-None-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file matches, which
+    /// means the file is not modified.
     pub fn if_none_match(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_if_none_match(v));
         self
     }
 
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_modified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_modified_since`]: crate::operator_futures::FutureStat::if_modified_since
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_unmodified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_unmodified_since`]: crate::operator_futures::FutureStat::if_unmodified_since
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
+    }
+
     /// Set the version for this operation.
     pub fn version(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_version(v));
@@ -415,12 +964,71 @@ impl FunctionStat {
         self
     }
 
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_modified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_modified_since`]: crate::operator_futures::FutureStat::if_modified_since
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_unmodified_since`] for the precision of the given time.
+    ///
+    /// [`FutureStat::if_unmodified_since`]: crate::operator_futures::FutureStat::if_unmodified_since
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
+    }
+
     /// Set the version for this operation.
     pub fn version(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
 
+    /// Set the content-disposition header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_content_disposition` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_content_disposition(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_override_content_disposition(v));
+        self
+    }
+
+    /// Set the cache-control header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_cache_control` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_cache_control(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_override_cache_control(v));
+        self
+    }
+
+    /// Set the content-type header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_content_type` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_content_type(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_override_content_type(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Metadata> {
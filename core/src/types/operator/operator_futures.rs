@@ -19,18 +19,25 @@
 //!
 //! By using futures, users can add more options for operation.
 
+use std::cmp;
+use std::collections::HashMap;
 use std::mem;
 use std::ops::RangeBounds;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
+use std::time::SystemTime;
 
 use bytes::Bytes;
 use flagset::FlagSet;
+use futures::stream;
 use futures::Future;
 use futures::FutureExt;
+use futures::StreamExt;
+use futures::TryStreamExt;
 
+use crate::raw::oio::ReadExt;
 use crate::raw::*;
 use crate::*;
 
@@ -48,9 +55,14 @@ pub(crate) enum OperatorFuture<T, F> {
         T,
         /// The function which will move all the args and return a static future
         fn(FusedAccessor, String, T) -> BoxedFuture<Result<F>>,
+        /// The timeout of the whole operation
+        Option<Duration>,
     ),
     /// Polling state, waiting for the future to be ready
     Poll(BoxedFuture<Result<F>>),
+    /// Failed state, the input args are invalid and the error will be
+    /// returned once polled.
+    Failed(Error),
     /// Empty state, the future has been polled and completed or
     /// something is broken during state switch.
     Empty,
@@ -63,14 +75,52 @@ impl<T, F> OperatorFuture<T, F> {
         args: T,
         f: fn(FusedAccessor, String, T) -> BoxedFuture<Result<F>>,
     ) -> Self {
-        OperatorFuture::Idle(inner, path, args, f)
+        OperatorFuture::Idle(inner, path, args, f, None)
     }
 
     fn map_args(self, f: impl FnOnce(T) -> T) -> Self {
         match self {
-            OperatorFuture::Idle(inner, path, args, func) => {
-                OperatorFuture::Idle(inner, path, f(args), func)
+            OperatorFuture::Idle(inner, path, args, func, timeout) => {
+                OperatorFuture::Idle(inner, path, f(args), func, timeout)
             }
+            OperatorFuture::Failed(err) => OperatorFuture::Failed(err),
+            _ => unreachable!("future has been polled and should not be changed again"),
+        }
+    }
+
+    /// Mark this future as failed, the error will be returned once polled.
+    ///
+    /// The first error will be kept if the future has already failed.
+    fn fail(self, err: Error) -> Self {
+        match self {
+            OperatorFuture::Idle(..) => OperatorFuture::Failed(err),
+            OperatorFuture::Failed(err) => OperatorFuture::Failed(err),
+            _ => unreachable!("future has been polled and should not be changed again"),
+        }
+    }
+
+    /// Convert into a new future with mapped args and another function.
+    fn map_function<U, G>(
+        self,
+        f: impl FnOnce(T) -> U,
+        func: fn(FusedAccessor, String, U) -> BoxedFuture<Result<G>>,
+    ) -> OperatorFuture<U, G> {
+        match self {
+            OperatorFuture::Idle(inner, path, args, _, timeout) => {
+                OperatorFuture::Idle(inner, path, f(args), func, timeout)
+            }
+            OperatorFuture::Failed(err) => OperatorFuture::Failed(err),
+            _ => unreachable!("future has been polled and should not be changed again"),
+        }
+    }
+
+    /// Set the timeout of the whole operation.
+    fn with_timeout(self, timeout: Duration) -> Self {
+        match self {
+            OperatorFuture::Idle(inner, path, args, func, _) => {
+                OperatorFuture::Idle(inner, path, args, func, Some(timeout))
+            }
+            OperatorFuture::Failed(err) => OperatorFuture::Failed(err),
             _ => unreachable!("future has been polled and should not be changed again"),
         }
     }
@@ -79,7 +129,7 @@ impl<T, F> OperatorFuture<T, F> {
 impl<T, F> Future for OperatorFuture<T, F>
 where
     T: Unpin,
-    F: Unpin,
+    F: Unpin + 'static,
 {
     type Output = Result<F>;
 
@@ -96,16 +146,31 @@ where
     /// In general, `Empty` state should not be polled.
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         *self = match mem::replace(self.as_mut().get_mut(), OperatorFuture::Empty) {
-            OperatorFuture::Idle(inner, path, args, f) => {
+            OperatorFuture::Idle(inner, path, args, f, timeout) => {
                 // Wake up to make sure the future is ready after the
                 // future has been built.
                 cx.waker().wake_by_ref();
-                OperatorFuture::Poll(f(inner, path, args))
+                match timeout {
+                    Some(timeout) => {
+                        let fut = f(inner, path.clone(), args);
+                        OperatorFuture::Poll(Box::pin(async move {
+                            tokio::time::timeout(timeout, fut).await.map_err(|_| {
+                                Error::new(ErrorKind::Timeout, "operation timeout")
+                                    .with_operation("OperatorFuture::timeout")
+                                    .with_context("path", path)
+                                    .with_context("timeout", timeout.as_secs_f64().to_string())
+                                    .set_temporary()
+                            })?
+                        }))
+                    }
+                    None => OperatorFuture::Poll(f(inner, path, args)),
+                }
             }
             OperatorFuture::Poll(mut fut) => match fut.as_mut().poll(cx) {
                 Poll::Pending => OperatorFuture::Poll(fut),
                 Poll::Ready(v) => return Poll::Ready(v),
             },
+            OperatorFuture::Failed(err) => return Poll::Ready(Err(err)),
             OperatorFuture::Empty => {
                 panic!("future polled after completion");
             }
@@ -114,6 +179,42 @@ where
     }
 }
 
+/// Future that generated by [`Operator::create_dir_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureCreateDir(pub(crate) OperatorFuture<OpCreateDir, ()>);
+
+impl FutureCreateDir {
+    /// Set the content type of the directory marker.
+    pub fn content_type(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_type(v));
+        self
+    }
+
+    /// Set the user metadata of the directory marker.
+    pub fn user_metadata(mut self, v: HashMap<String, String>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_user_metadata(v));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureCreateDir {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
 /// Future that generated by [`Operator::stat_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -132,11 +233,101 @@ impl FutureStat {
         self
     }
 
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// # Notes
+    ///
+    /// - HTTP date only has second precision, so the given time will be truncated to
+    ///   seconds.
+    /// - Dirs simulated via `list` (like [`FutureStat::infer_dir`]) don't have last
+    ///   modified, [`ErrorKind::Unsupported`] will be returned for them.
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// # Notes
+    ///
+    /// - HTTP date only has second precision, so the given time will be truncated to
+    ///   seconds.
+    /// - Dirs simulated via `list` (like [`FutureStat::infer_dir`]) don't have last
+    ///   modified, [`ErrorKind::Unsupported`] will be returned for them.
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
+    }
+
     /// Set the version for this operation.
     pub fn version(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
+
+    /// Set the content-disposition header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_content_disposition` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_content_disposition(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_override_content_disposition(v));
+        self
+    }
+
+    /// Set the cache-control header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_cache_control` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_cache_control(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_override_cache_control(v));
+        self
+    }
+
+    /// Set the content-type header that should be send back by the remote.
+    ///
+    /// Only services with `stat_with_override_content_type` capability
+    /// honor this option, and the returned metadata will carry the overridden value.
+    pub fn override_content_type(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_override_content_type(v));
+        self
+    }
+
+    /// Set whether to infer dir from prefix for dir path.
+    ///
+    /// Object storage services like `s3` don't have real dirs, so stat `abc/`
+    /// could return `NotFound` even if `abc/file` exists. If enabled, stat a path
+    /// ends with `/` that returns `NotFound` will list the path with limit `1`,
+    /// and return a dir metadata if any entry exists under it.
+    ///
+    /// # Notes
+    ///
+    /// An extra `list` request will be sent every time the dir path is not found,
+    /// and services that don't support `list` will return the `NotFound` as is.
+    pub fn infer_dir(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_infer_dir(v));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
 }
 
 impl Future for FutureStat {
@@ -178,12 +369,26 @@ impl FuturePresignStat {
     }
 
     /// Set the If-Match of the option
+    ///
+    /// The `If-Match` header will be included in the signed request, so clients
+    /// must send it as is and the service will return `412 Precondition Failed`
+    /// if the etag doesn't match.
+    ///
+    /// Supported by `s3`, `gcs`, `oss`, `obs` and `cos`. `b2` can't carry the
+    /// condition in its download authorization and will return
+    /// [`ErrorKind::Unsupported`] instead of dropping it.
     pub fn if_match(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|(args, dur)| (args.with_if_match(v), dur));
         self
     }
 
     /// Set the If-None-Match of the option
+    ///
+    /// The `If-None-Match` header will be included in the signed request, so
+    /// clients must send it as is and the service will return `304 Not Modified`
+    /// if the etag matches.
+    ///
+    /// Supported by the same services as [`FuturePresignStat::if_match`].
     pub fn if_none_match(mut self, v: &str) -> Self {
         self.0 = self
             .0
@@ -200,53 +405,88 @@ impl Future for FuturePresignStat {
     }
 }
 
+/// Args of presign futures: the operation args, the expire duration, extra
+/// signed headers and extra signed query parameters.
+pub(crate) type PresignArgs<T> = (T, Duration, Vec<(String, String)>, Vec<(String, String)>);
+
 /// Future that generated by [`Operator::presign_read_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FuturePresignRead(pub(crate) OperatorFuture<(OpRead, Duration), PresignedRequest>);
+pub struct FuturePresignRead(pub(crate) OperatorFuture<PresignArgs<OpRead>, PresignedRequest>);
 
 impl FuturePresignRead {
     /// Create a new OpRead with range.
     pub fn range(mut self, v: BytesRange) -> Self {
-        self.0 = self.0.map_args(|(args, dur)| (args.with_range(v), dur));
+        self.0 = self
+            .0
+            .map_args(|(args, dur, headers, query)| (args.with_range(v), dur, headers, query));
         self
     }
 
     /// Sets the content-disposition header that should be send back by the remote read operation.
     pub fn override_content_disposition(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_override_content_disposition(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (
+                args.with_override_content_disposition(v),
+                dur,
+                headers,
+                query,
+            )
+        });
         self
     }
 
     /// Sets the cache-control header that should be send back by the remote read operation.
     pub fn override_cache_control(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_override_cache_control(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_override_cache_control(v), dur, headers, query)
+        });
         self
     }
 
     /// Sets the content-type header that should be send back by the remote read operation.
     pub fn override_content_type(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_override_content_type(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_override_content_type(v), dur, headers, query)
+        });
         self
     }
 
     /// Set the If-Match of the option
     pub fn if_match(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|(args, dur)| (args.with_if_match(v), dur));
+        self.0 = self
+            .0
+            .map_args(|(args, dur, headers, query)| (args.with_if_match(v), dur, headers, query));
         self
     }
 
     /// Set the If-None-Match of the option
     pub fn if_none_match(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_if_none_match(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_if_none_match(v), dur, headers, query)
+        });
+        self
+    }
+
+    /// Add an extra header that will be included in the signature.
+    ///
+    /// The client must send this header with the same value, otherwise
+    /// the presigned request will be rejected. For example, `x-amz-acl`
+    /// could be signed in this way to enforce the acl of written object.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.0 = self.0.map_args(|(args, dur, mut headers, query)| {
+            headers.push((key.to_string(), value.to_string()));
+            (args, dur, headers, query)
+        });
+        self
+    }
+
+    /// Add an extra query parameter that will be included in the signature.
+    pub fn query(mut self, key: &str, value: &str) -> Self {
+        self.0 = self.0.map_args(|(args, dur, headers, mut query)| {
+            query.push((key.to_string(), value.to_string()));
+            (args, dur, headers, query)
+        });
         self
     }
 }
@@ -262,30 +502,52 @@ impl Future for FuturePresignRead {
 /// Future that generated by [`Operator::presign_read_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FuturePresignWrite(pub(crate) OperatorFuture<(OpWrite, Duration), PresignedRequest>);
+pub struct FuturePresignWrite(pub(crate) OperatorFuture<PresignArgs<OpWrite>, PresignedRequest>);
 
 impl FuturePresignWrite {
     /// Set the content type of option
     pub fn content_type(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_content_type(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_content_type(v), dur, headers, query)
+        });
         self
     }
 
     /// Set the content disposition of option
     pub fn content_disposition(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_content_disposition(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_content_disposition(v), dur, headers, query)
+        });
         self
     }
 
     /// Set the content type of option
     pub fn cache_control(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, dur)| (args.with_cache_control(v), dur));
+        self.0 = self.0.map_args(|(args, dur, headers, query)| {
+            (args.with_cache_control(v), dur, headers, query)
+        });
+        self
+    }
+
+    /// Add an extra header that will be included in the signature.
+    ///
+    /// The client must send this header with the same value, otherwise
+    /// the presigned request will be rejected. For example, `x-amz-acl`
+    /// could be signed in this way to enforce the acl of written object.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.0 = self.0.map_args(|(args, dur, mut headers, query)| {
+            headers.push((key.to_string(), value.to_string()));
+            (args, dur, headers, query)
+        });
+        self
+    }
+
+    /// Add an extra query parameter that will be included in the signature.
+    pub fn query(mut self, key: &str, value: &str) -> Self {
+        self.0 = self.0.map_args(|(args, dur, headers, mut query)| {
+            query.push((key.to_string(), value.to_string()));
+            (args, dur, headers, query)
+        });
         self
     }
 }
@@ -298,78 +560,85 @@ impl Future for FuturePresignWrite {
     }
 }
 
-/// Future that generated by [`Operator::read_with`].
+/// Future that generated by [`Operator::presign_delete_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FutureRead(pub(crate) OperatorFuture<OpRead, Vec<u8>>);
-
-impl FutureRead {
-    /// Set the range header for this operation.
-    pub fn range(mut self, range: impl RangeBounds<u64>) -> Self {
-        self.0 = self.0.map_args(|args| args.with_range(range.into()));
-        self
-    }
-
-    /// Sets the content-disposition header that should be send back by the remote read operation.
-    pub fn override_content_disposition(mut self, content_disposition: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|args| args.with_override_content_disposition(content_disposition));
-        self
-    }
-
-    /// Sets the cache-control header that should be send back by the remote read operation.
-    pub fn override_cache_control(mut self, cache_control: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|args| args.with_override_cache_control(cache_control));
-        self
-    }
-
-    /// Sets the content-type header that should be send back by the remote read operation.
-    pub fn override_content_type(mut self, content_type: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|args| args.with_override_content_type(content_type));
-        self
-    }
+pub struct FuturePresignDelete(pub(crate) OperatorFuture<(OpDelete, Duration), PresignedRequest>);
 
-    /// Set the If-Match for this operation.
-    pub fn if_match(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|args| args.with_if_match(v));
-        self
-    }
-
-    /// Set the If-None-Match for this operation.
-    pub fn if_none_match(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|args| args.with_if_none_match(v));
-        self
-    }
-
-    /// Set the version for this operation.
+impl FuturePresignDelete {
+    /// Set the version of the option
     pub fn version(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|args| args.with_version(v));
+        self.0 = self.0.map_args(|(args, dur)| (args.with_version(v), dur));
         self
     }
 }
 
-impl Future for FutureRead {
-    type Output = Result<Vec<u8>>;
+impl Future for FuturePresignDelete {
+    type Output = Result<PresignedRequest>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         self.0.poll_unpin(cx)
     }
 }
 
-/// Future that generated by [`Operator::reader_with`].
+/// Future that generated by [`Operator::read_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FutureReader(pub(crate) OperatorFuture<OpRead, Reader>);
+pub struct FutureRead(
+    pub(crate) OperatorFuture<OpRead, Vec<u8>>,
+    /// The limit of the operator, used as the concurrency of [`FutureRead::ranges`].
+    pub(crate) usize,
+);
 
-impl FutureReader {
+impl FutureRead {
     /// Set the range header for this operation.
+    ///
+    /// # Notes
+    ///
+    /// If the content length is known (open-ended ranges like `1024..` always
+    /// trigger a `stat`), the range will be validated against it:
+    ///
+    /// - A range starting beyond the end returns [`ErrorKind::RangeNotSatisfied`].
+    /// - A range ending beyond the end will be clamped to the end.
+    ///
+    /// Inverted ranges like `20..10` will always return [`ErrorKind::RangeNotSatisfied`].
     pub fn range(mut self, range: impl RangeBounds<u64>) -> Self {
-        self.0 = self.0.map_args(|args| args.with_range(range.into()));
+        self.0 = match BytesRange::try_from_range(range) {
+            Ok(range) => self.0.map_args(|args| args.with_range(range)),
+            Err(err) => self.0.fail(err.with_operation("read")),
+        };
+        self
+    }
+
+    /// Set the range from the value of a HTTP `Range` header.
+    ///
+    /// The value follows the standard syntax like `bytes=0-99`, `bytes=100-`
+    /// and `bytes=-100` (the last 100 bytes), so a `Range` header received
+    /// from an upstream request can be forwarded directly.
+    ///
+    /// # Notes
+    ///
+    /// Only a single range is supported. [`ErrorKind::InvalidInput`] will be
+    /// returned while polling if the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op.read_with("path/to/file").http_range("bytes=0-99").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn http_range(mut self, range: &str) -> Self {
+        self.0 = match range.parse::<BytesRange>() {
+            Ok(range) => self.0.map_args(|args| args.with_range(range)),
+            Err(err) => self
+                .0
+                .fail(err.with_operation("read").with_context("range", range)),
+        };
         self
     }
 
@@ -398,83 +667,1078 @@ impl FutureReader {
     }
 
     /// Set the If-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file doesn't match.
     pub fn if_match(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_if_match(v));
         self
     }
 
     /// Set the If-None-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file matches, which
+    /// means the file is not modified.
     pub fn if_none_match(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_if_none_match(v));
         self
     }
-}
 
-impl Future for FutureReader {
-    type Output = Result<Reader>;
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_modified_since`] for the precision of the given time.
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.0.poll_unpin(cx)
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_unmodified_since`] for the precision of the given time.
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
     }
-}
 
-/// Future that generated by [`Operator::write_with`].
-///
-/// Users can add more options by public functions provided by this struct.
-pub struct FutureWrite(pub(crate) OperatorFuture<(OpWrite, Bytes), ()>);
+    /// Set the version for this operation.
+    pub fn version(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_version(v));
+        self
+    }
 
-impl FutureWrite {
-    /// Set the append mode of op.
+    /// Set whether to verify the checksum of the content read.
     ///
-    /// If the append mode is set, the data will be appended to the end of the file.
+    /// If enabled and the service returns `Content-MD5` for this file, the
+    /// content will be verified after all bytes have been read. A
+    /// [`ErrorKind::ChecksumMismatch`] error will be returned on failure.
     ///
     /// # Notes
     ///
-    /// Service could return `Unsupported` if the underlying storage does not support append.
-    pub fn append(mut self, v: bool) -> Self {
-        self.0 = self.0.map_args(|(args, bs)| (args.with_append(v), bs));
+    /// - `s3` doesn't return `Content-MD5`, the etag will be used instead for objects
+    ///   uploaded in a single request without SSE-KMS or SSE-C.
+    /// - Verification will be skipped if the service doesn't return `Content-MD5`
+    ///   or the returned value is not a valid md5 digest.
+    /// - Verification is not available for range read since the stored checksum
+    ///   covers the whole object.
+    pub fn verify_checksum(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify_checksum(v));
         self
     }
 
-    /// Set the buffer size of op.
+    /// Verify the length of content against the resolved range while reading.
     ///
-    /// If buffer size is set, the data will be buffered by the underlying writer.
+    /// Services could return less data than requested in rare cases. OpenDAL will
+    /// always try to read the remaining range again if the range has been resolved
+    /// against the content length. The remaining range will be read with `If-Match`
+    /// set to the etag of the file if supported, so that content of different
+    /// versions won't be mixed up. With `verify_length` enabled:
     ///
-    /// ## NOTE
+    /// - The content length will be fetched via `stat` to resolve the range if it's
+    ///   not known yet.
+    /// - An [`ErrorKind::Unexpected`] error with the expected and actual length will
+    ///   be returned if the content is still short or longer than expected.
     ///
-    /// Service could have their own minimum buffer size while perform write operations like
-    /// multipart uploads. So the buffer size may be larger than the given buffer size.
-    pub fn buffer(mut self, v: usize) -> Self {
-        self.0 = self.0.map_args(|(args, bs)| (args.with_buffer(v), bs));
+    /// # Notes
+    ///
+    /// The verification will be skipped for decompressed content.
+    pub fn verify_length(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify_length(v));
         self
     }
 
-    /// Set the content type of option
-    pub fn content_type(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, bs)| (args.with_content_type(v), bs));
+    /// Set the chunk size for this operation.
+    ///
+    /// If chunk is set, the read will be split into multiple range requests, each of them
+    /// reads at most `chunk` bytes. Larger chunks reduce the number of requests on high-latency
+    /// links, while smaller chunks reduce memory usage of every request.
+    ///
+    /// # Notes
+    ///
+    /// - By default, there is no chunk and the whole range will be read in one request.
+    /// - Chunk only takes effect on services that read via range requests like `s3` and `gcs`.
+    /// - Some services have a maximum size for one request, please don't set it too large.
+    /// - Chunk must be larger than 0, [`ErrorKind::InvalidInput`] will be returned otherwise.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.0 = if v == 0 {
+            self.0.fail(
+                Error::new(ErrorKind::InvalidInput, "chunk must be larger than 0")
+                    .with_operation("read"),
+            )
+        } else {
+            self.0.map_args(|args| args.with_chunk(v))
+        };
         self
     }
 
-    /// Set the content disposition of option
-    pub fn content_disposition(mut self, v: &str) -> Self {
-        self.0 = self
-            .0
-            .map_args(|(args, bs)| (args.with_content_disposition(v), bs));
-        self
+    /// Pick the chunk size and concurrency for this operation automatically.
+    ///
+    /// After the content length has been resolved, small objects will still be read in
+    /// one request, while larger ones will be split into range requests that are sent
+    /// concurrently.
+    ///
+    /// # Notes
+    ///
+    /// - Chunks in flight are capped by [`FutureRead::memory_budget`], 64 MiB by default.
+    /// - `chunk` set by users will be respected.
+    /// - Auto concurrent only takes effect on services that support `read_with_range`.
+    /// - Auto concurrent will be skipped while verifying checksum, since the checksum must
+    ///   be calculated over the content in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_with("path/to/file")
+    ///     .auto_concurrent()
+    ///     .memory_budget(32 * 1024 * 1024)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_concurrent(mut self) -> Self {
+        self.0 = self.0.map_args(|args| args.with_auto_concurrent(true));
+        self
+    }
+
+    /// Set the memory budget of chunks in flight for [`FutureRead::auto_concurrent`].
+    ///
+    /// The concurrency will be lowered to make sure `chunk * concurrent` doesn't exceed
+    /// the budget. Only takes effect while auto concurrent is enabled.
+    pub fn memory_budget(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_memory_budget(v));
+        self
+    }
+
+    /// Decompress the content while reading.
+    ///
+    /// With [`Compression::Auto`], the algorithm will be detected from the
+    /// `Content-Encoding` of the object, and content without a supported
+    /// encoding will be returned as is.
+    ///
+    /// # Notes
+    ///
+    /// - Only available while the `compress` feature is enabled.
+    /// - `range` applies to the compressed content, please read the whole
+    ///   object to get a valid decompressed result.
+    /// - `on_progress` reports the compressed bytes read from the service.
+    /// - Auto concurrent will be skipped while decompressing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::Compression;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_with("path/to/file.gz")
+    ///     .decompress(Compression::Auto)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "compress")]
+    pub fn decompress(mut self, v: Compression) -> Self {
+        self.0 = self.0.map_args(|args| args.with_decompress(v));
+        self
+    }
+
+    /// Set the progress callback for this operation.
+    ///
+    /// The callback will be called with `(bytes_read, total)` every time a non-empty
+    /// chunk arrives. `total` is the size of the resolved range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_with("path/to/file")
+    ///     .on_progress(|read, total| println!("{read}/{total:?}"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        let progress = ProgressCallback::new(f);
+        self.0 = self.0.map_args(|args| args.with_progress(progress));
+        self
+    }
+
+    /// Set the transform which will be applied to the content after it has been
+    /// read, for example, to decrypt content that was encrypted on client side.
+    ///
+    /// # Notes
+    ///
+    /// - The transform is applied before `decompress`.
+    /// - Reading with transform will not be split into concurrent chunks.
+    /// - The full content must be read, [`ErrorKind::InvalidInput`] will be returned
+    ///   if `range` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bytes::Bytes;
+    /// # use opendal::Operator;
+    /// # use opendal::Result;
+    /// # use opendal::Transform;
+    /// struct Xor(u8);
+    ///
+    /// impl Transform for Xor {
+    ///     fn transform(&mut self, chunk: &[u8]) -> Result<Bytes> {
+    ///         Ok(chunk.iter().map(|b| b ^ self.0).collect())
+    ///     }
+    /// }
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_with("path/to/file")
+    ///     .transform(Box::new(Xor(0x42)))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transform(mut self, v: Box<dyn Transform>) -> Self {
+        let transform = TransformSlot::new(v);
+        self.0 = self.0.map_args(|args| args.with_transform(transform));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl FutureRead {
+    /// Read multiple ranges of the path at once.
+    ///
+    /// The returned future resolves to a `Vec<Bytes>` aligned to the given ranges.
+    /// Other options like `if_match` set before will be applied to every range.
+    ///
+    /// # Notes
+    ///
+    /// - Services don't return `multipart/byteranges` responses for now, so every range
+    ///   will be read via a single-range request concurrently. At most
+    ///   [`Operator::limit`] requests will be sent at the same time.
+    /// - `progress` will be called after every range has been read with the total bytes
+    ///   read so far.
+    /// - `verify_checksum`, `decompress` and `transform` are not supported along with
+    ///   `ranges`, [`ErrorKind::InvalidInput`] will be returned if any of them is set.
+    /// - Inverted ranges like `20..10` will return [`ErrorKind::RangeNotSatisfied`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bss = op
+    ///     .read_with("path/to/file")
+    ///     .ranges(vec![0..10, 100..200])
+    ///     .await?;
+    /// assert_eq!(bss.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ranges<R: RangeBounds<u64>>(
+        self,
+        ranges: impl IntoIterator<Item = R>,
+    ) -> FutureReadRanges {
+        let (ranges, err) = match ranges
+            .into_iter()
+            .map(BytesRange::try_from_range)
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(ranges) => (ranges, None),
+            Err(err) => (Vec::new(), Some(err)),
+        };
+        let limit = self.1;
+
+        let fut = self.0.map_function(
+            |args| (args, ranges, limit),
+            |inner, path, (args, ranges, limit)| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::FILE) {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "read path is a directory",
+                        )
+                        .with_operation("read")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+                    if args.verify_checksum()
+                        || args.decompress().is_some()
+                        || args.transform().is_some()
+                    {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "verify_checksum, decompress and transform are not supported with ranges",
+                        )
+                        .with_operation("read")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+
+                    let progress = args.progress().cloned();
+                    let total = ranges
+                        .iter()
+                        .map(|range| range.size())
+                        .sum::<Option<u64>>();
+                    let mut bss = stream::iter(ranges)
+                        .map(|range| {
+                            let inner = inner.clone();
+                            let path = path.clone();
+                            let args = args.clone().with_range(range);
+                            async move {
+                                // Empty range can't be sent to services.
+                                if range.size() == Some(0) {
+                                    return Ok(Bytes::new());
+                                }
+                                let (_, mut r) = inner.read(&path, args).await?;
+                                let mut buf =
+                                    Vec::with_capacity(range.size().unwrap_or_default() as usize);
+                                r.read_to_end(&mut buf).await?;
+                                Ok::<_, Error>(Bytes::from(buf))
+                            }
+                        })
+                        .buffered(cmp::max(1, limit));
+
+                    let mut res = Vec::new();
+                    let mut read = 0;
+                    while let Some(bs) = bss.try_next().await? {
+                        read += bs.len() as u64;
+                        if let Some(progress) = &progress {
+                            progress.call(read, total);
+                        }
+                        res.push(bs);
+                    }
+                    Ok(res)
+                };
+                Box::pin(fut)
+            },
+        );
+
+        FutureReadRanges(match err {
+            Some(err) => fut.fail(err.with_operation("read")),
+            None => fut,
+        })
+    }
+}
+
+impl Future for FutureRead {
+    type Output = Result<Vec<u8>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`FutureRead::ranges`].
+pub struct FutureReadRanges(
+    pub(crate) OperatorFuture<(OpRead, Vec<BytesRange>, usize), Vec<Bytes>>,
+);
+
+impl Future for FutureReadRanges {
+    type Output = Result<Vec<Bytes>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::reader_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureReader(pub(crate) OperatorFuture<OpRead, Reader>);
+
+impl FutureReader {
+    /// Set the range header for this operation.
+    ///
+    /// # Notes
+    ///
+    /// The range will be validated like [`FutureRead::range`] only if the content
+    /// length has been fetched for other options, for example `verify_checksum`.
+    /// Inverted ranges like `20..10` will always return [`ErrorKind::RangeNotSatisfied`].
+    pub fn range(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.0 = match BytesRange::try_from_range(range) {
+            Ok(range) => self.0.map_args(|args| args.with_range(range)),
+            Err(err) => self.0.fail(err.with_operation("read")),
+        };
+        self
+    }
+
+    /// Sets the content-disposition header that should be send back by the remote read operation.
+    pub fn override_content_disposition(mut self, content_disposition: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_override_content_disposition(content_disposition));
+        self
+    }
+
+    /// Sets the cache-control header that should be send back by the remote read operation.
+    pub fn override_cache_control(mut self, cache_control: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_override_cache_control(cache_control));
+        self
+    }
+
+    /// Sets the content-type header that should be send back by the remote read operation.
+    pub fn override_content_type(mut self, content_type: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_override_content_type(content_type));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file doesn't match.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_match(v));
+        self
+    }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the etag of the file matches, which
+    /// means the file is not modified.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_none_match(v));
+        self
+    }
+
+    /// Set the If-Modified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has not been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_modified_since`] for the precision of the given time.
+    pub fn if_modified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_modified_since(v.into()));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    ///
+    /// `ConditionNotMatch` will be returned if the file has been modified since
+    /// the given time.
+    ///
+    /// Refer to [`FutureStat::if_unmodified_since`] for the precision of the given time.
+    pub fn if_unmodified_since(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_if_unmodified_since(v.into()));
+        self
+    }
+
+    /// Set the version for this operation.
+    ///
+    /// The reader will read the content of the given version instead of the
+    /// latest one.
+    pub fn version(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_version(v));
+        self
+    }
+
+    /// Set whether to verify the checksum of the content read.
+    ///
+    /// If enabled and the service returns `Content-MD5` for this file, the
+    /// content will be verified after all bytes have been read. A
+    /// [`ErrorKind::ChecksumMismatch`] error will be returned on failure.
+    ///
+    /// # Notes
+    ///
+    /// - `s3` doesn't return `Content-MD5`, the etag will be used instead for objects
+    ///   uploaded in a single request without SSE-KMS or SSE-C.
+    /// - Verification will be skipped if the service doesn't return `Content-MD5`
+    ///   or the returned value is not a valid md5 digest.
+    /// - Verification is not available for range read since the stored checksum
+    ///   covers the whole object.
+    /// - Seeking on the reader will disable the verification.
+    pub fn verify_checksum(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify_checksum(v));
+        self
+    }
+
+    /// Verify the length of content against the resolved range while reading.
+    ///
+    /// Services could return less data than requested in rare cases. The reader
+    /// will try to read the remaining range again if the range has been resolved
+    /// against the content length, which happens if any of `verify_length`,
+    /// `verify_checksum` or auto decompress is enabled. The remaining range will
+    /// be read with `If-Match` set to the etag of the file if supported. With
+    /// `verify_length` enabled, an [`ErrorKind::Unexpected`] error will be returned
+    /// once the reader reaches the end if the content is still short.
+    ///
+    /// # Notes
+    ///
+    /// - The length is verified against the content before decompressed.
+    /// - Seeking on the reader will disable the verification, and seeking after
+    ///   the remaining range has been read again is not supported.
+    pub fn verify_length(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify_length(v));
+        self
+    }
+
+    /// Set the chunk size for this operation.
+    ///
+    /// If chunk is set, the read will be split into multiple range requests, each of them
+    /// reads at most `chunk` bytes. Larger chunks reduce the number of requests on high-latency
+    /// links, while smaller chunks reduce memory usage of every request.
+    ///
+    /// # Notes
+    ///
+    /// - By default, there is no chunk and the whole range will be read in one request.
+    /// - Chunk only takes effect on services that read via range requests like `s3` and `gcs`.
+    /// - Some services have a maximum size for one request, please don't set it too large.
+    /// - Chunk must be larger than 0, [`ErrorKind::InvalidInput`] will be returned otherwise.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.0 = if v == 0 {
+            self.0.fail(
+                Error::new(ErrorKind::InvalidInput, "chunk must be larger than 0")
+                    .with_operation("read"),
+            )
+        } else {
+            self.0.map_args(|args| args.with_chunk(v))
+        };
+        self
+    }
+
+    /// Set the read-ahead buffer size for this operation.
+    ///
+    /// If buffer is set, the reader will prefetch up to `buffer` bytes and serve
+    /// following sequential reads and seeks inside the buffered window from memory.
+    /// This is useful for consumers that issue many tiny reads, like streaming parsers.
+    ///
+    /// # Notes
+    ///
+    /// - Unlike `chunk`, buffer doesn't change the requests sent to services; it
+    ///   only avoids re-requesting data that has been fetched already.
+    /// - Reads larger than `buffer` will bypass the buffer.
+    pub fn buffer(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_buffer(v));
+        self
+    }
+
+    /// Set the merge gap for this operation.
+    ///
+    /// If merge gap is set, seeking forward at most `v` bytes while a read request is
+    /// in flight will read and drop the bytes in between instead of starting a new
+    /// request. Scattered small reads that are close to each other, like the ones issued
+    /// by Parquet/ORC readers, will be served by one larger request.
+    ///
+    /// # Notes
+    ///
+    /// - Merge gap only takes effect on services that read via range requests like `s3`
+    ///   and `gcs`, seekable services like `fs` will ignore it.
+    /// - The gap will never go across the range of current request: if `chunk` is set,
+    ///   seeking into the next chunk still starts a new request.
+    /// - Combined with `buffer`, seeks inside the buffered window are served from memory
+    ///   first, and only seeks beyond it will consume the merge gap.
+    /// - The skipped bytes are still transferred, so a large gap trades bandwidth for
+    ///   fewer requests.
+    pub fn merge_gap(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_merge_gap(v));
+        self
+    }
+
+    /// Decompress the content while reading.
+    ///
+    /// With [`Compression::Auto`], the algorithm will be detected from the
+    /// `Content-Encoding` of the object, and content without a supported
+    /// encoding will be returned as is.
+    ///
+    /// # Notes
+    ///
+    /// - Only available while the `compress` feature is enabled.
+    /// - The returned reader doesn't support seeking.
+    /// - `range` applies to the compressed content, please read the whole
+    ///   object to get a valid decompressed result.
+    #[cfg(feature = "compress")]
+    pub fn decompress(mut self, v: Compression) -> Self {
+        self.0 = self.0.map_args(|args| args.with_decompress(v));
+        self
+    }
+
+    /// Set the progress callback for this operation.
+    ///
+    /// The callback will be called with `(bytes_read, total)` every time a non-empty
+    /// chunk arrives. `total` is the size of the given range, or `None` if the range
+    /// is open-ended.
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        let progress = ProgressCallback::new(f);
+        self.0 = self.0.map_args(|args| args.with_progress(progress));
+        self
+    }
+
+    /// Set the transform which will be applied to the content after it has been
+    /// read, for example, to decrypt content that was encrypted on client side.
+    ///
+    /// # Notes
+    ///
+    /// - The transform is applied before `decompress`.
+    /// - The returned reader doesn't support seeking.
+    /// - The full content must be read, [`ErrorKind::InvalidInput`] will be returned
+    ///   if `range` is set.
+    pub fn transform(mut self, v: Box<dyn Transform>) -> Self {
+        let transform = TransformSlot::new(v);
+        self.0 = self.0.map_args(|args| args.with_transform(transform));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    ///
+    /// The timeout only covers creating the reader, reading from the returned
+    /// [`Reader`] is not limited by it.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureReader {
+    type Output = Result<Reader>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::write_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureWrite(pub(crate) OperatorFuture<(OpWrite, Bytes), Metadata>);
+
+impl FutureWrite {
+    /// Set the append mode of op.
+    ///
+    /// If the append mode is set, the data will be appended to the end of the file.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support append.
+    pub fn append(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_append(v), bs));
+        self
+    }
+
+    /// Set the buffer size of op.
+    ///
+    /// If buffer size is set, the data will be buffered by the underlying writer.
+    ///
+    /// ## NOTE
+    ///
+    /// Service could have their own minimum buffer size while perform write operations like
+    /// multipart uploads. So the buffer size may be larger than the given buffer size.
+    pub fn buffer(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_buffer(v), bs));
+        self
+    }
+
+    /// Set the maximum concurrent part uploads of op.
+    ///
+    /// If concurrent is set to > 1, the input bytes will be split into parts and at most
+    /// `v` parts will be uploaded at the same time before close.
+    ///
+    /// - If buffer is set, the part size follows the buffer size set by [`FutureWrite::buffer`].
+    /// - If buffer is not set, the input will be split into `v` parts evenly, which will be
+    ///   adjusted to meet the part size limitation of services.
+    ///
+    /// ## NOTE
+    ///
+    /// Concurrent only takes effect on services that write via multipart uploads like s3,
+    /// other services will write the content sequentially.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_concurrent(v), bs));
+        self
+    }
+
+    /// Set the content type of option
+    pub fn content_type(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_content_type(v), bs));
+        self
+    }
+
+    /// Set the content disposition of option
+    pub fn content_disposition(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_content_disposition(v), bs));
+        self
+    }
+
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_content_encoding(v), bs));
+        self
+    }
+
+    /// Set the content md5 of option
+    ///
+    /// The content md5 should be the base64 encoded md5 digest of the whole content,
+    /// it's the caller's responsibility to compute it correctly. Services that support
+    /// it will reject the corrupted upload with [`ErrorKind::ChecksumMismatch`].
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support content md5 will ignore this hint.
+    /// [`ErrorKind::Unsupported`] will be returned if the content is too large to be
+    /// uploaded in one request.
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_content_md5(v), bs));
+        self
+    }
+
+    /// Set the content type of option
+    pub fn cache_control(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_cache_control(v), bs));
+        self
+    }
+
+    /// Set the storage class of option
+    ///
+    /// The storage class will be passed to services as is, for example,
+    /// `STANDARD_IA` for s3 and `NEARLINE` for gcs.
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support storage class will ignore this hint.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_storage_class(v), bs));
+        self
+    }
+
+    /// Set the expires of option
+    ///
+    /// The object will be sent with `Expires` header set to the given time, and
+    /// it could be read back via [`Metadata::expires`].
+    ///
+    /// ## NOTE
+    ///
+    /// - `Expires` is an HTTP caching header that tells clients when the cached content
+    ///   becomes stale. It's NOT a TTL: services like `s3` won't delete the object or
+    ///   reject reads after that time, please use lifecycle rules of the service instead.
+    /// - HTTP date only has second precision, so the given time will be truncated to seconds.
+    /// - Service will return `Unsupported` if it doesn't support expires, unless
+    ///   `allow_unsupported` is set.
+    pub fn expires(mut self, v: SystemTime) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_expires(v.into()), bs));
+        self
+    }
+
+    /// Set the allow_unsupported of option
+    ///
+    /// If set, the following options will be ignored instead of returning
+    /// `Unsupported` error if the service doesn't support them:
+    ///
+    /// - [`expires`](Self::expires)
+    ///
+    /// Other options are not affected by this flag.
+    pub fn allow_unsupported(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_allow_unsupported(v), bs));
+        self
+    }
+
+    /// Set the if not exists of option
+    ///
+    /// If set, the write will only succeed if the path doesn't exist yet, otherwise
+    /// [`ErrorKind::ConditionNotMatch`] will be returned. This is the same as sending
+    /// `If-None-Match: *` on services like s3.
+    ///
+    /// ## NOTE
+    ///
+    /// Service will return `Unsupported` if it doesn't support conditional write.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_if_not_exists(v), bs));
+        self
+    }
+
+    /// Compress the content with given compression before uploading.
+    ///
+    /// `Content-Encoding` will be set to the compression, so that it can be decompressed
+    /// by `read_with(path).decompress(Compression::Auto)` later.
+    ///
+    /// ## NOTE
+    ///
+    /// - Only available while the `compress` feature is enabled.
+    /// - [`Compression::Auto`] is not supported for writing.
+    /// - Returns [`ErrorKind::Unsupported`] if the service doesn't support
+    ///   `write_with_content_encoding`.
+    #[cfg(feature = "compress")]
+    pub fn compress(mut self, v: Compression) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_compress(v), bs));
+        self
+    }
+
+    /// Set the progress callback of op.
+    ///
+    /// The callback will be called with `(bytes_accepted, total)` every time a chunk
+    /// has been accepted by the underlying writer. `total` is the size of the input
+    /// bytes.
+    ///
+    /// ## NOTE
+    ///
+    /// The reported bytes are the bytes accepted by the writer, not the bytes uploaded
+    /// to the service. Accepted data could still be held in the buffer (like the one set
+    /// by `buffer` or required by multipart uploads) until more data comes or the writer
+    /// is closed, so the progress could reach `total` before the upload finishes. The
+    /// write is only complete after `close` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_with("path/to/file", vec![0; 4096])
+    ///     .on_progress(|accepted, total| println!("{accepted}/{total:?}"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        let progress = ProgressCallback::new(f);
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_progress(progress), bs));
+        self
+    }
+
+    /// Set the transform which will be applied to the content before it's written,
+    /// for example, to encrypt content on client side.
+    ///
+    /// # Notes
+    ///
+    /// - The transform is applied after `compress`.
+    /// - Content length will not be sent to services since the size of transformed
+    ///   content is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bytes::Bytes;
+    /// # use opendal::Operator;
+    /// # use opendal::Result;
+    /// # use opendal::Transform;
+    /// struct Xor(u8);
+    ///
+    /// impl Transform for Xor {
+    ///     fn transform(&mut self, chunk: &[u8]) -> Result<Bytes> {
+    ///         Ok(chunk.iter().map(|b| b ^ self.0).collect())
+    ///     }
+    /// }
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_with("path/to/file", vec![0; 4096])
+    ///     .transform(Box::new(Xor(0x42)))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transform(mut self, v: Box<dyn Transform>) -> Self {
+        let transform = TransformSlot::new(v);
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_transform(transform), bs));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureWrite {
+    type Output = Result<Metadata>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
     }
+}
 
-    /// Set the content type of option
-    pub fn cache_control(mut self, v: &str) -> Self {
+/// Future that generated by [`Operator::copy_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureCopy(pub(crate) OperatorFuture<(OpCopy, String), ()>);
+
+impl FutureCopy {
+    /// Set the If-None-Match for this operation.
+    ///
+    /// Use `*` to copy only if the destination doesn't exist, otherwise
+    /// [`ErrorKind::ConditionNotMatch`] will be returned.
+    pub fn if_none_match(mut self, v: &str) -> Self {
         self.0 = self
             .0
-            .map_args(|(args, bs)| (args.with_cache_control(v), bs));
+            .map_args(|(args, to)| (args.with_if_none_match(v), to));
+        self
+    }
+
+    /// Set the metadata directive for this operation.
+    ///
+    /// - [`MetadataDirective::Copy`]: copy metadata from the source.
+    /// - [`MetadataDirective::Replace`]: replace metadata with the given ones.
+    pub fn metadata_directive(mut self, v: MetadataDirective) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_metadata_directive(v), to));
+        self
+    }
+
+    /// Set the content type of the destination.
+    ///
+    /// Setting content type implies [`MetadataDirective::Replace`].
+    pub fn content_type(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_content_type(v), to));
+        self
+    }
+
+    /// Set whether to fallback to read and write if the service doesn't support copy.
+    ///
+    /// If enabled, the content will be streamed from `from` into `to` and the content
+    /// type of `from` will be kept unless it's replaced.
+    ///
+    /// # Notes
+    ///
+    /// The fallback is quite different from native copy:
+    ///
+    /// - It's not atomic, `to` could be left incomplete if the fallback fails halfway.
+    /// - All bytes will be read from and written back to the service.
+    /// - Only `if_none_match("*")` is supported, and `from` and `to` can't be the same.
+    pub fn allow_fallback(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_allow_fallback(v), to));
+        self
+    }
+
+    /// Set whether to keep the last modified of `from` on `to`.
+    ///
+    /// By default, services will set the last modified of `to` to the time of copy.
+    ///
+    /// # Notes
+    ///
+    /// Only services with `copy_with_preserve_last_modified` capability support this,
+    /// others will return [`ErrorKind::Unsupported`] instead of ignoring it:
+    ///
+    /// - Supported: `fs`.
+    /// - Not supported: object storage services like `s3`, `gcs` and `azblob`, they
+    ///   always set the last modified by themselves and can't retain an arbitrary one.
+    ///
+    /// The copy fallback via read and write doesn't support this either.
+    pub fn preserve_last_modified(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_preserve_last_modified(v), to));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
         self
     }
 }
 
-impl Future for FutureWrite {
+impl Future for FutureCopy {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::rename_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureRename(pub(crate) OperatorFuture<(OpRename, String), ()>);
+
+impl FutureRename {
+    /// Set whether to fallback to copy and delete if the service doesn't support rename.
+    ///
+    /// If enabled, `from` will be copied to `to` via service's native copy, and then
+    /// `from` will be deleted.
+    ///
+    /// # Notes
+    ///
+    /// The fallback is quite different from native rename:
+    ///
+    /// - It's not atomic, both `from` and `to` could be visible during the rename.
+    /// - If deleting `from` fails, `to` will be deleted to clean up the copy and the
+    ///   error will be returned. `to` could still be left if the clean up fails too.
+    /// - Only files are supported, and the service must support `copy` and `delete`.
+    pub fn allow_fallback(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_allow_fallback(v), to));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureRename {
     type Output = Result<()>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -520,6 +1784,69 @@ impl FutureWriter {
         self
     }
 
+    /// Set the maximum concurrent part uploads of op.
+    ///
+    /// If concurrent is set to > 1, at most `v` parts will be uploaded at the same time,
+    /// and the part size follows the buffer size set by [`FutureWriter::buffer`].
+    /// `Writer::close` will wait for all in-flight parts before completing the upload.
+    ///
+    /// ## NOTE
+    ///
+    /// Concurrent only takes effect on services that write via multipart uploads like s3,
+    /// it will be ignored by other services.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+
+    /// Set the flush interval of op.
+    ///
+    /// If flush interval is set, data buffered by [`FutureWriter::buffer`] will be flushed
+    /// to the service on the next write once `v` has passed since the last flush, even if
+    /// the buffer is not full yet. It's useful for log-appender like workloads that write
+    /// continuously.
+    ///
+    /// ## NOTE
+    ///
+    /// This is NOT a timer: OpenDAL doesn't spawn background tasks, so the flush only
+    /// happens on the next write after `v`. Data buffered by the last write will stay in
+    /// the buffer until another write comes or `Writer::close` is called.
+    ///
+    /// The durability of flushed data depends on the service:
+    ///
+    /// - Services that support append (like `fs`, `azblob` and `oss`) with
+    ///   [`FutureWriter::append`] set: flushed data will be appended to the file
+    ///   and visible to readers immediately.
+    /// - Services that write via multipart uploads without aligned parts (like `s3`):
+    ///   flushed data will be uploaded as a part, but it won't be visible until the
+    ///   writer is closed. Buffer smaller than the minimum part size won't be flushed
+    ///   early.
+    /// - Other services, including the ones that require aligned parts (like `gcs`):
+    ///   flush interval will be ignored.
+    ///
+    /// Flush interval takes no effect if buffer is not set since there is nothing buffered.
+    pub fn flush_interval(mut self, v: Duration) -> Self {
+        self.0 = self.0.map_args(|args| args.with_flush_interval(v));
+        self
+    }
+
+    /// Set the content length of op.
+    ///
+    /// If the content length is set, the writer will make sure that exactly `v` bytes
+    /// are written:
+    ///
+    /// - Writing more bytes than `v` will return [`ErrorKind::ContentTruncated`].
+    /// - Closing the writer before `v` bytes are written will return [`ErrorKind::ContentIncomplete`].
+    ///
+    /// ## NOTE
+    ///
+    /// If buffer is not set and `v` is smaller than the minimum part size of the service,
+    /// the content will be buffered and uploaded in one request instead of multipart uploads.
+    pub fn content_length(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_length(v));
+        self
+    }
+
     /// Set the content type of option
     pub fn content_type(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_content_type(v));
@@ -532,35 +1859,373 @@ impl FutureWriter {
         self
     }
 
-    /// Set the content type of option
-    pub fn cache_control(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|args| args.with_cache_control(v));
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_encoding(v));
+        self
+    }
+
+    /// Set the content md5 of option
+    ///
+    /// The content md5 should be the base64 encoded md5 digest of the whole content,
+    /// it's the caller's responsibility to compute it correctly. Services that support
+    /// it will reject the corrupted upload with [`ErrorKind::ChecksumMismatch`].
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support content md5 will ignore this hint.
+    /// Content md5 only takes effect if the content is uploaded in one request,
+    /// [`ErrorKind::Unsupported`] will be returned if the content has to be uploaded
+    /// in multipart or the upload is resumed.
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_md5(v));
+        self
+    }
+
+    /// Set the content type of option
+    pub fn cache_control(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_cache_control(v));
+        self
+    }
+
+    /// Set the storage class of option
+    ///
+    /// The storage class will be passed to services as is, for example,
+    /// `STANDARD_IA` for s3 and `NEARLINE` for gcs.
+    ///
+    /// ## NOTE
+    ///
+    /// Services that don't support storage class will ignore this hint.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_storage_class(v));
+        self
+    }
+
+    /// Set the expires of option
+    ///
+    /// The object will be sent with `Expires` header set to the given time, and
+    /// it could be read back via [`Metadata::expires`].
+    ///
+    /// ## NOTE
+    ///
+    /// - `Expires` is an HTTP caching header that tells clients when the cached content
+    ///   becomes stale. It's NOT a TTL: services like `s3` won't delete the object or
+    ///   reject reads after that time, please use lifecycle rules of the service instead.
+    /// - HTTP date only has second precision, so the given time will be truncated to seconds.
+    /// - Service will return `Unsupported` if it doesn't support expires, unless
+    ///   `allow_unsupported` is set.
+    pub fn expires(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_expires(v.into()));
+        self
+    }
+
+    /// Set the allow_unsupported of option
+    ///
+    /// If set, the following options will be ignored instead of returning
+    /// `Unsupported` error if the service doesn't support them:
+    ///
+    /// - [`expires`](Self::expires)
+    ///
+    /// Other options are not affected by this flag.
+    pub fn allow_unsupported(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_allow_unsupported(v));
+        self
+    }
+
+    /// Set the if not exists of option
+    ///
+    /// If set, the write will only succeed if the path doesn't exist yet, otherwise
+    /// [`ErrorKind::ConditionNotMatch`] will be returned. This is the same as sending
+    /// `If-None-Match: *` on services like s3.
+    ///
+    /// ## NOTE
+    ///
+    /// Service will return `Unsupported` if it doesn't support conditional write.
+    /// For multipart uploads, the condition is checked while closing the writer, so
+    /// parts could have been uploaded before the conflict is detected.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_not_exists(v));
+        self
+    }
+
+    /// Set the If-Match of option
+    ///
+    /// If set, the write will only succeed if the etag of existing path matches `v`,
+    /// otherwise [`ErrorKind::ConditionNotMatch`] will be returned.
+    ///
+    /// ## NOTE
+    ///
+    /// - Service will return `Unsupported` if it doesn't support write with if match.
+    /// - Can't be used together with `if_not_exists`.
+    /// - For multipart uploads, the condition is checked while closing the writer, so
+    ///   parts could have been uploaded before the conflict is detected. Please call
+    ///   [`Writer::abort`] to clean them up if `close` returns `ConditionNotMatch`.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_match(v));
+        self
+    }
+
+    /// Set the If-None-Match of option
+    ///
+    /// If set, the write will only succeed if the etag of existing path doesn't
+    /// match `v`, otherwise [`ErrorKind::ConditionNotMatch`] will be returned. Use `*`
+    /// to only create the path if it doesn't exist.
+    ///
+    /// ## NOTE
+    ///
+    /// - Service will return `Unsupported` if it doesn't support write with if none match.
+    /// - Can't be used together with `if_not_exists`.
+    /// - For multipart uploads, the condition is checked while closing the writer, so
+    ///   parts could have been uploaded before the conflict is detected. Please call
+    ///   [`Writer::abort`] to clean them up if `close` returns `ConditionNotMatch`.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_none_match(v));
+        self
+    }
+
+    /// Resume the multipart upload with given [`ResumeToken`].
+    ///
+    /// The token is returned by [`Writer::resume_token`] of the previous writer.
+    /// Data written to the resumed writer will be uploaded as the parts after the
+    /// completed ones, so it should start at [`ResumeToken::uploaded_bytes`].
+    ///
+    /// ## NOTE
+    ///
+    /// Service will return `Unsupported` if it doesn't support resuming a write.
+    /// Resuming can't be used along with `append`.
+    pub fn resume(mut self, token: ResumeToken) -> Self {
+        self.0 = self.0.map_args(|args| args.with_resume(token.0));
+        self
+    }
+
+    /// Verify every part of multipart upload by its content md5.
+    ///
+    /// If enabled, the writer will compute the `Content-MD5` of every part and send
+    /// it along with the part, so that services can reject the corrupted part with
+    /// [`ErrorKind::ChecksumMismatch`] immediately instead of failing at completion.
+    ///
+    /// ## NOTE
+    ///
+    /// - Service will return `Unsupported` if it doesn't support verifying parts.
+    /// - Computing md5 costs extra CPU, so it's disabled by default.
+    /// - Content uploaded in one request is not affected, please use `content_md5` instead.
+    pub fn verify_parts(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify_parts(v));
+        self
+    }
+
+    /// Compress the content with given compression before uploading.
+    ///
+    /// `Content-Encoding` will be set to the compression, so that it can be decompressed
+    /// by `read_with(path).decompress(Compression::Auto)` later.
+    ///
+    /// ## NOTE
+    ///
+    /// - Only available while the `compress` feature is enabled.
+    /// - [`Compression::Auto`] is not supported for writing.
+    /// - Returns [`ErrorKind::Unsupported`] if the service doesn't support
+    ///   `write_with_content_encoding`.
+    /// - Content will be compressed in chunks while writing, and the remaining
+    ///   compressed data will be flushed while closing.
+    /// - The size of compressed content is unknown before closing, so `content_length`
+    ///   will be used as the size of uncompressed content for progress only, and
+    ///   won't be sent to services or checked.
+    #[cfg(feature = "compress")]
+    pub fn compress(mut self, v: Compression) -> Self {
+        self.0 = self.0.map_args(|args| args.with_compress(v));
+        self
+    }
+
+    /// Set the progress callback of op.
+    ///
+    /// The callback will be called with `(bytes_accepted, total)` every time a chunk
+    /// has been accepted by the underlying writer. `total` is the content length set by
+    /// [`FutureWriter::content_length`], or `None` if it's unknown.
+    ///
+    /// ## NOTE
+    ///
+    /// The reported bytes are the bytes accepted by the writer, not the bytes uploaded
+    /// to the service. Accepted data could still be held in the buffer (like the one set
+    /// by `buffer` or required by multipart uploads) until more data comes or the writer
+    /// is closed, so the progress could reach `total` before the upload finishes. The
+    /// write is only complete after `close` returns.
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        let progress = ProgressCallback::new(f);
+        self.0 = self.0.map_args(|args| args.with_progress(progress));
+        self
+    }
+
+    /// Set the transform which will be applied to the content before it's written,
+    /// for example, to encrypt content on client side.
+    ///
+    /// # Notes
+    ///
+    /// - The transform is applied after `compress`.
+    /// - Content length will not be sent to services since the size of transformed
+    ///   content is unknown.
+    pub fn transform(mut self, v: Box<dyn Transform>) -> Self {
+        let transform = TransformSlot::new(v);
+        self.0 = self.0.map_args(|args| args.with_transform(transform));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    ///
+    /// The timeout only covers creating the writer, writing to the returned
+    /// [`Writer`] is not limited by it.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureWriter {
+    type Output = Result<Writer>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::delete_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureDelete(pub(crate) OperatorFuture<OpDelete, ()>);
+
+impl FutureDelete {
+    /// Change the version of this delete operation.
+    pub fn version(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_version(v));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    ///
+    /// The object will only be deleted if its etag matches, otherwise
+    /// `ConditionNotMatch` will be returned. Services that don't support
+    /// conditional delete will return `Unsupported`.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_match(v));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
+}
+
+impl Future for FutureDelete {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Args for [`FutureCheck`].
+///
+/// Check is not an operation of services, so the operator that created this future
+/// is carried to keep options like `limit` and `default_metakey`.
+pub(crate) struct CheckArgs {
+    pub(crate) op: Operator,
+    pub(crate) round_trip: bool,
+}
+
+/// Future that generated by [`Operator::check_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureCheck(pub(crate) OperatorFuture<CheckArgs, ()>);
+
+impl FutureCheck {
+    /// Set whether to check with a write, read and delete round trip.
+    ///
+    /// If enabled, check will write a temporary file under the root, read it back and
+    /// delete it at last. The temporary file will be deleted even if write or read failed.
+    ///
+    /// The step that failed will be reported by the `check` context of returned error.
+    ///
+    /// Default to `false`, only a `list` request will be sent.
+    pub fn round_trip(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| CheckArgs {
+            round_trip: v,
+            ..args
+        });
+        self
+    }
+}
+
+impl Future for FutureCheck {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::remove_all_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureRemoveAll(pub(crate) OperatorFuture<OpRemoveAll, Vec<String>>);
+
+impl FutureRemoveAll {
+    /// Only list the paths that would be removed without removing them.
+    ///
+    /// The paths are collected via the same traversal as the real removal,
+    /// including the given path itself.
+    pub fn dry_run(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_dry_run(v));
         self
     }
 }
 
-impl Future for FutureWriter {
-    type Output = Result<Writer>;
+impl Future for FutureRemoveAll {
+    type Output = Result<Vec<String>>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         self.0.poll_unpin(cx)
     }
 }
 
-/// Future that generated by [`Operator::delete_with`].
+/// Future that generated by [`Operator::restore_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FutureDelete(pub(crate) OperatorFuture<OpDelete, ()>);
+pub struct FutureRestore(pub(crate) OperatorFuture<OpRestore, ()>);
 
-impl FutureDelete {
-    /// Change the version of this delete operation.
+impl FutureRestore {
+    /// Set the days to keep the restored copy.
+    ///
+    /// Services will use their own default value if not set, for example,
+    /// `1` day for s3.
+    pub fn days(mut self, v: u32) -> Self {
+        self.0 = self.0.map_args(|args| args.with_days(v));
+        self
+    }
+
+    /// Set the tier of this restore operation.
+    ///
+    /// The tier will be passed to services as is, for example, `Expedited`,
+    /// `Standard` or `Bulk` for s3.
+    pub fn tier(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_tier(v));
+        self
+    }
+
+    /// Change the version of this restore operation.
     pub fn version(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
 }
 
-impl Future for FutureDelete {
+impl Future for FutureRestore {
     type Output = Result<()>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -583,6 +2248,109 @@ impl FutureList {
         self
     }
 
+    /// The limit_total is used to cap the total entries returned by this list.
+    ///
+    /// Different from `limit` which only controls the page size of every request,
+    /// lister will stop fetching more pages once `limit_total` entries have been
+    /// returned. It works along with `recursive` and `start_after`.
+    pub fn limit_total(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit_total(v));
+        self
+    }
+
+    /// The min_size is used to only return entries whose content length is larger
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// The max_size is used to only return entries whose content length is smaller
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// The modified_after is used to only return entries whose last modified is
+    /// after `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_after(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_after(v.into()));
+        self
+    }
+
+    /// The modified_before is used to only return entries whose last modified is
+    /// before `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_before(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_before(v.into()));
+        self
+    }
+
+    /// The only_files is used to only return file entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_dirs`.
+    pub fn only_files(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_files(v));
+        self
+    }
+
+    /// The only_dirs is used to only return dir entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_files`.
+    ///
+    /// # Notes
+    ///
+    /// Services like s3 don't return dir entries while listing recursively,
+    /// dirs will be derived from the parent paths of listed files instead.
+    pub fn only_dirs(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_dirs(v));
+        self
+    }
+
+    /// The sorted is used to control whether entries should be returned in sorted path order.
+    ///
+    /// Default to `false`, entries are returned in the order of underlying services.
+    ///
+    /// ## NOTE
+    ///
+    /// To guarantee a globally sorted result (especially with `recursive(true)`), all
+    /// entries under the path will be buffered in memory and sorted before returning.
+    /// Nothing will be returned until the whole listing has been fetched, and the memory
+    /// usage is `O(n)` of the number of entries under the path.
+    ///
+    /// Please keep `sorted(false)` for large prefixes and consume the entries as they
+    /// are returned by services instead.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -590,6 +2358,24 @@ impl FutureList {
         self
     }
 
+    /// The start_before is used to only return entries whose path is lexicographically
+    /// strictly before the specified key.
+    ///
+    /// # Notes
+    ///
+    /// No service supports listing before a key natively, so all entries under the
+    /// path will be fetched and filtered at client side, even after the key has been
+    /// passed. With `sorted(true)`, the entries before the key will be buffered in
+    /// memory for sorting.
+    ///
+    /// Entries are still returned from the beginning: combined with `sorted(true)` and
+    /// `limit_total(n)`, the first `n` entries before the key will be returned in
+    /// ascending order, not the `n` entries right before the key.
+    pub fn start_before(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_start_before(v));
+        self
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -601,6 +2387,44 @@ impl FutureList {
         self
     }
 
+    /// Set the delimiter used to group entries into common prefixes.
+    ///
+    /// - `None` means a fully flat listing without any grouping, the same as `recursive(true)`.
+    /// - `Some('/')` is the default behavior, the same as `recursive(false)`.
+    /// - `Some(c)` groups entries by `c`, common prefixes will be returned as entries
+    ///   whose path ends with `c`. They are not dirs, so their mode is `EntryMode::Unknown`
+    ///   unless `c` is `/`.
+    ///
+    /// # Notes
+    ///
+    /// Services that can't customize the delimiter will return [`ErrorKind::Unsupported`]
+    /// for delimiters other than `/`. This option overrides `recursive` set before.
+    pub fn delimiter(mut self, v: Option<char>) -> Self {
+        self.0 = self.0.map_args(|args| match v {
+            None => args.with_recursive(true).without_delimiter(),
+            Some('/') => args.with_recursive(false).without_delimiter(),
+            Some(c) => args.with_recursive(false).with_delimiter(c),
+        });
+        self
+    }
+
+    /// The glob pattern is used to filter entries before they are returned.
+    ///
+    /// The pattern is matched against the path relative to the listing path, and
+    /// supports `*`, `?` and `**`. For example, `**/*.parquet` matches all parquet
+    /// files under the listing path.
+    ///
+    /// ## NOTE
+    ///
+    /// The filtering happens at client side, entries are still listed from services.
+    /// Only entries returned by the underlying lister can be matched, so patterns
+    /// with `/` or `**` should be used along with `recursive(true)` to match nested
+    /// entries.
+    pub fn pattern(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_pattern(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -608,11 +2432,36 @@ impl FutureList {
     /// - `Some(v)` means exist.
     /// - `None` means services doesn't have this meta.
     ///
-    /// The default metakey is `Metakey::Mode`.
+    /// The default metakey is `Metakey::Mode`, which could be changed by `with_default_metakey`
+    /// on operator. The metakey set here will override the default one.
     pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
+
+    /// Versions is used to control whether the list operation should return all versions of
+    /// objects.
+    ///
+    /// - If `false`, only the current version of objects will be listed.
+    /// - If `true`, all versions of objects will be listed, including delete markers which can
+    ///   be told apart via [`Metadata::is_deleted`].
+    ///
+    /// Service will return `Unsupported` if it doesn't support list with versions.
+    ///
+    /// Default to `false`.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
 }
 
 impl Future for FutureList {
@@ -638,6 +2487,109 @@ impl FutureLister {
         self
     }
 
+    /// The limit_total is used to cap the total entries returned by this list.
+    ///
+    /// Different from `limit` which only controls the page size of every request,
+    /// lister will stop fetching more pages once `limit_total` entries have been
+    /// returned. It works along with `recursive` and `start_after`.
+    pub fn limit_total(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit_total(v));
+        self
+    }
+
+    /// The min_size is used to only return entries whose content length is larger
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// The max_size is used to only return entries whose content length is smaller
+    /// than or equal to `v` bytes.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, so `Metakey::ContentLength` will be
+    /// requested automatically and entries might be stat-ed if services don't
+    /// return content length while listing. Dirs don't have a size and will always
+    /// be returned. `limit_total` counts entries after filtering.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// The modified_after is used to only return entries whose last modified is
+    /// after `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_after(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_after(v.into()));
+        self
+    }
+
+    /// The modified_before is used to only return entries whose last modified is
+    /// before `v`.
+    ///
+    /// # Notes
+    ///
+    /// Entries are filtered at client side, lister will stat entries if services don't
+    /// return last modified while listing. Dirs and entries without last modified will
+    /// always be returned. `limit_total` counts entries after filtering.
+    pub fn modified_before(mut self, v: SystemTime) -> Self {
+        self.0 = self.0.map_args(|args| args.with_modified_before(v.into()));
+        self
+    }
+
+    /// The only_files is used to only return file entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_dirs`.
+    pub fn only_files(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_files(v));
+        self
+    }
+
+    /// The only_dirs is used to only return dir entries.
+    ///
+    /// Default to `false`. Can't be used together with `only_files`.
+    ///
+    /// # Notes
+    ///
+    /// Services like s3 don't return dir entries while listing recursively,
+    /// dirs will be derived from the parent paths of listed files instead.
+    pub fn only_dirs(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_only_dirs(v));
+        self
+    }
+
+    /// The sorted is used to control whether entries should be returned in sorted path order.
+    ///
+    /// Default to `false`, entries are returned in the order of underlying services.
+    ///
+    /// ## NOTE
+    ///
+    /// To guarantee a globally sorted result (especially with `recursive(true)`), all
+    /// entries under the path will be buffered in memory and sorted before returning.
+    /// Nothing will be returned until the whole listing has been fetched, and the memory
+    /// usage is `O(n)` of the number of entries under the path.
+    ///
+    /// Please keep `sorted(false)` for large prefixes and consume the entries as they
+    /// are returned by services instead.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -645,6 +2597,24 @@ impl FutureLister {
         self
     }
 
+    /// The start_before is used to only return entries whose path is lexicographically
+    /// strictly before the specified key.
+    ///
+    /// # Notes
+    ///
+    /// No service supports listing before a key natively, so all entries under the
+    /// path will be fetched and filtered at client side, even after the key has been
+    /// passed. With `sorted(true)`, the entries before the key will be buffered in
+    /// memory for sorting.
+    ///
+    /// Entries are still returned from the beginning: combined with `sorted(true)` and
+    /// `limit_total(n)`, the first `n` entries before the key will be returned in
+    /// ascending order, not the `n` entries right before the key.
+    pub fn start_before(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_start_before(v));
+        self
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -656,6 +2626,44 @@ impl FutureLister {
         self
     }
 
+    /// Set the delimiter used to group entries into common prefixes.
+    ///
+    /// - `None` means a fully flat listing without any grouping, the same as `recursive(true)`.
+    /// - `Some('/')` is the default behavior, the same as `recursive(false)`.
+    /// - `Some(c)` groups entries by `c`, common prefixes will be returned as entries
+    ///   whose path ends with `c`. They are not dirs, so their mode is `EntryMode::Unknown`
+    ///   unless `c` is `/`.
+    ///
+    /// # Notes
+    ///
+    /// Services that can't customize the delimiter will return [`ErrorKind::Unsupported`]
+    /// for delimiters other than `/`. This option overrides `recursive` set before.
+    pub fn delimiter(mut self, v: Option<char>) -> Self {
+        self.0 = self.0.map_args(|args| match v {
+            None => args.with_recursive(true).without_delimiter(),
+            Some('/') => args.with_recursive(false).without_delimiter(),
+            Some(c) => args.with_recursive(false).with_delimiter(c),
+        });
+        self
+    }
+
+    /// The glob pattern is used to filter entries before they are returned.
+    ///
+    /// The pattern is matched against the path relative to the listing path, and
+    /// supports `*`, `?` and `**`. For example, `**/*.parquet` matches all parquet
+    /// files under the listing path.
+    ///
+    /// ## NOTE
+    ///
+    /// The filtering happens at client side, entries are still listed from services.
+    /// Only entries returned by the underlying lister can be matched, so patterns
+    /// with `/` or `**` should be used along with `recursive(true)` to match nested
+    /// entries.
+    pub fn pattern(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_pattern(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -663,12 +2671,28 @@ impl FutureLister {
     /// - `Some(v)` means exist.
     /// - `None` means services doesn't have this meta.
     ///
-    /// The default metakey is `Metakey::Mode`.
+    /// The default metakey is `Metakey::Mode`, which could be changed by `with_default_metakey`
+    /// on operator. The metakey set here will override the default one.
     pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
 
+    /// Versions is used to control whether the list operation should return all versions of
+    /// objects.
+    ///
+    /// - If `false`, only the current version of objects will be listed.
+    /// - If `true`, all versions of objects will be listed, including delete markers which can
+    ///   be told apart via [`Metadata::is_deleted`].
+    ///
+    /// Service will return `Unsupported` if it doesn't support list with versions.
+    ///
+    /// Default to `false`.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
     /// Concurrent is used to control the number of concurrent stat requests.
     ///
     /// If concurrent is set to <=1, the lister will perform stat requests sequentially.
@@ -678,6 +2702,18 @@ impl FutureLister {
         self.0 = self.0.map_args(|args| args.with_concurrent(v));
         self
     }
+
+    /// Set the timeout of this operation.
+    ///
+    /// The in-flight request will be cancelled and [`ErrorKind::Timeout`] will be
+    /// returned if the operation doesn't finish in time.
+    ///
+    /// The timeout only covers creating the lister, listing via the returned
+    /// [`Lister`] is not limited by it.
+    pub fn timeout(mut self, v: Duration) -> Self {
+        self.0 = self.0.with_timeout(v);
+        self
+    }
 }
 
 impl Future for FutureLister {
@@ -687,3 +2723,178 @@ impl Future for FutureLister {
         self.0.poll_unpin(cx)
     }
 }
+
+/// Future that generated by [`Operator::walk_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureWalk(pub(crate) OperatorFuture<OpList, Walker>);
+
+impl FutureWalk {
+    /// Metakey is used to control which meta should be returned.
+    ///
+    /// Walker will make sure the result for specified meta is **known**:
+    ///
+    /// - `Some(v)` means exist.
+    /// - `None` means services doesn't have this meta.
+    ///
+    /// The default metakey is `Metakey::Mode`, which could be changed by `with_default_metakey`
+    /// on operator. The metakey set here will override the default one.
+    pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_metakey(v));
+        self
+    }
+
+    /// Concurrent is used to control the number of concurrent stat requests.
+    ///
+    /// If concurrent is set to <=1, the walker will perform stat requests sequentially.
+    ///
+    /// The default concurrent is 1.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+}
+
+impl Future for FutureWalk {
+    type Output = Result<Walker>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// SlowService will sleep for `delay` before returning.
+    #[derive(Debug)]
+    struct SlowService {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl Accessor for SlowService {
+        type Reader = oio::Reader;
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Lister = ();
+        type BlockingLister = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().stat = true;
+            am.full_capability_mut().read = true;
+            am.full_capability_mut().delete = true;
+
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            tokio::time::sleep(self.delay).await;
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(13),
+            ))
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            tokio::time::sleep(self.delay).await;
+            let bs = args.range().apply_on_bytes(Bytes::from("Hello, World!"));
+            Ok((RpRead::new(), Box::new(oio::Cursor::from(bs))))
+        }
+
+        async fn delete(&self, _: &str, _: OpDelete) -> Result<RpDelete> {
+            tokio::time::sleep(self.delay).await;
+            Ok(RpDelete::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout_slow_operation() {
+        let op = Operator::from_inner(Arc::new(SlowService {
+            delay: Duration::from_secs(10),
+        }));
+        let timeout = Duration::from_millis(10);
+
+        let err = op.stat_with("test").timeout(timeout).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        assert!(err.is_temporary());
+
+        let err = op.read_with("test").timeout(timeout).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+
+        let err = op.delete_with("test").timeout(timeout).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_fast_operation() {
+        let op = Operator::from_inner(Arc::new(SlowService {
+            delay: Duration::ZERO,
+        }));
+        let timeout = Duration::from_secs(10);
+
+        let meta = op.stat_with("test").timeout(timeout).await.unwrap();
+        assert_eq!(meta.content_length(), 13);
+
+        let bs = op.read_with("test").timeout(timeout).await.unwrap();
+        assert_eq!(bs, b"Hello, World!");
+
+        op.delete_with("test").timeout(timeout).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_with_http_range() {
+        let op = Operator::from_inner(Arc::new(SlowService {
+            delay: Duration::ZERO,
+        }));
+
+        let bs = op.read_with("test").http_range("bytes=0-4").await.unwrap();
+        assert_eq!(bs, b"Hello");
+
+        let bs = op.read_with("test").http_range("bytes=7-").await.unwrap();
+        assert_eq!(bs, b"World!");
+
+        let bs = op.read_with("test").http_range("bytes=-6").await.unwrap();
+        assert_eq!(bs, b"World!");
+
+        for range in [
+            "bytes=4-0",
+            "bytes=-",
+            "bytes=-x",
+            "bytes=0-1,4-5",
+            "items=0-4",
+            "0-4",
+        ] {
+            let err = op.read_with("test").http_range(range).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput, "range: {range}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_ranges() {
+        let op = Operator::from_inner(Arc::new(SlowService {
+            delay: Duration::ZERO,
+        }));
+
+        let bss = op
+            .read_with("test")
+            .ranges(vec![0..5, 7..13])
+            .await
+            .unwrap();
+        assert_eq!(bss, vec![Bytes::from("Hello"), Bytes::from("World!")]);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let err = op
+            .read_with("test")
+            .ranges(vec![0..5, 20..10])
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RangeNotSatisfied);
+    }
+}
@@ -27,6 +27,9 @@ use futures::AsyncSeek;
 use futures::Stream;
 
 use crate::raw::*;
+use crate::types::compression::new_decompress_reader;
+use crate::types::transform::new_transform_reader;
+use crate::types::transform::take_read_transform;
 use crate::*;
 
 /// Reader is designed to read data from given path in an asynchronous
@@ -53,6 +56,10 @@ use crate::*;
 ///
 /// Besides, `Stream` **COULD** reduce an extra copy if underlying reader is
 /// stream based (like services s3, azure which based on HTTP).
+///
+/// `AsyncSeek` is lazy: seeking only updates the position, and a new ranged
+/// request will be sent on the next read. Seeking past the end is allowed like
+/// files, and the following reads will return `0`.
 pub struct Reader {
     inner: oio::Reader,
     seek_state: SeekState,
@@ -67,13 +74,120 @@ impl Reader {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
-        let (_, r) = acc.read(path, op).await?;
+        let transform = take_read_transform(&op)?;
+        // Empty range can't be sent to services, there is nothing to read.
+        if op.range().size() == Some(0) {
+            return Ok(Reader {
+                inner: Box::new(oio::Cursor::new()),
+                seek_state: SeekState::Init,
+            });
+        }
+        let verify_checksum = op.verify_checksum() && op.range().is_full();
+        let meta = if verify_checksum
+            || op.verify_length()
+            || op.decompress() == Some(Compression::Auto)
+        {
+            let mut stat_args = OpStat::new();
+            if let Some(v) = op.version() {
+                stat_args = stat_args.with_version(v);
+            }
+            Some(acc.stat(path, stat_args).await?.into_metadata())
+        } else {
+            None
+        };
+        let content_md5 = match &meta {
+            Some(meta) if verify_checksum => meta.content_md5().map(|v| v.to_string()),
+            _ => None,
+        };
+        // Validate the range while we already know the content length.
+        let range = match &meta {
+            Some(meta) => Some(op.range().validate(meta.content_length()).map_err(|err| {
+                err.with_operation("Reader::create")
+                    .with_context("path", path)
+            })?),
+            None => None,
+        };
+        let op = match range {
+            Some(range) if !op.range().is_full() => op.with_range(range),
+            _ => op,
+        };
+        let compression = match op.decompress() {
+            Some(Compression::Auto) => meta
+                .as_ref()
+                .and_then(|meta| meta.content_encoding())
+                .and_then(Compression::from_content_encoding),
+            v => v,
+        };
+
+        let buffer = op.buffer();
+        let progress = op.progress().cloned();
+        let total = op.range().size();
+        let (_, r) = acc.read(path, op.clone()).await?;
+        // Short reads can only be resumed if the range has been resolved against
+        // the content length.
+        let r: oio::Reader = match (range, &meta) {
+            (Some(range), Some(meta)) => Box::new(oio::ResumeReader::new(
+                acc.clone(),
+                path,
+                op,
+                r,
+                range,
+                meta.etag(),
+            )),
+            _ => r,
+        };
+        let r: oio::Reader = match content_md5 {
+            Some(md5) => Box::new(oio::ChecksumReader::new(r, &md5)),
+            None => r,
+        };
+        let r: oio::Reader = match progress {
+            Some(progress) => Box::new(oio::ProgressReader::new(r, progress, total)),
+            None => r,
+        };
+        let r = new_transform_reader(r, transform);
+        let r = new_decompress_reader(r, compression)?;
+        let r: oio::Reader = match buffer {
+            Some(cap) if cap > 0 => Box::new(oio::BufferReader::new(r, cap)),
+            _ => r,
+        };
 
         Ok(Reader {
             inner: r,
             seek_state: SeekState::Init,
         })
     }
+
+    /// Convert reader into a stream of `Bytes`.
+    ///
+    /// The returned stream yields the chunks from underlying reader directly
+    /// without extra copy. Unlike the `Stream` implemented by `Reader`, errors
+    /// are returned as [`Error`] so it can be composed with other opendal APIs.
+    ///
+    /// Users can use [`Operator::reader_with`] to specify the range to read.
+    /// Dropping the stream will cancel the in-flight request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let s = op
+    ///     .reader_with("path/to/file")
+    ///     .range(0..1024)
+    ///     .await?
+    ///     .into_bytes_stream();
+    /// let bs: Vec<Bytes> = s.try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_bytes_stream(self) -> impl Stream<Item = Result<Bytes>> + Unpin + Send {
+        let mut inner = self.inner;
+        futures::stream::poll_fn(move |cx| inner.poll_next(cx))
+    }
 }
 
 impl oio::Read for Reader {
@@ -190,7 +304,19 @@ impl BlockingReader {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
+        // Empty range can't be sent to services, there is nothing to read.
+        if op.range().size() == Some(0) {
+            return Ok(BlockingReader {
+                inner: Box::new(oio::Cursor::new()),
+            });
+        }
+
+        let buffer = op.buffer();
         let (_, r) = acc.blocking_read(path, op)?;
+        let r: oio::BlockingReader = match buffer {
+            Some(cap) if cap > 0 => Box::new(oio::BufferReader::new(r, cap)),
+            _ => r,
+        };
 
         Ok(BlockingReader { inner: r })
     }
@@ -240,14 +366,22 @@ impl Iterator for BlockingReader {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::TryStreamExt;
     use rand::rngs::ThreadRng;
     use rand::Rng;
     use rand::RngCore;
     use tokio::io::AsyncReadExt;
     use tokio::io::AsyncSeekExt;
 
+    use crate::raw::*;
     use crate::services;
-    use crate::Operator;
+    use crate::*;
 
     fn gen_random_bytes() -> Vec<u8> {
         let mut rng = ThreadRng::default();
@@ -306,4 +440,222 @@ mod tests {
             .expect("read to end must succeed");
         assert_eq!(buf, content);
     }
+
+    #[tokio::test]
+    async fn test_reader_into_bytes_stream() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        let content = vec![1; 10 * 1024 * 1024];
+        op.write(path, content.clone())
+            .await
+            .expect("write must succeed");
+
+        let s = op.reader(path).await.unwrap().into_bytes_stream();
+        let bs: Vec<Bytes> = s.try_collect().await.expect("collect must succeed");
+        let buf = bs.concat();
+
+        assert_eq!(buf, op.read(path).await.unwrap());
+    }
+
+    /// MockService stores content by version, the latest version is used if
+    /// version is not specified.
+    #[derive(Debug)]
+    struct MockService {
+        latest: String,
+        versions: HashMap<String, Bytes>,
+    }
+
+    impl MockService {
+        fn content(&self, version: Option<&str>) -> Result<Bytes> {
+            let version = version.unwrap_or(&self.latest);
+            self.versions
+                .get(version)
+                .cloned()
+                .ok_or_else(|| Error::new(ErrorKind::NotFound, "version not found"))
+        }
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Lister = oio::Lister;
+        type BlockingLister = oio::BlockingLister;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().read = true;
+            am.full_capability_mut().stat = true;
+
+            am
+        }
+
+        async fn stat(&self, _: &str, args: OpStat) -> Result<RpStat> {
+            let bs = self.content(args.version())?;
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+            ))
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            let bs = self.content(args.version())?;
+            Ok((RpRead::new(), Box::new(oio::Cursor::from(bs))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_version() {
+        let srv = MockService {
+            latest: "v2".to_string(),
+            versions: HashMap::from([
+                ("v1".to_string(), Bytes::from("version 1")),
+                ("v2".to_string(), Bytes::from("version 2")),
+            ]),
+        };
+        let op = Operator::from_inner(Arc::new(srv));
+        let path = "test_file";
+
+        assert_eq!(op.read(path).await.unwrap(), b"version 2");
+        assert_eq!(
+            op.read_with(path).version("v1").await.unwrap(),
+            b"version 1"
+        );
+
+        let mut reader = op.reader_with(path).version("v1").await.unwrap();
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert_eq!(buf, b"version 1");
+
+        let res = op.read_with(path).version("v3").await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::NotFound));
+    }
+
+    /// ShortReadService returns at most `chunk` bytes for every read, and never
+    /// returns the content after `available`.
+    #[derive(Debug)]
+    struct ShortReadService {
+        content: Bytes,
+        chunk: usize,
+        available: usize,
+        /// The `If-Match` of every read request.
+        if_matches: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl Accessor for ShortReadService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Lister = oio::Lister;
+        type BlockingLister = oio::BlockingLister;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().read = true;
+            am.full_capability_mut().read_with_range = true;
+            am.full_capability_mut().read_with_if_match = true;
+            am.full_capability_mut().stat = true;
+
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE)
+                    .with_content_length(self.content.len() as u64)
+                    .with_etag("\"etag\"".to_string()),
+            ))
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.if_matches
+                .lock()
+                .unwrap()
+                .push(args.if_match().map(|v| v.to_string()));
+            let bs = self.content.slice(..self.available);
+            let mut bs = args.range().apply_on_bytes(bs);
+            bs.truncate(self.chunk);
+            Ok((RpRead::new(), Box::new(oio::Cursor::from(bs))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_short_read() {
+        let content = Bytes::from("Hello, World!");
+        let srv = ShortReadService {
+            content: content.clone(),
+            chunk: 4,
+            available: content.len(),
+            if_matches: Mutex::default(),
+        };
+        let op = Operator::from_inner(Arc::new(srv));
+        let path = "test_file";
+
+        // The remaining range should be read again.
+        assert_eq!(op.read(path).await.unwrap(), content);
+        assert_eq!(
+            op.read_with(path)
+                .range(2..11)
+                .verify_length(true)
+                .await
+                .unwrap(),
+            content.slice(2..11)
+        );
+
+        let srv = ShortReadService {
+            content: content.clone(),
+            chunk: 4,
+            available: 6,
+            if_matches: Mutex::default(),
+        };
+        let op = Operator::from_inner(Arc::new(srv));
+
+        // Truncated content will be returned as is without verify_length.
+        assert_eq!(op.read(path).await.unwrap(), content.slice(..6));
+        let res = op.read_with(path).verify_length(true).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unexpected));
+    }
+
+    #[tokio::test]
+    async fn test_reader_with_short_read() {
+        let content = Bytes::from("Hello, World!");
+        let srv = Arc::new(ShortReadService {
+            content: content.clone(),
+            chunk: 4,
+            available: content.len(),
+            if_matches: Mutex::default(),
+        });
+        let op = Operator::from_inner(srv.clone());
+        let path = "test_file";
+
+        // The remaining range should be read again with etag pinned.
+        let r = op.reader_with(path).verify_length(true).await.unwrap();
+        let bs: Vec<Bytes> = r.into_bytes_stream().try_collect().await.unwrap();
+        assert_eq!(bs.concat(), content);
+        let if_matches = srv.if_matches.lock().unwrap().clone();
+        assert_eq!(if_matches.len(), 4);
+        assert_eq!(if_matches[0], None);
+        assert!(if_matches[1..]
+            .iter()
+            .all(|v| v.as_deref() == Some("\"etag\"")));
+
+        let srv = ShortReadService {
+            content: content.clone(),
+            chunk: 4,
+            available: 6,
+            if_matches: Mutex::default(),
+        };
+        let op = Operator::from_inner(Arc::new(srv));
+
+        let r = op.reader_with(path).verify_length(true).await.unwrap();
+        let res: Result<Vec<Bytes>> = r.into_bytes_stream().try_collect().await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unexpected));
+    }
 }
@@ -0,0 +1,45 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// RestoreStatus represents the restore status of an archived object.
+///
+/// Objects in archival tiers (like AWS S3 `GLACIER` or `DEEP_ARCHIVE`) can't
+/// be read directly, users need to restore them first.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RestoreStatus {
+    /// The object is not archived and can be read directly.
+    NotArchived,
+    /// The object is archived and has not been restored yet.
+    Archived,
+    /// The restore of this object is in progress.
+    InProgress,
+    /// The object has been restored and can be read until the given time.
+    Restored(DateTime<Utc>),
+}
+
+impl RestoreStatus {
+    /// Check if the object can be read directly.
+    pub fn is_readable(&self) -> bool {
+        matches!(
+            self,
+            RestoreStatus::NotArchived | RestoreStatus::Restored(_)
+        )
+    }
+}
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Transform is a hook to transform the content chunk by chunk while reading
+/// or writing, for example, to apply client side encryption.
+///
+/// - While reading, the transform is applied to the bytes downloaded from services.
+/// - While writing, the transform is applied to the bytes before they are uploaded.
+///
+/// Transform is stateful and will be used for only one read or write.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use opendal::Result;
+/// use opendal::Transform;
+///
+/// /// Xor every byte with the given key.
+/// struct Xor(u8);
+///
+/// impl Transform for Xor {
+///     fn transform(&mut self, chunk: &[u8]) -> Result<Bytes> {
+///         Ok(chunk.iter().map(|b| b ^ self.0).collect())
+///     }
+/// }
+/// ```
+pub trait Transform: Send + Sync + 'static {
+    /// Transform the given chunk.
+    ///
+    /// The returned bytes could be empty if the transform needs more input,
+    /// for example, a block cipher which is waiting for a full block.
+    fn transform(&mut self, chunk: &[u8]) -> Result<Bytes>;
+
+    /// Finish the transform and return the remaining bytes.
+    ///
+    /// This will be called exactly once after all chunks have been transformed.
+    fn finish(&mut self) -> Result<Bytes> {
+        Ok(Bytes::new())
+    }
+}
+
+/// Take the transform out of the read args.
+///
+/// Transform is stateful and must start from the beginning of the content, so
+/// reading a part of the content with transform will be rejected.
+pub(crate) fn take_read_transform(args: &OpRead) -> Result<Option<Box<dyn Transform>>> {
+    let transform = match args.transform() {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+
+    if !args.range().is_full() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "transform can only be applied while reading the full content",
+        )
+        .with_context("range", format!("{:?}", args.range())));
+    }
+
+    Ok(transform.take())
+}
+
+/// Wrap the reader with transform if needed.
+pub(crate) fn new_transform_reader(
+    r: oio::Reader,
+    transform: Option<Box<dyn Transform>>,
+) -> oio::Reader {
+    match transform {
+        Some(transform) => Box::new(oio::TransformReader::new(r, transform)),
+        None => r,
+    }
+}
+
+/// Prepare the write args for transform.
+///
+/// Returns the args to be sent to services and the transform to apply. Content
+/// length will be cleared since the size of transformed content is unknown.
+pub(crate) fn prepare_transform(args: OpWrite) -> (OpWrite, Option<Box<dyn Transform>>) {
+    match args.transform().and_then(|v| v.take()) {
+        Some(transform) => (args.without_content_length(), Some(transform)),
+        None => (args, None),
+    }
+}
+
+/// Wrap the writer with transform if needed.
+pub(crate) fn new_transform_writer(
+    w: oio::Writer,
+    transform: Option<Box<dyn Transform>>,
+) -> oio::Writer {
+    match transform {
+        Some(transform) => Box::new(oio::TransformWriter::new(w, transform)),
+        None => w,
+    }
+}
@@ -15,8 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::io;
 use std::pin::Pin;
+use std::str::FromStr;
 use std::task::Context;
 use std::task::Poll;
 
@@ -28,6 +31,10 @@ use crate::raw::oio::Write;
 use crate::raw::oio::WriteBuf;
 use crate::raw::oio::WriteExt;
 use crate::raw::*;
+use crate::types::compression::new_compress_writer;
+use crate::types::compression::prepare_compress;
+use crate::types::transform::new_transform_writer;
+use crate::types::transform::prepare_transform;
 use crate::*;
 
 /// Writer is designed to write data into given path in an asynchronous
@@ -84,7 +91,16 @@ impl Writer {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpWrite) -> Result<Self> {
+        let progress = op.progress().cloned();
+        let total = op.content_length();
+        let (op, compression) = prepare_compress(&acc.info(), op)?;
+        let (op, transform) = prepare_transform(op);
         let (_, w) = acc.write(path, op).await?;
+        let w = new_transform_writer(w, transform);
+        let mut w = new_compress_writer(w, compression)?;
+        if let Some(progress) = progress {
+            w = Box::new(oio::ProgressWriter::new(w, progress, total));
+        }
 
         Ok(Writer { inner: w })
     }
@@ -200,12 +216,133 @@ impl Writer {
 
     /// Close the writer and make sure all data have been committed.
     ///
+    /// Returns the metadata of the written file reported by the service, so
+    /// there is no need to `stat` it again.
+    ///
     /// ## Notes
     ///
     /// Close should only be called when the writer is not closed or
     /// aborted, otherwise an unexpected error could be returned.
-    pub async fn close(&mut self) -> Result<()> {
-        self.inner.close().await
+    ///
+    /// Only mode and `content_length` are guaranteed to be set in the returned
+    /// metadata, `content_length` will be the bytes written if the service
+    /// doesn't report it. Other fields like `etag`, `version` and
+    /// `last_modified` are gathered from the service's write response (the
+    /// complete response for multipart uploads), use [`Metadata::metakey`] to
+    /// check which fields are reported.
+    /// For writers with `append` enabled or resumed from a previous upload,
+    /// `content_length` is only set if the service reports it. While appending,
+    /// it's the total size of the file after appending, so the appended data
+    /// starts at `content_length - written`.
+    pub async fn close(&mut self) -> Result<Metadata> {
+        self.inner.close().await?;
+
+        Ok(self.inner.metadata())
+    }
+
+    /// Get the upload id of the in-progress multipart upload.
+    ///
+    /// Returns `None` if the writer is not based on multipart uploads or the
+    /// upload hasn't been started yet.
+    pub fn upload_id(&self) -> Option<String> {
+        self.inner.upload_state().map(|state| state.upload_id)
+    }
+
+    /// Get the [`ResumeToken`] of the in-progress multipart upload.
+    ///
+    /// The token can be kept to resume the upload via
+    /// `writer_with(path).resume(token)` after a crash. Returns `None` if the
+    /// writer is not based on multipart uploads or the upload hasn't been
+    /// started yet.
+    ///
+    /// Only the parts numbered from 0 without gaps are included, parts that
+    /// are still uploading in background are not. So the resumed upload should
+    /// start with the data at [`ResumeToken::uploaded_bytes`].
+    pub fn resume_token(&self) -> Option<ResumeToken> {
+        self.inner.upload_state().map(ResumeToken)
+    }
+}
+
+/// ResumeToken is the state of an in-progress multipart upload returned by
+/// [`Writer::resume_token`], which can be used to resume the upload via
+/// [`FutureWriter::resume`].
+///
+/// The token can be persisted via its string representation:
+///
+/// ```
+/// # use opendal::Result;
+/// use opendal::ResumeToken;
+///
+/// # fn test(token: ResumeToken) -> Result<()> {
+/// let s = token.to_string();
+/// let token: ResumeToken = s.parse()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumeToken(pub(crate) oio::MultipartUploadState);
+
+impl ResumeToken {
+    /// Get the upload id of the multipart upload.
+    pub fn upload_id(&self) -> &str {
+        &self.0.upload_id
+    }
+
+    /// Get the size of data that has been uploaded.
+    ///
+    /// The resumed writer should start with the data at this offset.
+    pub fn uploaded_bytes(&self) -> u64 {
+        self.0.uploaded_bytes
+    }
+}
+
+/// The string representation of ResumeToken is line based:
+///
+/// ```text
+/// <uploaded_bytes>
+/// <upload_id>
+/// <part_number> <etag>
+/// ...
+/// ```
+impl Display for ResumeToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}", self.0.uploaded_bytes, self.0.upload_id)?;
+        for part in &self.0.parts {
+            write!(f, "\n{} {}", part.part_number, part.etag)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ResumeToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let new_error = || {
+            Error::new(ErrorKind::InvalidInput, "resume token is invalid").with_context("input", s)
+        };
+
+        let mut lines = s.split('\n');
+        let uploaded_bytes = lines
+            .next()
+            .and_then(|v| v.parse::<u64>().ok())
+            .ok_or_else(new_error)?;
+        let upload_id = lines.next().ok_or_else(new_error)?.to_string();
+        let parts = lines
+            .map(|line| {
+                let (part_number, etag) = line.split_once(' ').ok_or_else(new_error)?;
+                Ok(oio::MultipartUploadPart {
+                    part_number: part_number.parse().map_err(|_| new_error())?,
+                    etag: etag.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ResumeToken(oio::MultipartUploadState {
+            upload_id,
+            parts,
+            uploaded_bytes,
+        }))
     }
 }
 
@@ -256,6 +393,17 @@ impl tokio::io::AsyncWrite for Writer {
 
 /// BlockingWriter is designed to write data into given path in an blocking
 /// manner.
+///
+/// BlockingWriter implements [`io::Write`], so it can be used along with
+/// `std::io::copy` or wrapped by `std::io::BufWriter`.
+///
+/// # Notes
+///
+/// The writer will **NOT** be closed while dropping, so that an early return or
+/// panic won't commit partial data. Please call [`BlockingWriter::close`]
+/// explicitly to make sure the data has been stored, the same as [`Writer`].
+/// While wrapped by `BufWriter`, use `BufWriter::into_inner` to flush the buffered
+/// data and get the writer back before closing it.
 pub struct BlockingWriter {
     pub(crate) inner: oio::BlockingWriter,
 }
@@ -302,3 +450,39 @@ impl io::Write for BlockingWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_token_round_trip() {
+        let token = ResumeToken(oio::MultipartUploadState {
+            upload_id: "upload id".to_string(),
+            parts: vec![
+                oio::MultipartUploadPart {
+                    part_number: 0,
+                    etag: "\"etag 0\"".to_string(),
+                },
+                oio::MultipartUploadPart {
+                    part_number: 1,
+                    etag: "\"etag-1\"".to_string(),
+                },
+            ],
+            uploaded_bytes: 10 * 1024 * 1024,
+        });
+
+        let parsed: ResumeToken = token.to_string().parse().expect("parse must succeed");
+        assert_eq!(parsed, token);
+        assert_eq!(parsed.upload_id(), "upload id");
+        assert_eq!(parsed.uploaded_bytes(), 10 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_resume_token_invalid() {
+        for input in ["", "abc\nupload", "10\nupload\nabc"] {
+            let err = input.parse::<ResumeToken>().expect_err("parse must fail");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+}
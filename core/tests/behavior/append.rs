@@ -40,6 +40,7 @@ pub fn behavior_append_tests(op: &Operator) -> Vec<Trial> {
         test_append_with_content_type,
         test_append_with_content_disposition,
         test_appender_futures_copy,
+        test_appender_close_with_offset,
         test_fuzz_appender
     )
 }
@@ -197,6 +198,35 @@ pub async fn test_appender_futures_copy(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Test appender close should report the total size if service supports.
+pub async fn test_appender_close_with_offset(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content_one, size_one) = gen_bytes(op.info().full_capability());
+    let (content_two, size_two) = gen_bytes(op.info().full_capability());
+
+    let mut a = op.writer_with(&path).append(true).await?;
+    a.write(content_one).await?;
+    let meta = a.close().await?;
+    if meta.metakey().contains(Metakey::ContentLength) {
+        assert_eq!(meta.content_length(), size_one as u64);
+    }
+
+    let mut a = op.writer_with(&path).append(true).await?;
+    a.write(content_two).await?;
+    let meta = a.close().await?;
+    if meta.metakey().contains(Metakey::ContentLength) {
+        assert_eq!(meta.content_length(), (size_one + size_two) as u64);
+        assert_eq!(
+            meta.content_length() - size_two as u64,
+            size_one as u64,
+            "appended data should start at the end of the first write"
+        );
+    }
+
+    op.delete(&path).await.expect("delete file must success");
+    Ok(())
+}
+
 /// Test for fuzzing appender.
 pub async fn test_fuzz_appender(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
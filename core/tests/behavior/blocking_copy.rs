@@ -36,7 +36,9 @@ pub fn behavior_blocking_copy_tests(op: &Operator) -> Vec<Trial> {
         test_blocking_copy_target_dir,
         test_blocking_copy_self,
         test_blocking_copy_nested,
-        test_blocking_copy_overwrite
+        test_blocking_copy_overwrite,
+        test_blocking_copy_all_dir,
+        test_blocking_copy_all_self
     )
 }
 
@@ -185,3 +187,46 @@ pub fn test_blocking_copy_overwrite(op: BlockingOperator) -> Result<()> {
     op.delete(&target_path).expect("delete must succeed");
     Ok(())
 }
+
+/// Copy all should copy every file under dir.
+pub fn test_blocking_copy_all_dir(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().list {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut files = vec![];
+    for name in ["a", "b/c", "b/d/e"] {
+        let (content, _) = gen_bytes(op.info().full_capability());
+        op.write(&format!("{source_dir}{name}"), content.clone())?;
+        files.push((name, content));
+    }
+
+    op.copy_all(&source_dir, &target_dir)?;
+
+    for (name, content) in files {
+        let target_content = op
+            .read(&format!("{target_dir}{name}"))
+            .expect("read must succeed");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(target_content)),
+            format!("{:x}", Sha256::digest(&content)),
+        );
+    }
+
+    op.remove_all(&source_dir).expect("remove_all must succeed");
+    op.remove_all(&target_dir).expect("remove_all must succeed");
+    Ok(())
+}
+
+/// Copy all to self should return an error.
+pub fn test_blocking_copy_all_self(op: BlockingOperator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let res = op.copy_all(&source_dir, &source_dir);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IsSameFile);
+    Ok(())
+}
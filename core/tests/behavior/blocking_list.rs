@@ -84,12 +84,15 @@ pub fn test_blocking_list_dir_with_metakey(op: BlockingOperator) -> Result<()> {
             Metakey::Mode
                 | Metakey::CacheControl
                 | Metakey::ContentDisposition
+                | Metakey::ContentEncoding
                 | Metakey::ContentLength
                 | Metakey::ContentMd5
                 | Metakey::ContentRange
                 | Metakey::ContentType
                 | Metakey::Etag
                 | Metakey::LastModified
+                | Metakey::RestoreStatus
+                | Metakey::StorageClass
                 | Metakey::Version,
         )
         .call()?;
@@ -103,11 +106,14 @@ pub fn test_blocking_list_dir_with_metakey(op: BlockingOperator) -> Result<()> {
             // We don't care about the value, we just to check there is no panic.
             let _ = meta.cache_control();
             let _ = meta.content_disposition();
+            let _ = meta.content_encoding();
             let _ = meta.content_md5();
             let _ = meta.content_range();
             let _ = meta.content_type();
             let _ = meta.etag();
             let _ = meta.last_modified();
+            let _ = meta.restore_status();
+            let _ = meta.storage_class();
             let _ = meta.version();
 
             found = true
@@ -142,11 +148,14 @@ pub fn test_blocking_list_dir_with_metakey_complete(op: BlockingOperator) -> Res
             // We don't care about the value, we just to check there is no panic.
             let _ = meta.cache_control();
             let _ = meta.content_disposition();
+            let _ = meta.content_encoding();
             let _ = meta.content_md5();
             let _ = meta.content_range();
             let _ = meta.content_type();
             let _ = meta.etag();
             let _ = meta.last_modified();
+            let _ = meta.restore_status();
+            let _ = meta.storage_class();
             let _ = meta.version();
 
             found = true
@@ -36,7 +36,9 @@ pub fn behavior_blocking_rename_tests(op: &Operator) -> Vec<Trial> {
         test_blocking_rename_target_dir,
         test_blocking_rename_self,
         test_blocking_rename_nested,
-        test_blocking_rename_overwrite
+        test_blocking_rename_overwrite,
+        test_blocking_rename_dir,
+        test_blocking_rename_all_dir
     )
 }
 
@@ -194,3 +196,71 @@ pub fn test_blocking_rename_overwrite(op: BlockingOperator) -> Result<()> {
     op.delete(&target_path).expect("delete must succeed");
     Ok(())
 }
+
+/// Rename a dir should succeed if service supports rename dir, else return Unsupported.
+pub fn test_blocking_rename_dir(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{source_dir}file"), content.clone())?;
+
+    let res = op.rename(&source_dir, &target_dir);
+    if !op.info().full_capability().rename_dir {
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        op.remove_all(&source_dir)?;
+        return Ok(());
+    }
+    res?;
+
+    assert!(!op.is_exist(&format!("{source_dir}file"))?);
+    let target_content = op
+        .read(&format!("{target_dir}file"))
+        .expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&content)),
+    );
+
+    op.remove_all(&target_dir)?;
+    Ok(())
+}
+
+/// Rename all should move every file under dir.
+pub fn test_blocking_rename_all_dir(op: BlockingOperator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.list && (cap.rename_dir || (cap.copy && cap.delete))) {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut files = vec![];
+    for name in ["a", "b/c"] {
+        let (content, _) = gen_bytes(cap);
+        op.write(&format!("{source_dir}{name}"), content.clone())?;
+        files.push((name, content));
+    }
+
+    op.rename_all(&source_dir, &target_dir)?;
+
+    for (name, content) in files {
+        assert!(!op.is_exist(&format!("{source_dir}{name}"))?);
+
+        let target_content = op
+            .read(&format!("{target_dir}{name}"))
+            .expect("read must succeed");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(target_content)),
+            format!("{:x}", Sha256::digest(&content)),
+        );
+    }
+
+    op.remove_all(&target_dir)?;
+    Ok(())
+}
@@ -15,8 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::BufReader;
+use std::io::BufWriter;
 use std::io::Read;
 use std::io::Seek;
+use std::io::Write;
 
 use anyhow::Result;
 use log::debug;
@@ -44,7 +47,11 @@ pub fn behavior_blocking_write_tests(op: &Operator) -> Vec<Trial> {
         test_blocking_stat_dir,
         test_blocking_stat_with_special_chars,
         test_blocking_stat_not_exist,
+        test_blocking_stat_with_if_match,
+        test_blocking_stat_with_if_none_match,
+        test_blocking_read_with_if_match,
         test_blocking_read_full,
+        test_blocking_io_with_buf_reader_writer,
         test_blocking_read_range,
         test_blocking_read_large_range,
         test_blocking_read_not_exist,
@@ -52,7 +59,8 @@ pub fn behavior_blocking_write_tests(op: &Operator) -> Vec<Trial> {
         test_blocking_fuzz_offset_reader,
         test_blocking_fuzz_part_reader,
         test_blocking_delete_file,
-        test_blocking_remove_one_file
+        test_blocking_remove_one_file,
+        test_blocking_remove_multiple_files
     )
 }
 
@@ -205,6 +213,95 @@ pub fn test_blocking_stat_with_special_chars(op: BlockingOperator) -> Result<()>
     Ok(())
 }
 
+/// Stat with if_match should succeed, else get a ConditionNotMatch.
+pub fn test_blocking_stat_with_if_match(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).expect("write must succeed");
+
+    let meta = op.stat(&path)?;
+
+    let res = op.stat_with(&path).if_match("\"invalid_etag\"").call();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let res = op
+        .stat_with(&path)
+        .if_match(meta.etag().expect("etag must exist"))
+        .call();
+    assert!(res.is_ok());
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat with if_none_match should succeed, else get a ConditionNotMatch.
+pub fn test_blocking_stat_with_if_none_match(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_none_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).expect("write must succeed");
+
+    let meta = op.stat(&path)?;
+
+    let res = op
+        .stat_with(&path)
+        .if_none_match(meta.etag().expect("etag must exist"))
+        .call();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let res = op
+        .stat_with(&path)
+        .if_none_match("\"invalid_etag\"")
+        .call()?;
+    assert_eq!(res.content_length(), size as u64);
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with if_match should match, else get a ConditionNotMatch error.
+pub fn test_blocking_read_with_if_match(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .expect("write must succeed");
+
+    let meta = op.stat(&path)?;
+
+    let res = op.read_with(&path).if_match("\"invalid_etag\"").call();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let bs = op
+        .read_with(&path)
+        .if_match(meta.etag().expect("etag must exist"))
+        .call()
+        .expect("read must succeed");
+    assert_eq!(bs, content);
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
 /// Stat not exist file should return NotFound
 pub fn test_blocking_stat_not_exist(op: BlockingOperator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -237,6 +334,32 @@ pub fn test_blocking_read_full(op: BlockingOperator) -> Result<()> {
     Ok(())
 }
 
+/// Write via `BufWriter` and read via `BufReader` should match.
+pub fn test_blocking_io_with_buf_reader_writer(op: BlockingOperator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    // Use a capacity not less than the content so that only one write reaches the writer.
+    let mut w = BufWriter::with_capacity(size, op.writer(&path)?);
+    w.write_all(&content)?;
+    let mut w = w.into_inner().map_err(|err| err.into_error())?;
+    w.close()?;
+
+    let mut r = BufReader::new(op.reader(&path)?);
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs)?;
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
 /// Read range content should match.
 pub fn test_blocking_read_range(op: BlockingOperator) -> Result<()> {
     if !op.info().full_capability().read_with_range {
@@ -455,3 +578,22 @@ pub fn test_blocking_remove_one_file(op: BlockingOperator) -> Result<()> {
 
     Ok(())
 }
+
+/// Remove multiple files in chunks
+pub fn test_blocking_remove_multiple_files(op: BlockingOperator) -> Result<()> {
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let paths: Vec<String> = (0..5).map(|_| uuid::Uuid::new_v4().to_string()).collect();
+    for path in paths.iter() {
+        op.write(path, content.clone()).expect("write must succeed");
+    }
+
+    // Use a small limit to make sure paths are removed in multiple chunks.
+    op.with_limit(2).remove(paths.clone())?;
+
+    for path in paths.iter() {
+        assert!(!op.is_exist(path)?);
+    }
+
+    Ok(())
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use anyhow::Result;
+use opendal::raw::MetadataDirective;
 use sha2::Digest;
 use sha2::Sha256;
 
@@ -24,11 +25,17 @@ use crate::*;
 pub fn behavior_copy_tests(op: &Operator) -> Vec<Trial> {
     let cap = op.info().full_capability();
 
-    if !(cap.read && cap.write && cap.copy) {
+    if !(cap.read && cap.write) {
         return vec![];
     }
 
-    async_trials!(
+    // Copy with fallback should work even if copy is not supported.
+    let mut trials = async_trials!(op, test_copy_with_fallback);
+    if !cap.copy {
+        return trials;
+    }
+
+    trials.extend(async_trials!(
         op,
         test_copy_file_with_ascii_name,
         test_copy_file_with_non_ascii_name,
@@ -36,9 +43,17 @@ pub fn behavior_copy_tests(op: &Operator) -> Vec<Trial> {
         test_copy_source_dir,
         test_copy_target_dir,
         test_copy_self,
+        test_copy_self_with_unnormalized_path,
         test_copy_nested,
-        test_copy_overwrite
-    )
+        test_copy_overwrite,
+        test_copy_with_if_none_match,
+        test_copy_with_preserve_last_modified,
+        test_copy_self_with_content_type,
+        test_copy_all_dir,
+        test_copy_all_self
+    ));
+
+    trials
 }
 
 /// Copy a file with ascii name and test contents.
@@ -158,6 +173,48 @@ pub async fn test_copy_self(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Copy a file to self with different but equivalent path should return an error.
+pub async fn test_copy_self_with_unnormalized_path(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let name = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{parent}/{name}");
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, content.clone()).await?;
+
+    // Paths are the same after normalization.
+    let err = op
+        .copy(
+            &source_path,
+            &format!("//{}", source_path.replace('/', "//")),
+        )
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+
+    // Copy file to the dir path with the same name.
+    let err = op
+        .copy(&source_path, &format!("{source_path}/"))
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::IsADirectory);
+
+    // `.` is resolved by local fs, so the paths point to the same file.
+    if op.info().scheme() == opendal::Scheme::Fs {
+        let err = op
+            .copy(&source_path, &format!("{parent}/./{name}"))
+            .await
+            .expect_err("copy must fail");
+        assert_eq!(err.kind(), ErrorKind::IsSameFile);
+
+        let bs = op.read(&source_path).await?;
+        assert_eq!(bs, content, "source must not be truncated");
+    }
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Copy to a nested path, parent path should be created successfully.
 pub async fn test_copy_nested(op: Operator) -> Result<()> {
     let source_path = uuid::Uuid::new_v4().to_string();
@@ -210,3 +267,182 @@ pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy all should copy every file under dir.
+pub async fn test_copy_all_dir(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut files = vec![];
+    for name in ["a", "b/c", "b/d/e"] {
+        let (content, _) = gen_bytes(op.info().full_capability());
+        op.write(&format!("{source_dir}{name}"), content.clone())
+            .await?;
+        files.push((name, content));
+    }
+
+    op.copy_all(&source_dir, &target_dir).await?;
+
+    for (name, content) in files {
+        let target_content = op
+            .read(&format!("{target_dir}{name}"))
+            .await
+            .expect("read must succeed");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(target_content)),
+            format!("{:x}", Sha256::digest(&content)),
+        );
+    }
+
+    op.remove_all(&source_dir)
+        .await
+        .expect("remove_all must succeed");
+    op.remove_all(&target_dir)
+        .await
+        .expect("remove_all must succeed");
+    Ok(())
+}
+
+/// Copy all to self should return an error.
+pub async fn test_copy_all_self(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let res = op.copy_all(&source_dir, &source_dir).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IsSameFile);
+    Ok(())
+}
+
+/// Copy with if_none_match `*` should fail if the target exists.
+pub async fn test_copy_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().copy_with_if_none_match {
+        return Ok(());
+    }
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.copy_with(&source_path, &target_path)
+        .if_none_match("*")
+        .await?;
+
+    let err = op
+        .copy_with(&source_path, &target_path)
+        .if_none_match("*")
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+    let target_content = op.read(&target_path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy with preserve_last_modified should keep the last modified of the source,
+/// or return `Unsupported` if the service can't.
+pub async fn test_copy_with_preserve_last_modified(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+    op.write(&source_path, source_content).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    let res = op
+        .copy_with(&source_path, &target_path)
+        .preserve_last_modified(true)
+        .await;
+    if !op.info().full_capability().copy_with_preserve_last_modified {
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        op.delete(&source_path).await.expect("delete must succeed");
+        return Ok(());
+    }
+    res?;
+
+    let source_meta = op.stat(&source_path).await?;
+    let target_meta = op.stat(&target_path).await?;
+    assert_eq!(
+        target_meta.last_modified(),
+        source_meta.last_modified(),
+        "last modified must be preserved"
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy a file to self with new content type should update the metadata only.
+pub async fn test_copy_self_with_content_type(op: Operator) -> Result<()> {
+    if !op.info().full_capability().copy_with_metadata_directive {
+        return Ok(());
+    }
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+    op.write_with(&source_path, content.clone())
+        .content_type("application/octet-stream")
+        .await?;
+
+    op.copy_with(&source_path, &source_path)
+        .metadata_directive(MetadataDirective::Replace)
+        .content_type("text/plain")
+        .await?;
+
+    let meta = op.stat(&source_path).await.expect("stat must succeed");
+    assert_eq!(meta.content_type(), Some("text/plain"));
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&source_path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(bs)),
+        format!("{:x}", Sha256::digest(&content)),
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy with fallback should work even if service doesn't support copy.
+pub async fn test_copy_with_fallback(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(cap);
+
+    let mut write = op.write_with(&source_path, content.clone());
+    if cap.write_with_content_type {
+        write = write.content_type("text/plain");
+    }
+    write.await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.copy_with(&source_path, &target_path)
+        .allow_fallback(true)
+        .await?;
+
+    let bs = op.read(&target_path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+    if cap.write_with_content_type {
+        let meta = op.stat(&target_path).await?;
+        assert_eq!(meta.content_type(), Some("text/plain"));
+    }
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
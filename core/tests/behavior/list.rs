@@ -17,6 +17,8 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use anyhow::Result;
 use futures::stream::FuturesUnordered;
@@ -36,8 +38,15 @@ pub fn behavior_list_tests(op: &Operator) -> Vec<Trial> {
     async_trials!(
         op,
         test_check,
+        test_check_with_round_trip,
         test_list_dir,
         test_list_dir_with_metakey,
+        test_list_dir_with_metakey_etag,
+        test_list_dir_with_metakey_content_disposition,
+        test_list_dir_with_metakey_content_type,
+        test_list_map,
+        test_stat_with_infer_dir,
+        test_list_dir_with_default_metakey,
         test_list_dir_with_metakey_complete,
         test_list_prefix,
         test_list_rich_dir,
@@ -47,9 +56,24 @@ pub fn behavior_list_tests(op: &Operator) -> Vec<Trial> {
         test_list_nested_dir,
         test_list_dir_with_file_path,
         test_list_with_start_after,
+        test_list_with_start_before,
         test_list_with_recursive,
         test_list_root_with_recursive,
-        test_remove_all
+        test_list_with_pattern,
+        test_list_with_size_filter,
+        test_list_with_modified_filter,
+        test_list_with_only_files_and_dirs,
+        test_list_with_sorted,
+        test_walk,
+        test_list_with_versions,
+        test_list_with_versions_is_latest,
+        test_list_with_delimiter,
+        test_delete_entries_with_versions,
+        test_remove_all,
+        test_remove_all_with_wide_dirs,
+        test_remove_all_not_existing_dir,
+        test_remove_all_with_many_files,
+        test_remove_all_with_dry_run
     )
 }
 
@@ -60,6 +84,24 @@ pub async fn test_check(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Check with round trip should be OK and leave nothing behind.
+pub async fn test_check_with_round_trip(op: Operator) -> Result<()> {
+    op.check_with()
+        .round_trip(true)
+        .await
+        .expect("operator check with round trip is ok");
+
+    let entries = op.list("/").await?;
+    assert!(
+        entries
+            .iter()
+            .all(|v| !v.path().starts_with(".opendal_check_")),
+        "temporary file of check should be removed"
+    );
+
+    Ok(())
+}
+
 /// List dir should return newly created file.
 pub async fn test_list_dir(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -87,6 +129,34 @@ pub async fn test_list_dir(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List dir into a map should index entries by path.
+pub async fn test_list_map(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let entries = op
+        .with_default_metakey(Metakey::Mode | Metakey::ContentLength)
+        .list_map(&format!("{parent}/"))
+        .await?;
+    let meta = entries.get(&path).expect("file should be found in map");
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    let listed = op.list(&format!("{parent}/")).await?;
+    let paths = listed
+        .iter()
+        .map(|e| e.path().to_string())
+        .collect::<HashSet<_>>();
+    assert_eq!(entries.keys().cloned().collect::<HashSet<_>>(), paths);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// List dir with metakey
 pub async fn test_list_dir_with_metakey(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -102,12 +172,15 @@ pub async fn test_list_dir_with_metakey(op: Operator) -> Result<()> {
             Metakey::Mode
                 | Metakey::CacheControl
                 | Metakey::ContentDisposition
+                | Metakey::ContentEncoding
                 | Metakey::ContentLength
                 | Metakey::ContentMd5
                 | Metakey::ContentRange
                 | Metakey::ContentType
                 | Metakey::Etag
                 | Metakey::LastModified
+                | Metakey::RestoreStatus
+                | Metakey::StorageClass
                 | Metakey::Version,
         )
         .await?;
@@ -121,11 +194,14 @@ pub async fn test_list_dir_with_metakey(op: Operator) -> Result<()> {
             // We don't care about the value, we just to check there is no panic.
             let _ = meta.cache_control();
             let _ = meta.content_disposition();
+            let _ = meta.content_encoding();
             let _ = meta.content_md5();
             let _ = meta.content_range();
             let _ = meta.content_type();
             let _ = meta.etag();
             let _ = meta.last_modified();
+            let _ = meta.restore_status();
+            let _ = meta.storage_class();
             let _ = meta.version();
 
             found = true
@@ -137,6 +213,181 @@ pub async fn test_list_dir_with_metakey(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List dir with etag should return the same etag presence as stat.
+pub async fn test_list_dir_with_metakey_etag(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+    let expected = op.stat(&path).await?;
+
+    let mut obs = op
+        .lister_with(&format!("{parent}/"))
+        .metakey(Metakey::Etag)
+        .await?;
+    let mut found = false;
+    while let Some(de) = obs.try_next().await? {
+        if de.path() == path {
+            let meta = de.metadata();
+            assert!(
+                meta.metakey().contains(Metakey::Etag),
+                "etag must be known while listing with Metakey::Etag"
+            );
+            assert_eq!(
+                meta.etag().is_some(),
+                expected.etag().is_some(),
+                "etag must be returned if stat returns it"
+            );
+
+            found = true
+        }
+    }
+    assert!(found, "file should be found in list");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// List dir with metakey content disposition should return the value set on write.
+pub async fn test_list_dir_with_metakey_content_disposition(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_disposition {
+        return Ok(());
+    }
+
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let target_content_disposition = "attachment; filename=\"filename.jpg\"";
+    op.write_with(&path, content)
+        .content_disposition(target_content_disposition)
+        .await
+        .expect("write must succeed");
+
+    let mut obs = op
+        .lister_with(&format!("{parent}/"))
+        .metakey(Metakey::ContentDisposition)
+        .await?;
+    let mut found = false;
+    while let Some(de) = obs.try_next().await? {
+        if de.path() == path {
+            assert_eq!(
+                de.metadata().content_disposition(),
+                Some(target_content_disposition),
+                "content disposition must be returned while listing"
+            );
+
+            found = true
+        }
+    }
+    assert!(found, "file should be found in list");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// List dir with metakey content type should return the content type of files.
+pub async fn test_list_dir_with_metakey_content_type(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_type {
+        return Ok(());
+    }
+
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write_with(&path, content)
+        .content_type("image/png")
+        .await
+        .expect("write must succeed");
+
+    let mut obs = op
+        .lister_with(&format!("{parent}/"))
+        .metakey(Metakey::Mode | Metakey::ContentType)
+        .await?;
+    let mut found = false;
+    while let Some(de) = obs.try_next().await? {
+        if de.path() == path {
+            assert_eq!(
+                de.content_type(),
+                Some("image/png"),
+                "content type must be returned while listing"
+            );
+            assert_eq!(de.content_type(), de.metadata().content_type());
+
+            found = true
+        } else if de.metadata().is_dir() {
+            assert_ne!(
+                de.content_type(),
+                Some(""),
+                "content type must not be empty"
+            );
+        }
+    }
+    assert!(found, "file should be found in list");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat a prefix with infer dir should return dir even without dir object.
+pub async fn test_stat_with_infer_dir(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let meta = op.stat_with(&format!("{parent}/")).infer_dir(true).await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    let res = op
+        .stat_with(&format!("{}/", uuid::Uuid::new_v4()))
+        .infer_dir(true)
+        .await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::NotFound));
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// List dir with default metakey set on operator.
+pub async fn test_list_dir_with_default_metakey(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let op = op.with_default_metakey(Metakey::Mode | Metakey::ContentLength);
+    let entries = op.list(&format!("{parent}/")).await?;
+    let entry = entries
+        .iter()
+        .find(|v| v.path() == path)
+        .expect("file should be found in list");
+    assert_eq!(entry.metadata().mode(), EntryMode::FILE);
+    assert_eq!(entry.metadata().content_length(), size as u64);
+
+    // Explicit metakey should override the default one.
+    let entries = op
+        .list_with(&format!("{parent}/"))
+        .metakey(Metakey::Mode)
+        .await?;
+    let entry = entries
+        .iter()
+        .find(|v| v.path() == path)
+        .expect("file should be found in list");
+    assert_eq!(entry.metadata().mode(), EntryMode::FILE);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// List dir with metakey complete
 pub async fn test_list_dir_with_metakey_complete(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -160,11 +411,14 @@ pub async fn test_list_dir_with_metakey_complete(op: Operator) -> Result<()> {
             // We don't care about the value, we just to check there is no panic.
             let _ = meta.cache_control();
             let _ = meta.content_disposition();
+            let _ = meta.content_encoding();
             let _ = meta.content_md5();
             let _ = meta.content_range();
             let _ = meta.content_type();
             let _ = meta.etag();
             let _ = meta.last_modified();
+            let _ = meta.restore_status();
+            let _ = meta.storage_class();
             let _ = meta.version();
 
             found = true
@@ -413,6 +667,59 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with start before should only return entries strictly before the specified key
+pub async fn test_list_with_start_before(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+
+    let given: Vec<String> = ["file-0", "file-1", "file-2", "file-3", "file-4", "file-5"]
+        .iter()
+        .map(|name| format!("{dir}{name}-{}", uuid::Uuid::new_v4()))
+        .collect();
+
+    for name in given.iter() {
+        op.write(name, "content").await?;
+    }
+
+    let actual = op
+        .list_with(dir)
+        .start_before(&given[3])
+        .sorted(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .filter(|v| v != dir)
+        .collect::<Vec<_>>();
+
+    let expected: Vec<String> = given.iter().take(3).cloned().collect();
+
+    assert_eq!(expected, actual);
+
+    // limit_total returns the first entries before the key in ascending order.
+    let all = op
+        .list_with(dir)
+        .start_before(&given[3])
+        .sorted(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    let actual = op
+        .list_with(dir)
+        .start_before(&given[3])
+        .sorted(true)
+        .limit_total(2)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(all[..2].to_vec(), actual);
+
+    op.remove_all(dir).await?;
+
+    Ok(())
+}
+
 pub async fn test_list_root_with_recursive(op: Operator) -> Result<()> {
     let w = op.lister_with("").recursive(true).await?;
     let actual = w
@@ -466,6 +773,441 @@ pub async fn test_list_with_recursive(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with pattern should only return matched entries.
+pub async fn test_list_with_pattern(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let files = [
+        "a.parquet",
+        "b.csv",
+        "x/c.parquet",
+        "x/d.csv",
+        "x/y/e.parquet",
+    ];
+    for path in files.iter() {
+        op.write(&format!("{parent}/{path}"), "test_pattern")
+            .await?;
+    }
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .pattern("**/*.parquet")
+        .await?
+        .into_iter()
+        .map(|v| {
+            v.path()
+                .strip_prefix(&format!("{parent}/"))
+                .unwrap()
+                .to_string()
+        })
+        .collect::<HashSet<_>>();
+    assert_eq!(
+        actual,
+        HashSet::from([
+            "a.parquet".to_string(),
+            "x/c.parquet".to_string(),
+            "x/y/e.parquet".to_string()
+        ])
+    );
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .pattern("*.csv")
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(actual, vec![format!("{parent}/b.csv")]);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List with min_size and max_size should only return files in the range.
+pub async fn test_list_with_size_filter(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    for (path, size) in [("small", 1), ("medium", 1024), ("large", 4096)] {
+        op.write(&format!("{parent}/{path}"), vec![0u8; size])
+            .await?;
+    }
+    op.create_dir(&format!("{parent}/dir/")).await?;
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .min_size(1024)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    assert!(actual.contains(&format!("{parent}/medium")));
+    assert!(actual.contains(&format!("{parent}/large")));
+    assert!(!actual.contains(&format!("{parent}/small")));
+    // Dirs don't have a size and will always be returned.
+    assert!(actual.contains(&format!("{parent}/dir/")));
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .min_size(2)
+        .max_size(1024)
+        .await?
+        .into_iter()
+        .filter(|v| v.metadata().is_file())
+        .map(|v| (v.path().to_string(), v.metadata().content_length()))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, vec![(format!("{parent}/medium"), 1024)]);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List with modified_after and modified_before should only return files in the window.
+pub async fn test_list_with_modified_filter(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/file");
+    op.write(&path, "test_modified").await?;
+
+    let Some(last_modified) = op.stat(&path).await?.last_modified() else {
+        // Entries without last modified will never be filtered out.
+        op.remove_all(&format!("{parent}/")).await?;
+        return Ok(());
+    };
+    let last_modified: SystemTime = last_modified.into();
+
+    for (after, before, expected) in [
+        (Some(last_modified - Duration::from_secs(60)), None, true),
+        (Some(last_modified), None, false),
+        (None, Some(last_modified + Duration::from_secs(60)), true),
+        (None, Some(last_modified), false),
+    ] {
+        let mut fut = op.list_with(&format!("{parent}/"));
+        if let Some(v) = after {
+            fut = fut.modified_after(v);
+        }
+        if let Some(v) = before {
+            fut = fut.modified_before(v);
+        }
+        let actual = fut.await?.into_iter().any(|v| v.path() == path.as_str());
+        assert_eq!(actual, expected, "after: {after:?}, before: {before:?}");
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List with only_files and only_dirs should only return entries in given mode.
+pub async fn test_list_with_only_files_and_dirs(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let files = ["a", "x/b", "x/y/c", "z/d"];
+    for path in files.iter() {
+        op.write(&format!("{parent}/{path}"), "test_only").await?;
+    }
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .only_files(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = files
+        .iter()
+        .map(|v| format!("{parent}/{v}"))
+        .collect::<HashSet<_>>();
+    assert_eq!(actual, expected);
+
+    // Dirs should be returned even if services don't return them explicitly.
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .only_dirs(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    let mut expected = vec![
+        format!("{parent}/x/"),
+        format!("{parent}/x/y/"),
+        format!("{parent}/z/"),
+    ];
+    let mut actual_sorted = actual.clone();
+    actual_sorted.sort();
+    expected.sort();
+    assert_eq!(actual_sorted, expected, "dirs should be returned only once");
+
+    let res = op
+        .list_with(&format!("{parent}/"))
+        .only_files(true)
+        .only_dirs(true)
+        .await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List with sorted should return entries in sorted path order.
+pub async fn test_list_with_sorted(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let files = ["d", "a/c", "b", "a/b/a", "c/a", "a/a"];
+    for path in files.iter() {
+        op.write(&format!("{parent}/{path}"), "test_sorted").await?;
+    }
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .recursive(true)
+        .sorted(true)
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    let mut expected = actual.clone();
+    expected.sort();
+    assert_eq!(actual, expected);
+    for path in files.iter() {
+        assert!(actual.contains(&format!("{parent}/{path}")));
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Walk should return all files recursively without dirs.
+pub async fn test_walk(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let files = ["a", "x/b", "x/y/c"];
+    for path in files.iter() {
+        op.write(&format!("{parent}/{path}"), "test_walk").await?;
+    }
+    if op.info().full_capability().create_dir {
+        op.create_dir(&format!("{parent}/z/")).await?;
+    }
+
+    let actual = op
+        .walk_with(&format!("{parent}/"))
+        .metakey(Metakey::Mode | Metakey::ContentLength)
+        .await?
+        .map_ok(|v| {
+            assert_eq!(v.metadata().mode(), EntryMode::FILE);
+            assert_eq!(v.metadata().content_length(), "test_walk".len() as u64);
+            v.path().to_string()
+        })
+        .try_collect::<HashSet<_>>()
+        .await?;
+    assert_eq!(
+        actual,
+        files
+            .iter()
+            .map(|v| format!("{parent}/{v}"))
+            .collect::<HashSet<_>>()
+    );
+
+    let res = op.walk(&parent).await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::NotADirectory));
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List with versions should return entries with version.
+pub async fn test_list_with_versions(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+
+    if !op.info().full_capability().list_with_versions {
+        let res = op.lister_with(&format!("{parent}/")).versions(true).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        return Ok(());
+    }
+
+    op.write(&path, "1").await.expect("write must succeed");
+    op.write(&path, "2").await.expect("write must succeed");
+
+    let entries: Vec<Entry> = op
+        .lister_with(&format!("{parent}/"))
+        .versions(true)
+        .await?
+        .try_collect()
+        .await?;
+    let entries: Vec<&Entry> = entries.iter().filter(|v| v.path() == path).collect();
+    assert!(!entries.is_empty(), "file should be found in list");
+    for entry in entries {
+        assert!(entry.metadata().version().is_some());
+        assert!(!entry.metadata().is_deleted());
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// List with custom delimiter should group entries by the delimiter.
+pub async fn test_list_with_delimiter(op: Operator) -> Result<()> {
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+
+    if !op.info().full_capability().list_with_delimiter {
+        let res = op.lister_with(&parent).delimiter(Some('-')).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        return Ok(());
+    }
+
+    let paths = ["a-1", "a-2", "b"].map(|v| format!("{parent}{v}"));
+    for path in paths.iter() {
+        op.write(path, "1").await.expect("write must succeed");
+    }
+
+    let mut entries: Vec<(String, EntryMode)> = op
+        .lister_with(&parent)
+        .delimiter(Some('-'))
+        .await?
+        .map_ok(|e| (e.path().to_string(), e.metadata().mode()))
+        .try_collect()
+        .await?;
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        entries,
+        vec![
+            (format!("{parent}a-"), EntryMode::Unknown),
+            (format!("{parent}b"), EntryMode::FILE)
+        ]
+    );
+
+    // Delimiter `/` should clear the custom delimiter set before.
+    let mut entries: Vec<String> = op
+        .lister_with(&parent)
+        .delimiter(Some('-'))
+        .delimiter(Some('/'))
+        .await?
+        .map_ok(|e| e.path().to_string())
+        .try_collect()
+        .await?;
+    entries.sort();
+    assert_eq!(entries, paths.to_vec());
+
+    // Delimiter `None` means a fully flat listing.
+    let mut entries: Vec<String> = op
+        .lister_with(&parent)
+        .delimiter(None)
+        .await?
+        .map_ok(|e| e.path().to_string())
+        .try_collect()
+        .await?;
+    entries.sort();
+    assert_eq!(entries, paths.to_vec());
+
+    op.remove_all(&parent)
+        .await
+        .expect("remove all must succeed");
+    Ok(())
+}
+
+/// Delete entries listed with versions should remove all versions.
+pub async fn test_delete_entries_with_versions(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list_with_versions {
+        return Ok(());
+    }
+
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{parent}{}", uuid::Uuid::new_v4());
+
+    op.write(&path, "1").await.expect("write must succeed");
+    op.write(&path, "2").await.expect("write must succeed");
+
+    let entries: Vec<Entry> = op
+        .lister_with(&parent)
+        .versions(true)
+        .await?
+        .try_collect()
+        .await?;
+    let entries: Vec<Entry> = entries.into_iter().filter(|v| v.path() == path).collect();
+    assert!(!entries.is_empty(), "file should be found in list");
+
+    op.delete_entries(futures::stream::iter(entries)).await?;
+
+    let entries: Vec<Entry> = op
+        .lister_with(&parent)
+        .versions(true)
+        .await?
+        .try_collect()
+        .await?;
+    assert!(
+        entries.iter().all(|v| v.path() != path),
+        "all versions should be removed"
+    );
+    Ok(())
+}
+
+/// List with versions should mark exactly one version as latest.
+pub async fn test_list_with_versions_is_latest(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list_with_versions {
+        return Ok(());
+    }
+
+    let parent = uuid::Uuid::new_v4().to_string();
+    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+
+    op.write(&path, "1").await.expect("write must succeed");
+    op.write(&path, "2").await.expect("write must succeed");
+
+    let entries: Vec<Entry> = op
+        .lister_with(&format!("{parent}/"))
+        .versions(true)
+        .await?
+        .try_collect()
+        .await?;
+    let entries: Vec<&Entry> = entries.iter().filter(|v| v.path() == path).collect();
+    let latest: Vec<&&Entry> = entries
+        .iter()
+        .filter(|v| v.metadata().is_latest() == Some(true))
+        .collect();
+    assert_eq!(latest.len(), 1, "only one version should be latest");
+
+    // Stat without version should return the latest version.
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.is_latest(), Some(true));
+    // Unversioned buckets will not return version while stat.
+    if meta.version().is_some() {
+        assert_eq!(meta.version(), latest[0].metadata().version());
+    }
+
+    // Stat with version should return the given version.
+    for entry in entries {
+        let version = entry.metadata().version().expect("version must exist");
+        let meta = op.stat_with(&path).version(version).await?;
+        if meta.version().is_some() {
+            assert_eq!(meta.version(), Some(version));
+        }
+        assert!(!meta.is_delete_marker());
+    }
+
+    // Stat a delete marker with version should return it as delete marker.
+    op.delete(&path).await.expect("delete must succeed");
+    let entries: Vec<Entry> = op
+        .lister_with(&format!("{parent}/"))
+        .versions(true)
+        .await?
+        .try_collect()
+        .await?;
+    if let Some(marker) = entries
+        .iter()
+        .find(|v| v.path() == path && v.metadata().is_delete_marker())
+    {
+        let version = marker.metadata().version().expect("version must exist");
+        let meta = op.stat_with(&path).version(version).await?;
+        assert!(meta.is_delete_marker());
+        assert_eq!(meta.version(), Some(version));
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 // Remove all should remove all in this path.
 pub async fn test_remove_all(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -494,3 +1236,98 @@ pub async fn test_remove_all(op: Operator) -> Result<()> {
     }
     Ok(())
 }
+
+/// Remove all should remove children before their parents even if entries
+/// are removed concurrently.
+pub async fn test_remove_all_with_wide_dirs(op: Operator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+    let op = op.with_limit(8);
+
+    let mut expected = vec![];
+    for i in 0..4 {
+        expected.push(format!("{parent}dir-{i}/"));
+        expected.push(format!("{parent}dir-{i}/nested/"));
+        for j in 0..4 {
+            expected.push(format!("{parent}dir-{i}/file-{j}"));
+            expected.push(format!("{parent}dir-{i}/nested/file-{j}"));
+        }
+    }
+    for path in expected.iter() {
+        if path.ends_with('/') {
+            op.create_dir(path).await?;
+        } else {
+            op.write(path, "test_remove_all").await?;
+        }
+    }
+
+    op.remove_all(&parent).await?;
+
+    for path in expected.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be removed");
+    }
+    assert!(!op.is_exist(&parent).await?, "{parent} should be removed");
+    Ok(())
+}
+
+/// Remove all on a not existing dir should succeed.
+pub async fn test_remove_all_not_existing_dir(op: Operator) -> Result<()> {
+    let path = format!("{}/", uuid::Uuid::new_v4());
+
+    op.remove_all(&path).await?;
+
+    Ok(())
+}
+
+/// Remove all should remove every file in a tree without creating dirs first.
+pub async fn test_remove_all_with_many_files(op: Operator) -> Result<()> {
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut expected = vec![];
+    for i in 0..8 {
+        for j in 0..16 {
+            expected.push(format!("{parent}dir-{i}/file-{j}"));
+        }
+    }
+    let op = &op;
+    futures::stream::iter(expected.iter())
+        .map(Ok)
+        .try_for_each_concurrent(16, |path| async move {
+            op.write(path, "test_remove_all").await.map(|_| ())
+        })
+        .await?;
+
+    op.remove_all(&parent).await?;
+
+    for path in expected.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be removed");
+    }
+    Ok(())
+}
+
+/// Remove all with dry run should return the paths to remove without removing them.
+pub async fn test_remove_all_with_dry_run(op: Operator) -> Result<()> {
+    let parent = format!("{}/", uuid::Uuid::new_v4());
+
+    let expected = vec![
+        format!("{parent}a"),
+        format!("{parent}x/b"),
+        format!("{parent}x/y/c"),
+    ];
+    for path in expected.iter() {
+        op.write(path, "test_remove_all").await?;
+    }
+
+    let paths = op.remove_all_with(&parent).dry_run(true).await?;
+    for path in expected.iter() {
+        assert!(paths.contains(path), "{path} should be returned");
+        assert!(op.is_exist(path).await?, "{path} should not be removed");
+    }
+    assert!(paths.contains(&parent), "{parent} should be returned");
+
+    op.remove_all(&parent).await?;
+    Ok(())
+}
@@ -35,7 +35,18 @@ pub fn behavior_presign_tests(op: &Operator) -> Vec<Trial> {
         return vec![];
     }
 
-    async_trials!(op, test_presign_write, test_presign_read, test_presign_stat)
+    async_trials!(
+        op,
+        test_presign_write,
+        test_presign_write_with_extra_header,
+        test_presign_read,
+        test_presign_stat,
+        test_presign_stat_with_if_match,
+        test_presign_delete,
+        test_presign_upload_part,
+        test_presign_post,
+        test_presign_generic
+    )
 }
 
 /// Presign write should succeed.
@@ -71,6 +82,54 @@ pub async fn test_presign_write(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Presign write with extra header should include it in the signed request.
+pub async fn test_presign_write_with_extra_header(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let signed_req = match op
+        .presign_write_with(&path, Duration::from_secs(3600))
+        .header("x-amz-meta-opendal", "presign")
+        .await
+    {
+        Ok(v) => v,
+        Err(err) if err.kind() == ErrorKind::Unsupported => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+    debug!("Generated request: {signed_req:?}");
+    assert_eq!(
+        signed_req
+            .header()
+            .get("x-amz-meta-opendal")
+            .expect("extra header must be included"),
+        "presign"
+    );
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    req = req.header(header::CONTENT_LENGTH, content.len());
+    req = req.body(reqwest::Body::from(content));
+
+    let resp = req.send().await.expect("send request must succeed");
+    debug!(
+        "write response: {:?}",
+        resp.text().await.expect("read response must succeed")
+    );
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 pub async fn test_presign_stat(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
     debug!("Generate a random file: {}", &path);
@@ -100,6 +159,63 @@ pub async fn test_presign_stat(op: Operator) -> Result<()> {
     Ok(())
 }
 
+// Presign stat with if_match should be validated by the service.
+pub async fn test_presign_stat_with_if_match(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+    let meta = op.stat(&path).await?;
+
+    let res = op
+        .presign_stat_with(&path, Duration::from_secs(3600))
+        .if_match("\"invalid_etag\"")
+        .await;
+    let signed_req = match res {
+        Err(err) if err.kind() == ErrorKind::Unsupported => {
+            op.delete(&path).await.expect("delete must succeed");
+            return Ok(());
+        }
+        res => res?,
+    };
+    debug!("Generated request: {signed_req:?}");
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    let resp = req.send().await.expect("send request must succeed");
+    assert_eq!(
+        resp.status(),
+        http::StatusCode::PRECONDITION_FAILED,
+        "status precondition failed"
+    );
+
+    if let Some(etag) = meta.etag() {
+        let signed_req = op
+            .presign_stat_with(&path, Duration::from_secs(3600))
+            .if_match(etag)
+            .await?;
+        let mut req = client.request(
+            signed_req.method().clone(),
+            Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+        );
+        for (k, v) in signed_req.header() {
+            req = req.header(k, v);
+        }
+        let resp = req.send().await.expect("send request must succeed");
+        assert_eq!(resp.status(), http::StatusCode::OK, "status ok");
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 // Presign read should read content successfully.
 pub async fn test_presign_read(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -135,3 +251,168 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Presign delete should remove the file.
+pub async fn test_presign_delete(op: Operator) -> Result<()> {
+    if !op.info().full_capability().presign_delete {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let signed_req = op.presign_delete(&path, Duration::from_secs(3600)).await?;
+    debug!("Generated request: {signed_req:?}");
+    assert_eq!(signed_req.method(), http::Method::DELETE);
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send request must succeed");
+    assert!(resp.status().is_success(), "status success");
+
+    assert!(!op.is_exist(&path).await?);
+    Ok(())
+}
+
+/// Presign upload part should allow uploading a part of an initiated multipart upload.
+pub async fn test_presign_upload_part(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.presign_upload_part && cap.write_with_resume) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let size = 5 * 1024 * 1024; // write file with 5 MiB per part
+    let content = gen_fixed_bytes(size * 2);
+
+    // Initiate the multipart upload via writer to get the upload id.
+    let mut w = op.writer(&path).await?;
+    for chunk in content.chunks(size) {
+        w.write(chunk.to_vec()).await?;
+    }
+    let token = w.resume_token().expect("resume token must exist");
+    drop(w);
+
+    // Parts written by writer start from 1, use a large part number to avoid conflict.
+    let signed_req = op
+        .presign_upload_part(&path, token.upload_id(), 100, Duration::from_secs(3600))
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+    assert_eq!(signed_req.method(), http::Method::PUT);
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    req = req.header(header::CONTENT_LENGTH, size);
+    req = req.body(reqwest::Body::from(content[..size].to_vec()));
+
+    let resp = req.send().await.expect("send request must succeed");
+    assert!(resp.status().is_success(), "status success");
+    assert!(
+        resp.headers().get(header::ETAG).is_some(),
+        "etag of uploaded part must exist"
+    );
+
+    // Abort the multipart upload to clean up uploaded parts.
+    let mut w = op.writer_with(&path).resume(token).await?;
+    w.abort().await?;
+    Ok(())
+}
+
+/// Presign post should allow uploading via form.
+pub async fn test_presign_post(op: Operator) -> Result<()> {
+    if !op.info().full_capability().presign_post {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let signed_post = op
+        .presign_post(
+            &path,
+            Duration::from_secs(3600),
+            vec![raw::PostPolicyCondition::ContentLengthRange(0, size as u64)],
+        )
+        .await?;
+    debug!("Generated post: {signed_post:?}");
+
+    let mut multipart = raw::Multipart::new();
+    for (k, v) in signed_post.fields() {
+        multipart = multipart.part(raw::FormDataPart::new(k).content(v.clone()));
+    }
+    multipart = multipart.part(raw::FormDataPart::new("file").content(content));
+    let req = multipart.apply(http::Request::post(signed_post.uri().to_string()))?;
+
+    let resp = raw::HttpClient::new()?.send(req).await?;
+    debug!("post response: {:?}", resp.status());
+    assert!(resp.status().is_success());
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Presign via generic entry should work for arbitrary operations.
+pub async fn test_presign_generic(op: Operator) -> Result<()> {
+    if !op.info().full_capability().presign_delete {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let signed_req = op
+        .presign(
+            &path,
+            raw::OpPresign::new(raw::OpDelete::new(), Duration::from_secs(3600)),
+        )
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+    assert_eq!(signed_req.method(), http::Method::DELETE);
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send request must succeed");
+    assert!(resp.status().is_success(), "status success");
+
+    assert!(!op.is_exist(&path).await?);
+
+    let res = op
+        .presign(
+            &path,
+            raw::OpPresign::new(raw::OpPresignPost::new(vec![]), Duration::from_secs(3600)),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+    Ok(())
+}
@@ -24,20 +24,31 @@ use crate::*;
 pub fn behavior_rename_tests(op: &Operator) -> Vec<Trial> {
     let cap = op.info().full_capability();
 
-    if !(cap.read && cap.write && cap.rename) {
+    if !(cap.read && cap.write) {
         return vec![];
     }
 
-    async_trials!(
+    // Rename with fallback should work even if rename is not supported.
+    let mut trials = async_trials!(op, test_rename_with_fallback);
+    if !cap.rename {
+        return trials;
+    }
+
+    trials.extend(async_trials!(
         op,
         test_rename_file,
         test_rename_non_existing_source,
         test_rename_source_dir,
         test_rename_target_dir,
         test_rename_self,
+        test_rename_self_with_unnormalized_path,
         test_rename_nested,
-        test_rename_overwrite
-    )
+        test_rename_overwrite,
+        test_rename_dir,
+        test_rename_all_dir
+    ));
+
+    trials
 }
 
 /// Rename a file and test with stat.
@@ -140,6 +151,35 @@ pub async fn test_rename_self(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Rename a file to self with different but equivalent path should return an error.
+pub async fn test_rename_self_with_unnormalized_path(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, content).await?;
+
+    // Paths are the same after normalization.
+    let err = op
+        .rename(
+            &source_path,
+            &format!("//{}", source_path.replace('/', "//")),
+        )
+        .await
+        .expect_err("rename must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+
+    // Rename file to the dir path with the same name.
+    let err = op
+        .rename(&source_path, &format!("{source_path}/"))
+        .await
+        .expect_err("rename must fail");
+    assert_eq!(err.kind(), ErrorKind::IsADirectory);
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Rename to a nested path, parent path should be created successfully.
 pub async fn test_rename_nested(op: Operator) -> Result<()> {
     let source_path = uuid::Uuid::new_v4().to_string();
@@ -198,3 +238,113 @@ pub async fn test_rename_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Rename a dir should succeed if service supports rename dir, else return Unsupported.
+pub async fn test_rename_dir(op: Operator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{source_dir}file"), content.clone())
+        .await?;
+
+    let res = op.rename(&source_dir, &target_dir).await;
+    if !op.info().full_capability().rename_dir {
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        op.remove_all(&source_dir).await?;
+        return Ok(());
+    }
+    res?;
+
+    assert!(!op.is_exist(&format!("{source_dir}file")).await?);
+    let target_content = op
+        .read(&format!("{target_dir}file"))
+        .await
+        .expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&content)),
+    );
+
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
+
+/// Rename all should move every file under dir.
+pub async fn test_rename_all_dir(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.list && (cap.rename_dir || (cap.copy && cap.delete))) {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut files = vec![];
+    for name in ["a", "b/c"] {
+        let (content, _) = gen_bytes(cap);
+        op.write(&format!("{source_dir}{name}"), content.clone())
+            .await?;
+        files.push((name, content));
+    }
+
+    op.rename_all(&source_dir, &target_dir).await?;
+
+    for (name, content) in files {
+        assert!(!op.is_exist(&format!("{source_dir}{name}")).await?);
+
+        let target_content = op
+            .read(&format!("{target_dir}{name}"))
+            .await
+            .expect("read must succeed");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(target_content)),
+            format!("{:x}", Sha256::digest(&content)),
+        );
+    }
+
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
+
+/// Rename with fallback should work even if service doesn't support rename.
+pub async fn test_rename_with_fallback(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.rename || (cap.copy && cap.delete)) {
+        return Ok(());
+    }
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(cap);
+    op.write(&source_path, content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.rename_with(&source_path, &target_path)
+        .allow_fallback(true)
+        .await?;
+
+    let err = op.stat(&source_path).await.expect_err("stat must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    let bs = op.read(&target_path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    // Rename to self should still be rejected.
+    let err = op
+        .rename_with(&target_path, &target_path)
+        .allow_fallback(true)
+        .await
+        .expect_err("rename to self must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -15,8 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
+use std::time::SystemTime;
 
 use anyhow::Result;
 use bytes::Buf;
@@ -27,9 +31,15 @@ use futures::stream;
 use futures::AsyncReadExt;
 use futures::AsyncSeekExt;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use http::StatusCode;
 use log::debug;
 use log::warn;
+use opendal::raw::format_content_md5;
+use opendal::raw::BatchOperation;
+use opendal::raw::OpDelete;
+use opendal::ResumeToken;
+use opendal::Transform;
 use reqwest::Url;
 use sha2::Digest;
 use sha2::Sha256;
@@ -43,38 +53,70 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         return vec![];
     }
 
-    async_trials!(
+    #[allow(unused_mut)]
+    let mut trials = async_trials!(
         op,
         test_create_dir,
         test_create_dir_existing,
+        test_create_dir_with_metadata,
         test_write_only,
+        test_write_returning_metadata,
         test_write_with_empty_content,
         test_write_with_dir_path,
         test_write_with_special_chars,
         test_write_with_cache_control,
         test_write_with_content_type,
         test_write_with_content_disposition,
+        test_write_without_content_disposition,
+        test_write_with_content_encoding,
+        test_write_with_content_md5,
+        test_write_with_storage_class,
+        test_write_with_expires,
+        test_write_with_if_not_exists,
+        test_writer_with_if_none_match,
+        test_writer_with_if_match,
+        test_write_with_on_progress,
+        test_write_with_transform,
+        test_restore_not_supported,
         test_stat_file,
         test_stat_dir,
+        test_is_dir_and_is_file,
         test_stat_nested_parent_dir,
         test_stat_with_special_chars,
         test_stat_not_cleaned_path,
         test_stat_not_exist,
         test_stat_with_if_match,
         test_stat_with_if_none_match,
+        test_stat_with_if_modified_since,
+        test_stat_with_if_unmodified_since,
         test_stat_with_override_cache_control,
         test_stat_with_override_content_disposition,
         test_stat_with_override_content_type,
+        test_stat_with_override_content_type_direct,
+        test_stat_raw,
         test_stat_root,
+        test_stat_many,
         test_read_full,
+        test_read_with_chunk,
+        test_read_with_auto_concurrent,
+        test_read_with_ranges,
+        test_read_with_on_progress,
+        test_reader_with_buffer,
+        test_read_into_reuse_buffer,
+        test_read_with_verify_checksum,
         test_read_range,
+        test_read_http_range,
         test_read_large_range,
+        test_read_range_not_satisfied,
         test_reader_range,
         test_reader_from,
         test_reader_tail,
         test_read_not_exist,
         test_read_with_if_match,
         test_read_with_if_none_match,
+        test_reader_with_if_none_match,
+        test_read_if_modified,
+        test_read_with_if_modified_since,
         test_fuzz_reader_with_range,
         test_fuzz_offset_reader,
         test_fuzz_part_reader,
@@ -84,19 +126,46 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_read_with_override_content_disposition,
         test_read_with_override_content_type,
         test_delete_file,
+        test_delete_with_if_match,
         test_delete_empty_dir,
         test_delete_with_special_chars,
         test_delete_not_existing,
         test_delete_stream,
+        test_delete_entries,
         test_remove_one_file,
+        test_remove_via_report,
+        test_write_many,
+        test_batch_delete,
         test_writer_write,
+        test_writer_resume,
+        test_writer_with_verify_parts,
+        test_writer_close_with_metadata,
+        test_writer_with_content_length,
+        test_writer_write_with_concurrent,
+        test_write_with_concurrent,
         test_writer_sink,
         test_writer_copy,
         test_writer_abort,
         test_writer_futures_copy,
         test_fuzz_unsized_writer,
-        test_invalid_reader_seek
-    )
+        test_invalid_reader_seek,
+        test_reader_seek_footer_and_header
+    );
+
+    #[cfg(feature = "compress")]
+    trials.extend(async_trials!(
+        op,
+        test_write_with_compress,
+        test_writer_with_compress
+    ));
+
+    #[cfg(feature = "content-type-guess")]
+    trials.extend(async_trials!(
+        op,
+        test_write_with_content_type_from_extension
+    ));
+
+    trials
 }
 
 /// Create dir with dir path should succeed.
@@ -135,6 +204,45 @@ pub async fn test_create_dir_existing(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Create dir with content type and user metadata should succeed.
+pub async fn test_create_dir_with_metadata(op: Operator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let path = format!("{}/", uuid::Uuid::new_v4());
+
+    op.create_dir_with(&path)
+        .content_type("application/x-directory")
+        .user_metadata(HashMap::from([(
+            "owner".to_string(),
+            "opendal".to_string(),
+        )]))
+        .await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    // Options only take effect on services that create dirs via marker objects.
+    let native_create_dir = op.info().native_capability().create_dir;
+    if !native_create_dir && op.info().full_capability().write_with_content_type {
+        assert_eq!(meta.content_type(), Some("application/x-directory"));
+    }
+    if op.info().scheme() == opendal::Scheme::S3 {
+        let (meta, headers) = op.stat_raw(&path).await?;
+        assert_eq!(meta.mode(), EntryMode::DIR);
+        assert_eq!(
+            headers
+                .get("x-amz-meta-owner")
+                .and_then(|v| v.to_str().ok()),
+            Some("opendal")
+        );
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Write a single file and test with stat.
 pub async fn test_write_only(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -149,6 +257,27 @@ pub async fn test_write_only(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file and check the returned metadata.
+pub async fn test_write_returning_metadata(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let written = op.write(&path, content).await?;
+    assert!(written.is_file());
+    assert_eq!(written.content_length(), size as u64);
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    if written.metakey().contains(Metakey::Etag) {
+        assert_eq!(written.etag(), meta.etag(), "etag must match stat");
+    }
+    if written.metakey().contains(Metakey::Version) {
+        assert_eq!(written.version(), meta.version(), "version must match stat");
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Write a file with empty content.
 pub async fn test_write_with_empty_content(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_can_empty {
@@ -256,6 +385,40 @@ pub async fn test_write_with_content_type(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write with content type from extension should guess content type by path.
+#[cfg(feature = "content-type-guess")]
+pub async fn test_write_with_content_type_from_extension(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_type {
+        return Ok(());
+    }
+
+    let op = op.with_content_type_from_extension(true);
+    let parent = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let path = format!("{parent}/data.json");
+    op.write(&path, content.clone()).await?;
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(
+        meta.content_type().expect("content type must exist"),
+        "application/json"
+    );
+
+    // Explicit content type should always win.
+    let path = format!("{parent}/index.html");
+    op.write_with(&path, content)
+        .content_type("text/plain")
+        .await?;
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(
+        meta.content_type().expect("content type must exist"),
+        "text/plain"
+    );
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
 /// Write a single file with content disposition should succeed.
 pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_content_disposition {
@@ -283,225 +446,776 @@ pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     Ok(())
 }
 
-/// Stat existing file should return metadata
-pub async fn test_stat_file(op: Operator) -> Result<()> {
+/// Stat a file written without content disposition should return `None`.
+pub async fn test_write_without_content_disposition(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
-    let (content, size) = gen_bytes(op.info().full_capability());
+    let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content).await.expect("write must succeed");
 
-    let meta = op.stat(&path).await?;
-    assert_eq!(meta.mode(), EntryMode::FILE);
-    assert_eq!(meta.content_length(), size as u64);
-
-    // Stat a file with trailing slash should return `NotFound`.
-    if op.info().full_capability().create_dir {
-        let result = op.stat(&format!("{path}/")).await;
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
-    }
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_disposition(), None);
 
     op.delete(&path).await.expect("delete must succeed");
+
     Ok(())
 }
 
-/// Stat existing file should return metadata
-pub async fn test_stat_dir(op: Operator) -> Result<()> {
-    if !op.info().full_capability().create_dir {
+/// Write a single file with content encoding should succeed.
+pub async fn test_write_with_content_encoding(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_encoding {
         return Ok(());
     }
 
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
 
-    op.create_dir(&path).await.expect("write must succeed");
+    // Use `identity` here to make sure content will not be decoded by http clients.
+    let target_content_encoding = "identity";
+    op.write_with(&path, content.clone())
+        .content_encoding(target_content_encoding)
+        .await?;
 
-    let meta = op.stat(&path).await?;
-    assert_eq!(meta.mode(), EntryMode::DIR);
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(
+        meta.content_encoding()
+            .expect("content encoding must exist"),
+        target_content_encoding
+    );
+    assert_eq!(meta.content_length(), size as u64);
 
-    // Stat a dir without trailing slash could have two behavior.
-    let result = op.stat(path.trim_end_matches('/')).await;
-    match result {
-        Ok(meta) => assert_eq!(meta.mode(), EntryMode::DIR),
-        Err(err) => assert_eq!(err.kind(), ErrorKind::NotFound),
-    }
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
 
     op.delete(&path).await.expect("delete must succeed");
+
     Ok(())
 }
 
-/// Stat the parent dir of existing dir should return metadata
-pub async fn test_stat_nested_parent_dir(op: Operator) -> Result<()> {
-    if !op.info().full_capability().create_dir {
+/// Write a single file with content md5 should succeed, and mismatched md5 should be rejected.
+pub async fn test_write_with_content_md5(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_md5 {
         return Ok(());
     }
 
-    let parent = format!("{}", uuid::Uuid::new_v4());
-    let file = format!("{}", uuid::Uuid::new_v4());
-    let (content, _) = gen_bytes(op.info().full_capability());
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
 
-    op.write(&format!("{parent}/{file}"), content.clone())
-        .await
-        .expect("write must succeed");
+    op.write_with(&path, content.clone())
+        .content_md5(&format_content_md5(&content))
+        .await?;
 
-    let meta = op.stat(&format!("{parent}/")).await?;
-    assert_eq!(meta.mode(), EntryMode::DIR);
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let res = op
+        .write_with(&path, content.clone())
+        .content_md5(&format_content_md5(b"Hello, OpenDAL!"))
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ChecksumMismatch);
+
+    // Content md5 can't be sent while uploading in multipart.
+    if op.info().full_capability().write_can_multi {
+        let size = 5 * 1024 * 1024;
+        let content = gen_fixed_bytes(size);
+        let mut w = op
+            .writer_with(&path)
+            .content_md5(&format_content_md5(&content))
+            .await?;
+        let res = async {
+            w.write(content.clone()).await?;
+            w.write(content.clone()).await
+        }
+        .await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        w.abort().await?;
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
 
-    op.delete(&format!("{parent}/{file}"))
-        .await
-        .expect("delete must succeed");
     Ok(())
 }
 
-/// Stat existing file with special chars should return metadata
-pub async fn test_stat_with_special_chars(op: Operator) -> Result<()> {
-    // Ignore test for supabase until https://github.com/apache/incubator-opendal/issues/2194 addressed.
-    if op.info().scheme() == opendal::Scheme::Supabase {
-        warn!("ignore test for supabase until https://github.com/apache/incubator-opendal/issues/2194 is resolved");
-        return Ok(());
-    }
-    // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
-    if op.info().scheme() == opendal::Scheme::Atomicserver {
-        warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
+/// Write a single file with storage class should succeed.
+pub async fn test_write_with_storage_class(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_storage_class {
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = uuid::Uuid::new_v4().to_string();
     let (content, size) = gen_bytes(op.info().full_capability());
 
-    op.write(&path, content).await.expect("write must succeed");
+    // `STANDARD` is supported by both s3 and gcs.
+    op.write_with(&path, content.clone())
+        .storage_class("STANDARD")
+        .await?;
 
-    let meta = op.stat(&path).await?;
-    assert_eq!(meta.mode(), EntryMode::FILE);
+    let meta = op.stat(&path).await.expect("stat must succeed");
     assert_eq!(meta.content_length(), size as u64);
 
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
     op.delete(&path).await.expect("delete must succeed");
+
     Ok(())
 }
 
-/// Stat not cleaned path should also succeed.
-pub async fn test_stat_not_cleaned_path(op: Operator) -> Result<()> {
+/// Write a single file with expires should succeed.
+pub async fn test_write_with_expires(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
-    debug!("Generate a random file: {}", &path);
-    let (content, size) = gen_bytes(op.info().full_capability());
+    let (content, _) = gen_bytes(op.info().full_capability());
 
-    op.write(&path, content).await.expect("write must succeed");
+    let expires = SystemTime::now() + Duration::from_secs(3600);
 
-    let meta = op.stat(&format!("//{}", &path)).await?;
-    assert_eq!(meta.mode(), EntryMode::FILE);
-    assert_eq!(meta.content_length(), size as u64);
+    if !op.info().full_capability().write_with_expires {
+        let res = op.write_with(&path, content.clone()).expires(expires).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
 
-    op.delete(&path).await.expect("delete must succeed");
-    Ok(())
-}
+        // Expires should be ignored while allow_unsupported is set.
+        op.write_with(&path, content)
+            .expires(expires)
+            .allow_unsupported(true)
+            .await?;
+        op.delete(&path).await.expect("delete must succeed");
+        return Ok(());
+    }
 
-/// Stat not exist file should return NotFound
-pub async fn test_stat_not_exist(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    op.write_with(&path, content).expires(expires).await?;
 
-    // Stat not exist file should returns NotFound.
-    let meta = op.stat(&path).await;
-    assert!(meta.is_err());
-    assert_eq!(meta.unwrap_err().kind(), ErrorKind::NotFound);
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    let expected = expires
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("expires must be after epoch")
+        .as_secs() as i64;
+    assert_eq!(
+        meta.expires().expect("expires must exist").timestamp(),
+        expected,
+        "expires should be truncated to seconds"
+    );
 
-    // Stat not exist dir should also returns NotFound.
-    if op.info().full_capability().create_dir {
-        let meta = op.stat(&format!("{path}/")).await;
-        assert!(meta.is_err());
-        assert_eq!(meta.unwrap_err().kind(), ErrorKind::NotFound);
-    }
+    op.delete(&path).await.expect("delete must succeed");
 
     Ok(())
 }
 
-/// Stat with if_match should succeed, else get a ConditionNotMatch error.
-pub async fn test_stat_with_if_match(op: Operator) -> Result<()> {
-    if !op.info().full_capability().stat_with_if_match {
+/// Write with if not exists should not overwrite existing file.
+pub async fn test_write_with_if_not_exists(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_not_exists {
         return Ok(());
     }
 
     let path = uuid::Uuid::new_v4().to_string();
-    debug!("Generate a random file: {}", &path);
-    let (content, size) = gen_bytes(op.info().full_capability());
-
-    op.write(&path, content.clone())
-        .await
-        .expect("write must succeed");
+    let (content, _) = gen_bytes(op.info().full_capability());
 
-    let meta = op.stat(&path).await?;
-    assert_eq!(meta.mode(), EntryMode::FILE);
-    assert_eq!(meta.content_length(), size as u64);
+    op.write_with(&path, content.clone())
+        .if_not_exists(true)
+        .await?;
 
-    let res = op.stat_with(&path).if_match("\"invalid_etag\"").await;
+    let (new_content, _) = gen_bytes(op.info().full_capability());
+    let res = op.write_with(&path, new_content).if_not_exists(true).await;
     assert!(res.is_err());
     assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
 
-    let result = op
-        .stat_with(&path)
-        .if_match(meta.etag().expect("etag must exist"))
-        .await;
-    assert!(result.is_ok());
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
 
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
 
-/// Stat with if_none_match should succeed, else get a ConditionNotMatch.
-pub async fn test_stat_with_if_none_match(op: Operator) -> Result<()> {
-    if !op.info().full_capability().stat_with_if_none_match {
+/// Writer with if none match should not overwrite existing file.
+pub async fn test_writer_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_none_match {
         return Ok(());
     }
 
     let path = uuid::Uuid::new_v4().to_string();
-    debug!("Generate a random file: {}", &path);
-    let (content, size) = gen_bytes(op.info().full_capability());
+    let (content, _) = gen_bytes(op.info().full_capability());
 
-    op.write(&path, content.clone())
-        .await
-        .expect("write must succeed");
+    let mut w = op.writer_with(&path).if_none_match("*").await?;
+    w.write(content.clone()).await?;
+    w.close().await?;
 
-    let meta = op.stat(&path).await?;
-    assert_eq!(meta.mode(), EntryMode::FILE);
-    assert_eq!(meta.content_length(), size as u64);
+    let (new_content, _) = gen_bytes(op.info().full_capability());
+    let mut w = op.writer_with(&path).if_none_match("*").await?;
+    w.write(new_content).await?;
+    let res = w.close().await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::ConditionNotMatch)
+    );
+    // Clean up the uploaded parts.
+    let _ = w.abort().await;
 
-    let res = op
-        .stat_with(&path)
-        .if_none_match(meta.etag().expect("etag must exist"))
-        .await;
-    assert!(res.is_err());
-    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
 
+    // if_none_match can't be used together with if_not_exists.
     let res = op
-        .stat_with(&path)
-        .if_none_match("\"invalid_etag\"")
-        .await?;
-    assert_eq!(res.mode(), meta.mode());
-    assert_eq!(res.content_length(), meta.content_length());
+        .writer_with(&path)
+        .if_none_match("*")
+        .if_not_exists(true)
+        .await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
 
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
 
-/// Stat file with override-cache-control should succeed.
-pub async fn test_stat_with_override_cache_control(op: Operator) -> Result<()> {
-    if !(op.info().full_capability().stat_with_override_cache_control
-        && op.info().full_capability().presign)
-    {
+/// Writer with if match should only overwrite file with matched etag.
+pub async fn test_writer_with_if_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_match {
         return Ok(());
     }
 
     let path = uuid::Uuid::new_v4().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
+    op.write(&path, content.clone()).await?;
+    let meta = op.stat(&path).await?;
+    let etag = meta.etag().expect("etag must exist");
 
-    op.write(&path, content.clone())
-        .await
-        .expect("write must succeed");
+    let (new_content, _) = gen_bytes(op.info().full_capability());
+    let mut w = op.writer_with(&path).if_match("\"invalid_etag\"").await?;
+    w.write(new_content.clone()).await?;
+    let res = w.close().await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::ConditionNotMatch)
+    );
+    // Clean up the uploaded parts.
+    let _ = w.abort().await;
+
+    let mut w = op.writer_with(&path).if_match(etag).await?;
+    w.write(new_content.clone()).await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&new_content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Write a single file with progress callback should report all bytes.
+pub async fn test_write_with_on_progress(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let cloned = reports.clone();
+    op.write_with(&path, content.clone())
+        .on_progress(move |written, total| cloned.lock().unwrap().push((written, total)))
+        .await?;
+
+    let reports = reports.lock().unwrap().clone();
+    assert!(!reports.is_empty(), "progress must be reported");
+    assert!(
+        reports.windows(2).all(|v| v[0].0 < v[1].0),
+        "progress must be increasing"
+    );
+    assert_eq!(
+        reports.last().cloned(),
+        Some((size as u64, Some(size as u64)))
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Xor every byte with the given key, which is symmetric for reading and writing.
+struct XorTransform(u8);
+
+impl Transform for XorTransform {
+    fn transform(&mut self, chunk: &[u8]) -> opendal::Result<Bytes> {
+        Ok(chunk.iter().map(|b| b ^ self.0).collect())
+    }
+}
+
+/// Write with transform should store the transformed content, and read with
+/// the same transform should return the original content.
+pub async fn test_write_with_transform(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write_with(&path, content.clone())
+        .transform(Box::new(XorTransform(0x5a)))
+        .await?;
+
+    let raw = op.read(&path).await?;
+    let expected: Vec<u8> = content.iter().map(|b| b ^ 0x5a).collect();
+    assert_eq!(raw, expected, "stored content must be transformed");
+
+    let bs = op
+        .read_with(&path)
+        .transform(Box::new(XorTransform(0x5a)))
+        .await?;
+    assert_eq!(bs, content, "read with transform must be restored");
+
+    let mut r = op
+        .reader_with(&path)
+        .transform(Box::new(XorTransform(0x5a)))
+        .await?;
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(bs, content, "reader with transform must be restored");
+
+    // Transform must be applied from the start of the content.
+    let res = op
+        .read_with(&path)
+        .range(1..)
+        .transform(Box::new(XorTransform(0x5a)))
+        .await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Restore on services without archival tiers should return Unsupported.
+pub async fn test_restore_not_supported(op: Operator) -> Result<()> {
+    if op.info().full_capability().restore {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let res = op.restore(&path).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Stat existing file should return metadata
+pub async fn test_stat_file(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    // Stat a file with trailing slash should return `NotFound`.
+    if op.info().full_capability().create_dir {
+        let result = op.stat(&format!("{path}/")).await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat raw should return metadata along with raw response headers.
+pub async fn test_stat_raw(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    if !op.info().full_capability().stat_with_raw_headers {
+        let res = op.stat_raw(&path).await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+
+        op.delete(&path).await.expect("delete must succeed");
+        return Ok(());
+    }
+
+    let (meta, headers) = op.stat_raw(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+    assert_eq!(
+        headers
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok()),
+        Some(size.to_string().as_str())
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat existing file should return metadata
+pub async fn test_stat_dir(op: Operator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let path = format!("{}/", uuid::Uuid::new_v4());
+
+    op.create_dir(&path).await.expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    // Stat a dir without trailing slash could have two behavior.
+    let result = op.stat(path.trim_end_matches('/')).await;
+    match result {
+        Ok(meta) => assert_eq!(meta.mode(), EntryMode::DIR),
+        Err(err) => assert_eq!(err.kind(), ErrorKind::NotFound),
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// is_dir and is_file should only return true for the matching mode.
+pub async fn test_is_dir_and_is_file(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    assert!(op.is_file(&path).await?);
+    assert!(!op.is_dir(&path).await?);
+
+    if op.info().full_capability().create_dir {
+        let dir = format!("{}/", uuid::Uuid::new_v4());
+        op.create_dir(&dir).await.expect("create dir must succeed");
+
+        assert!(op.is_dir(&dir).await?);
+        assert!(!op.is_file(&dir).await?);
+
+        op.delete(&dir).await.expect("delete must succeed");
+    }
+
+    let not_exist = uuid::Uuid::new_v4().to_string();
+    assert!(!op.is_file(&not_exist).await?);
+    assert!(!op.is_dir(&format!("{not_exist}/")).await?);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat the parent dir of existing dir should return metadata
+pub async fn test_stat_nested_parent_dir(op: Operator) -> Result<()> {
+    if !op.info().full_capability().create_dir {
+        return Ok(());
+    }
+
+    let parent = format!("{}", uuid::Uuid::new_v4());
+    let file = format!("{}", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{parent}/{file}"), content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&format!("{parent}/")).await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    op.delete(&format!("{parent}/{file}"))
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat existing file with special chars should return metadata
+pub async fn test_stat_with_special_chars(op: Operator) -> Result<()> {
+    // Ignore test for supabase until https://github.com/apache/incubator-opendal/issues/2194 addressed.
+    if op.info().scheme() == opendal::Scheme::Supabase {
+        warn!("ignore test for supabase until https://github.com/apache/incubator-opendal/issues/2194 is resolved");
+        return Ok(());
+    }
+    // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
+    if op.info().scheme() == opendal::Scheme::Atomicserver {
+        warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
+        return Ok(());
+    }
+
+    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat not cleaned path should also succeed.
+pub async fn test_stat_not_cleaned_path(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let meta = op.stat(&format!("//{}", &path)).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat not exist file should return NotFound
+pub async fn test_stat_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    // Stat not exist file should returns NotFound.
+    let meta = op.stat(&path).await;
+    assert!(meta.is_err());
+    assert_eq!(meta.unwrap_err().kind(), ErrorKind::NotFound);
+
+    // Stat not exist dir should also returns NotFound.
+    if op.info().full_capability().create_dir {
+        let meta = op.stat(&format!("{path}/")).await;
+        assert!(meta.is_err());
+        assert_eq!(meta.unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Stat with if_match should succeed, else get a ConditionNotMatch error.
+pub async fn test_stat_with_if_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    let res = op.stat_with(&path).if_match("\"invalid_etag\"").await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let result = op
+        .stat_with(&path)
+        .if_match(meta.etag().expect("etag must exist"))
+        .await;
+    assert!(result.is_ok());
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat with if_none_match should succeed, else get a ConditionNotMatch.
+pub async fn test_stat_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_none_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    let res = op
+        .stat_with(&path)
+        .if_none_match(meta.etag().expect("etag must exist"))
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let res = op
+        .stat_with(&path)
+        .if_none_match("\"invalid_etag\"")
+        .await?;
+    assert_eq!(res.mode(), meta.mode());
+    assert_eq!(res.content_length(), meta.content_length());
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat with if_modified_since should succeed, otherwise get a ConditionNotMatch error.
+pub async fn test_stat_with_if_modified_since(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_modified_since {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    let last_modified: SystemTime = meta
+        .last_modified()
+        .expect("last_modified must exist")
+        .into();
+
+    let res = op
+        .stat_with(&path)
+        .if_modified_since(last_modified - Duration::from_secs(60))
+        .await?;
+    assert_eq!(res.content_length(), meta.content_length());
+
+    let res = op
+        .stat_with(&path)
+        .if_modified_since(last_modified + Duration::from_secs(60))
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat with if_unmodified_since should succeed, otherwise get a ConditionNotMatch error.
+pub async fn test_stat_with_if_unmodified_since(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_if_unmodified_since {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+    let last_modified: SystemTime = meta
+        .last_modified()
+        .expect("last_modified must exist")
+        .into();
+
+    let res = op
+        .stat_with(&path)
+        .if_unmodified_since(last_modified + Duration::from_secs(60))
+        .await?;
+    assert_eq!(res.content_length(), meta.content_length());
+
+    let res = op
+        .stat_with(&path)
+        .if_unmodified_since(last_modified - Duration::from_secs(60))
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat file with override-cache-control should succeed.
+pub async fn test_stat_with_override_cache_control(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().stat_with_override_cache_control
+        && op.info().full_capability().presign)
+    {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
 
     let target_cache_control = "no-cache, no-store, must-revalidate";
     let signed_req = op
         .presign_stat_with(&path, Duration::from_secs(60))
-        .override_cache_control(target_cache_control)
+        .override_cache_control(target_cache_control)
+        .await
+        .expect("sign must succeed");
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send must succeed");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get("cache-control")
+            .expect("cache-control header must exist")
+            .to_str()
+            .expect("cache-control header must be string"),
+        target_cache_control
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Stat file with override_content_disposition should succeed.
+pub async fn test_stat_with_override_content_disposition(op: Operator) -> Result<()> {
+    if !(op
+        .info()
+        .full_capability()
+        .stat_with_override_content_disposition
+        && op.info().full_capability().presign)
+    {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let target_content_disposition = "attachment; filename=foo.txt";
+
+    let signed_req = op
+        .presign_stat_with(&path, Duration::from_secs(60))
+        .override_content_disposition(target_content_disposition)
         .await
-        .expect("sign must succeed");
+        .expect("presign must succeed");
 
     let client = reqwest::Client::new();
     let mut req = client.request(
@@ -517,140 +1231,441 @@ pub async fn test_stat_with_override_cache_control(op: Operator) -> Result<()> {
     assert_eq!(resp.status(), StatusCode::OK);
     assert_eq!(
         resp.headers()
-            .get("cache-control")
-            .expect("cache-control header must exist")
+            .get(http::header::CONTENT_DISPOSITION)
+            .expect("content-disposition header must exist")
             .to_str()
-            .expect("cache-control header must be string"),
-        target_cache_control
+            .expect("content-disposition header must be string"),
+        target_content_disposition
     );
 
     op.delete(&path).await.expect("delete must succeed");
+
     Ok(())
 }
 
-/// Stat file with override_content_disposition should succeed.
-pub async fn test_stat_with_override_content_disposition(op: Operator) -> Result<()> {
-    if !(op
-        .info()
-        .full_capability()
-        .stat_with_override_content_disposition
+/// Stat with override content type should return the overridden value directly.
+pub async fn test_stat_with_override_content_type_direct(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_override_content_type {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let target_content_type = "application/opendal";
+
+    let meta = op
+        .stat_with(&path)
+        .override_content_type(target_content_type)
+        .await
+        .expect("stat must succeed");
+    assert_eq!(meta.content_type(), Some(target_content_type));
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Stat file with override_content_type should succeed.
+pub async fn test_stat_with_override_content_type(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().stat_with_override_content_type
         && op.info().full_capability().presign)
     {
         return Ok(());
     }
 
     let path = uuid::Uuid::new_v4().to_string();
-    let (content, _) = gen_bytes(op.info().full_capability());
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let target_content_type = "application/opendal";
+
+    let signed_req = op
+        .presign_stat_with(&path, Duration::from_secs(60))
+        .override_content_type(target_content_type)
+        .await
+        .expect("presign must succeed");
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send must succeed");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get(http::header::CONTENT_TYPE)
+            .expect("content-type header must exist")
+            .to_str()
+            .expect("content-type header must be string"),
+        target_content_type
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Root should be able to stat and returns DIR.
+pub async fn test_stat_root(op: Operator) -> Result<()> {
+    let meta = op.stat("").await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    let meta = op.stat("/").await?;
+    assert_eq!(meta.mode(), EntryMode::DIR);
+
+    Ok(())
+}
+
+/// Stat many should return results for all given paths.
+pub async fn test_stat_many(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+    op.write(&path, content).await.expect("write must succeed");
+
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    let mut results: Vec<_> = op
+        .stat_many(stream::iter(vec![path.clone(), not_exist.clone()]))
+        .collect()
+        .await;
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(results.len(), 2);
+
+    for (p, res) in results {
+        if p == path {
+            let meta = res.expect("stat must succeed");
+            assert_eq!(meta.content_length(), size as u64);
+        } else {
+            assert_eq!(p, not_exist);
+            assert_eq!(
+                res.err().map(|e| e.kind()),
+                Some(ErrorKind::NotFound),
+                "stat not exist path must return NotFound"
+            );
+        }
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read full content should match.
+pub async fn test_read_full(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op.read(&path).await?;
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with chunk should return the full content.
+pub async fn test_read_with_chunk(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op.read_with(&path).chunk(size / 3 + 1).await?;
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    let res = op.read_with(&path).chunk(0).await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+    let res = op.reader_with(&path).chunk(0).await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with auto concurrent should return the whole content.
+pub async fn test_read_with_auto_concurrent(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    // Make sure the content is large enough to be split.
+    let size = 9 * 1024 * 1024;
+    if !cap.read_with_range || matches!(cap.write_total_max_size, Some(v) if v < size) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let content = gen_fixed_bytes(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op
+        .read_with(&path)
+        .auto_concurrent()
+        .memory_budget(4 * 1024 * 1024)
+        .await?;
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Write with compress should be read back via decompress.
+#[cfg(feature = "compress")]
+pub async fn test_write_with_compress(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let res = op
+        .write_with(&path, content.clone())
+        .compress(opendal::Compression::Zstd)
+        .await;
+    // Compressed content can't be recognized without content encoding.
+    if !op.info().full_capability().write_with_content_encoding {
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        return Ok(());
+    }
+    res?;
+
+    let bs = op
+        .read_with(&path)
+        .decompress(opendal::Compression::Auto)
+        .await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Writer with compress should compress chunks while writing.
+#[cfg(feature = "compress")]
+pub async fn test_writer_with_compress(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_encoding {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content_a, _) = gen_bytes(op.info().full_capability());
+    let (content_b, _) = gen_bytes(op.info().full_capability());
+
+    let mut w = op
+        .writer_with(&path)
+        .compress(opendal::Compression::Zstd)
+        .await?;
+    w.write(content_a.clone()).await?;
+    w.write(content_b.clone()).await?;
+    w.close().await?;
+
+    let bs = op
+        .read_with(&path)
+        .decompress(opendal::Compression::Zstd)
+        .await?;
+    assert_eq!(bs.len(), content_a.len() + content_b.len());
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest([content_a, content_b].concat())),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with multiple ranges should return the content of every range.
+pub async fn test_read_with_ranges(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let ranges: Vec<_> = (0..3)
+        .map(|_| {
+            let (offset, length) = gen_offset_length(size);
+            offset..offset + length
+        })
+        .collect();
+
+    let bss = op.read_with(&path).ranges(ranges.clone()).await?;
+    assert_eq!(bss.len(), ranges.len());
+    for (bs, range) in bss.iter().zip(ranges) {
+        assert_eq!(
+            format!("{:x}", Sha256::digest(bs)),
+            format!(
+                "{:x}",
+                Sha256::digest(&content[range.start as usize..range.end as usize])
+            ),
+            "read content"
+        );
+    }
+
+    let res = op
+        .read_with(&path)
+        .verify_checksum(true)
+        .ranges(vec![0..1])
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with progress callback should report all bytes.
+pub async fn test_read_with_on_progress(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content.clone())
         .await
         .expect("write must succeed");
 
-    let target_content_disposition = "attachment; filename=foo.txt";
-
-    let signed_req = op
-        .presign_stat_with(&path, Duration::from_secs(60))
-        .override_content_disposition(target_content_disposition)
-        .await
-        .expect("presign must succeed");
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let cloned = reports.clone();
+    let bs = op
+        .read_with(&path)
+        .on_progress(move |read, total| cloned.lock().unwrap().push((read, total)))
+        .await?;
+    assert_eq!(size, bs.len(), "read size");
 
-    let client = reqwest::Client::new();
-    let mut req = client.request(
-        signed_req.method().clone(),
-        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    let reports = reports.lock().unwrap().clone();
+    assert!(!reports.is_empty(), "progress must be reported");
+    assert!(
+        reports.windows(2).all(|v| v[0].0 < v[1].0),
+        "progress must be increasing without zero-byte reads"
     );
-    for (k, v) in signed_req.header() {
-        req = req.header(k, v);
-    }
-
-    let resp = req.send().await.expect("send must succeed");
-
-    assert_eq!(resp.status(), StatusCode::OK);
     assert_eq!(
-        resp.headers()
-            .get(http::header::CONTENT_DISPOSITION)
-            .expect("content-disposition header must exist")
-            .to_str()
-            .expect("content-disposition header must be string"),
-        target_content_disposition
+        reports.last().cloned(),
+        Some((size as u64, Some(size as u64)))
     );
 
     op.delete(&path).await.expect("delete must succeed");
-
     Ok(())
 }
 
-/// Stat file with override_content_type should succeed.
-pub async fn test_stat_with_override_content_type(op: Operator) -> Result<()> {
-    if !(op.info().full_capability().stat_with_override_content_type
-        && op.info().full_capability().presign)
-    {
-        return Ok(());
-    }
-
+/// Reader with buffer should serve small reads and seeks correctly.
+pub async fn test_reader_with_buffer(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
-    let (content, _) = gen_bytes(op.info().full_capability());
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content.clone())
         .await
         .expect("write must succeed");
 
-    let target_content_type = "application/opendal";
-
-    let signed_req = op
-        .presign_stat_with(&path, Duration::from_secs(60))
-        .override_content_type(target_content_type)
-        .await
-        .expect("presign must succeed");
-
-    let client = reqwest::Client::new();
-    let mut req = client.request(
-        signed_req.method().clone(),
-        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
-    );
-    for (k, v) in signed_req.header() {
-        req = req.header(k, v);
-    }
+    let mut r = op.reader_with(&path).buffer(size / 3 + 1).await?;
 
-    let resp = req.send().await.expect("send must succeed");
+    let mut head = vec![0; 16.min(size)];
+    r.read_exact(&mut head).await?;
+    assert_eq!(head, content[..head.len()], "read head");
 
-    assert_eq!(resp.status(), StatusCode::OK);
+    // Seek back into the buffered window.
+    r.seek(std::io::SeekFrom::Start(0)).await?;
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(size, bs.len(), "read size");
     assert_eq!(
-        resp.headers()
-            .get(http::header::CONTENT_TYPE)
-            .expect("content-type header must exist")
-            .to_str()
-            .expect("content-type header must be string"),
-        target_content_type
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
     );
 
     op.delete(&path).await.expect("delete must succeed");
-
     Ok(())
 }
 
-/// Root should be able to stat and returns DIR.
-pub async fn test_stat_root(op: Operator) -> Result<()> {
-    let meta = op.stat("").await?;
-    assert_eq!(meta.mode(), EntryMode::DIR);
+/// Read into a reused buffer should match independent reads.
+pub async fn test_read_into_reuse_buffer(op: Operator) -> Result<()> {
+    let (path_a, path_b) = (
+        uuid::Uuid::new_v4().to_string(),
+        uuid::Uuid::new_v4().to_string(),
+    );
+    let (content_a, _) = gen_bytes(op.info().full_capability());
+    let (content_b, _) = gen_bytes(op.info().full_capability());
 
-    let meta = op.stat("/").await?;
-    assert_eq!(meta.mode(), EntryMode::DIR);
+    op.write(&path_a, content_a)
+        .await
+        .expect("write must succeed");
+    op.write(&path_b, content_b)
+        .await
+        .expect("write must succeed");
+
+    let mut buf = Vec::new();
+    for path in [&path_a, &path_b] {
+        let n = op.read_into(path, &mut buf).await?;
+        let bs = op.read(path).await?;
+        assert_eq!(n, bs.len(), "read size");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&buf)),
+            format!("{:x}", Sha256::digest(&bs)),
+            "read content"
+        );
+    }
 
+    op.delete(&path_a).await.expect("delete must succeed");
+    op.delete(&path_b).await.expect("delete must succeed");
     Ok(())
 }
 
-/// Read full content should match.
-pub async fn test_read_full(op: Operator) -> Result<()> {
+/// Read with verify_checksum should succeed for untouched content.
+pub async fn test_read_with_verify_checksum(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
-    debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content.clone())
         .await
         .expect("write must succeed");
 
-    let bs = op.read(&path).await?;
+    let bs = op.read_with(&path).verify_checksum(true).await?;
     assert_eq!(size, bs.len(), "read size");
     assert_eq!(
         format!("{:x}", Sha256::digest(&bs)),
@@ -658,6 +1673,11 @@ pub async fn test_read_full(op: Operator) -> Result<()> {
         "read content"
     );
 
+    let mut r = op.reader_with(&path).verify_checksum(true).await?;
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(size, bs.len(), "read size");
+
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
@@ -692,6 +1712,51 @@ pub async fn test_read_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read with http range header value should match.
+pub async fn test_read_http_range(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op
+        .read_with(&path)
+        .http_range(&format!("bytes={}-{}", offset, offset + length - 1))
+        .await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!(
+            "{:x}",
+            Sha256::digest(&content[offset as usize..(offset + length) as usize])
+        ),
+        "read content"
+    );
+
+    let bs = op
+        .read_with(&path)
+        .http_range(&format!("bytes=-{length}"))
+        .await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content[size - length as usize..])),
+        "read suffix content"
+    );
+
+    let res = op.read_with(&path).http_range("bytes=10-1").await;
+    assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read large range content should match.
 pub async fn test_read_large_range(op: Operator) -> Result<()> {
     if !op.info().full_capability().read_with_range {
@@ -723,6 +1788,59 @@ pub async fn test_read_large_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read with range beyond the end should be validated or clamped.
+pub async fn test_read_range_not_satisfied(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let res = op.read_with(&path).range(size as u64 + 1..).await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::RangeNotSatisfied),
+        "read with offset beyond the end"
+    );
+
+    let bs = op.read_with(&path).range(size as u64..).await?;
+    assert!(bs.is_empty(), "read with offset at the end");
+
+    let res = op.read_with(&path).range(u64::MAX..).await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::RangeNotSatisfied),
+        "read with offset at u64::MAX"
+    );
+
+    let bs = op
+        .read_with(&path)
+        .range(size as u64 - 1..=u64::MAX)
+        .await?;
+    assert_eq!(
+        bs,
+        content[size - 1..],
+        "read with inclusive end at u64::MAX"
+    );
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let res = op.read_with(&path).range(10..5).await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::RangeNotSatisfied),
+        "read with inverted range"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read range content should match.
 pub async fn test_reader_range(op: Operator) -> Result<()> {
     if !op.info().full_capability().read_with_range {
@@ -830,16 +1948,127 @@ pub async fn test_reader_tail(op: Operator) -> Result<()> {
 pub async fn test_read_not_exist(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
 
-    let bs = op.read(&path).await;
-    assert!(bs.is_err());
-    assert_eq!(bs.unwrap_err().kind(), ErrorKind::NotFound);
+    let bs = op.read(&path).await;
+    assert!(bs.is_err());
+    assert_eq!(bs.unwrap_err().kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
+
+/// Read with if_match should match, else get a ConditionNotMatch error.
+pub async fn test_read_with_if_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+
+    let res = op.read_with(&path).if_match("\"invalid_etag\"").await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let bs = op
+        .read_with(&path)
+        .if_match(meta.etag().expect("etag must exist"))
+        .await
+        .expect("read must succeed");
+    assert_eq!(bs, content);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read if modified should return None if the etag matches.
+pub async fn test_read_if_modified(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_none_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+
+    let bs = op
+        .read_if_modified(&path, meta.etag().expect("etag must exist"))
+        .await?;
+    assert!(bs.is_none(), "file is not modified");
+
+    let bs = op.read_if_modified(&path, "\"invalid_etag\"").await?;
+    assert_eq!(bs, Some(content));
+
+    // The condition should also be checked for empty file.
+    op.write(&path, vec![]).await.expect("write must succeed");
+    let meta = op.stat(&path).await?;
+    if let Some(etag) = meta.etag() {
+        let bs = op.read_if_modified(&path, etag).await?;
+        assert!(bs.is_none(), "empty file is not modified");
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Reader with if_none_match should match, else get a ConditionNotMatch error.
+pub async fn test_reader_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_none_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await?;
+
+    // Reader could be lazy, so the error could be returned while reading.
+    let res = match op
+        .reader_with(&path)
+        .if_none_match(meta.etag().expect("etag must exist"))
+        .await
+    {
+        Ok(r) => {
+            let bs: opendal::Result<Vec<Bytes>> = r.into_bytes_stream().try_collect().await;
+            bs.map(|_| ())
+        }
+        Err(err) => Err(err),
+    };
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let mut r = op
+        .reader_with(&path)
+        .if_none_match("\"invalid_etag\"")
+        .await
+        .expect("reader must be created");
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(bs, content);
 
+    op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
 
-/// Read with if_match should match, else get a ConditionNotMatch error.
-pub async fn test_read_with_if_match(op: Operator) -> Result<()> {
-    if !op.info().full_capability().read_with_if_match {
+/// Read with if_none_match should match, else get a ConditionNotMatch error.
+pub async fn test_read_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_none_match {
         return Ok(());
     }
 
@@ -853,13 +2082,16 @@ pub async fn test_read_with_if_match(op: Operator) -> Result<()> {
 
     let meta = op.stat(&path).await?;
 
-    let res = op.read_with(&path).if_match("\"invalid_etag\"").await;
+    let res = op
+        .read_with(&path)
+        .if_none_match(meta.etag().expect("etag must exist"))
+        .await;
     assert!(res.is_err());
     assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
 
     let bs = op
         .read_with(&path)
-        .if_match(meta.etag().expect("etag must exist"))
+        .if_none_match("\"invalid_etag\"")
         .await
         .expect("read must succeed");
     assert_eq!(bs, content);
@@ -868,14 +2100,13 @@ pub async fn test_read_with_if_match(op: Operator) -> Result<()> {
     Ok(())
 }
 
-/// Read with if_none_match should match, else get a ConditionNotMatch error.
-pub async fn test_read_with_if_none_match(op: Operator) -> Result<()> {
-    if !op.info().full_capability().read_with_if_none_match {
+/// Read with if_modified_since should match, otherwise get a ConditionNotMatch error.
+pub async fn test_read_with_if_modified_since(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_if_modified_since {
         return Ok(());
     }
 
     let path = uuid::Uuid::new_v4().to_string();
-    debug!("Generate a random file: {}", &path);
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content.clone())
@@ -883,21 +2114,28 @@ pub async fn test_read_with_if_none_match(op: Operator) -> Result<()> {
         .expect("write must succeed");
 
     let meta = op.stat(&path).await?;
+    let last_modified: SystemTime = meta
+        .last_modified()
+        .expect("last_modified must exist")
+        .into();
+
+    let bs = op
+        .read_with(&path)
+        .if_modified_since(last_modified - Duration::from_secs(60))
+        .await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
 
     let res = op
         .read_with(&path)
-        .if_none_match(meta.etag().expect("etag must exist"))
+        .if_modified_since(last_modified + Duration::from_secs(60))
         .await;
     assert!(res.is_err());
     assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
 
-    let bs = op
-        .read_with(&path)
-        .if_none_match("\"invalid_etag\"")
-        .await
-        .expect("read must succeed");
-    assert_eq!(bs, content);
-
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
@@ -1271,6 +2509,35 @@ pub async fn test_delete_file(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Delete with if_match should only delete the file if etag matches.
+pub async fn test_delete_with_if_match(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    if !op.info().full_capability().delete_with_if_match {
+        let res = op.delete_with(&path).if_match("\"invalid_etag\"").await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        return Ok(());
+    }
+
+    op.write(&path, content).await.expect("write must succeed");
+    let meta = op.stat(&path).await?;
+
+    let res = op.delete_with(&path).if_match("\"invalid_etag\"").await;
+    assert_eq!(
+        res.err().map(|e| e.kind()),
+        Some(ErrorKind::ConditionNotMatch)
+    );
+    assert!(op.is_exist(&path).await?);
+
+    op.delete_with(&path)
+        .if_match(meta.etag().expect("etag must exist"))
+        .await?;
+    assert!(!op.is_exist(&path).await?);
+
+    Ok(())
+}
+
 /// Delete empty dir should succeed.
 pub async fn test_delete_empty_dir(op: Operator) -> Result<()> {
     if !op.info().full_capability().create_dir {
@@ -1348,6 +2615,88 @@ pub async fn test_remove_one_file(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Remove via report should report result of every path.
+pub async fn test_remove_via_report(op: Operator) -> Result<()> {
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let mut paths = Vec::new();
+    for _ in 0..3 {
+        let path = uuid::Uuid::new_v4().to_string();
+        op.write(&path, content.clone())
+            .await
+            .expect("write must succeed");
+        paths.push(path);
+    }
+    // Deleting a file that does not exist should also succeed.
+    paths.push(uuid::Uuid::new_v4().to_string());
+
+    let mut reports = op.remove_via_report(stream::iter(paths.clone())).await;
+    reports.sort_by(|a, b| a.0.cmp(&b.0));
+    paths.sort();
+
+    assert_eq!(
+        reports.iter().map(|(p, _)| p.clone()).collect::<Vec<_>>(),
+        paths
+    );
+    for (path, result) in reports {
+        assert!(result.is_ok(), "remove {path} must succeed");
+        assert!(!op.is_exist(&path).await?);
+    }
+
+    Ok(())
+}
+
+/// Write many should report result of every path.
+pub async fn test_write_many(op: Operator) -> Result<()> {
+    let mut inputs = Vec::new();
+    for _ in 0..8 {
+        let path = uuid::Uuid::new_v4().to_string();
+        let (content, _) = gen_bytes_with_range(1..1024);
+        inputs.push((path, Bytes::from(content)));
+    }
+    // Writing to a dir path should fail without affecting others.
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let mut reports = op
+        .write_many(stream::iter(
+            inputs
+                .iter()
+                .cloned()
+                .chain([(dir.clone(), Bytes::from("Hello"))]),
+        ))
+        .await;
+    reports.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut paths: Vec<_> = inputs
+        .iter()
+        .map(|(p, _)| p.clone())
+        .chain([dir.clone()])
+        .collect();
+    paths.sort();
+    assert_eq!(
+        reports.iter().map(|(p, _)| p.clone()).collect::<Vec<_>>(),
+        paths
+    );
+
+    for (path, result) in reports {
+        if path == dir {
+            assert_eq!(
+                result.expect_err("write to dir must fail").kind(),
+                ErrorKind::IsADirectory
+            );
+        } else {
+            assert!(result.is_ok(), "write {path} must succeed");
+        }
+    }
+    for (path, content) in inputs {
+        let bs = op.read(&path).await.expect("read must succeed");
+        assert_eq!(bs, content.to_vec(), "read content must match");
+        op.delete(&path).await.expect("delete must succeed");
+    }
+
+    Ok(())
+}
+
 /// Delete via stream.
 pub async fn test_delete_stream(op: Operator) -> Result<()> {
     if !op.info().full_capability().create_dir {
@@ -1379,6 +2728,64 @@ pub async fn test_delete_stream(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Delete entries returned by lister.
+pub async fn test_delete_entries(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list {
+        return Ok(());
+    }
+
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let expected: Vec<_> = (0..10).map(|v| format!("{dir}{v}")).collect();
+    for path in expected.iter() {
+        op.write(path, "delete_entries").await?;
+    }
+
+    let entries: Vec<_> = op.lister(&dir).await?.try_collect().await?;
+    let entries: Vec<_> = entries.into_iter().filter(|v| v.path() != dir).collect();
+    assert_eq!(entries.len(), expected.len());
+
+    op.with_limit(3)
+        .delete_entries(stream::iter(entries))
+        .await?;
+
+    for path in expected.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be removed")
+    }
+
+    Ok(())
+}
+
+/// Batch delete files and check the result of every operation.
+pub async fn test_batch_delete(op: Operator) -> Result<()> {
+    let dir = uuid::Uuid::new_v4().to_string();
+
+    let paths: Vec<_> = (0..10).map(|v| format!("{dir}/{v}")).collect();
+    for path in paths.iter() {
+        op.write(path, "batch_delete").await?;
+    }
+
+    let ops = paths
+        .iter()
+        .map(|path| (path.clone(), BatchOperation::Delete(OpDelete::new())))
+        .collect();
+    let results = op.with_limit(3).batch(ops).await?;
+    assert_eq!(results.len(), paths.len());
+    for (path, result) in results {
+        assert!(
+            result.is_ok(),
+            "{path} should be deleted: {:?}",
+            result.err()
+        );
+    }
+
+    // Stat it again to check.
+    for path in paths.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be removed")
+    }
+
+    Ok(())
+}
+
 /// Append data into writer
 pub async fn test_writer_write(op: Operator) -> Result<()> {
     if !(op.info().full_capability().write_can_multi) {
@@ -1415,6 +2822,214 @@ pub async fn test_writer_write(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Resume a writer with completed parts should upload the remaining data only.
+pub async fn test_writer_resume(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_with_resume) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 5 * 1024 * 1024; // write file with 5 MiB per part
+    let content = gen_fixed_bytes(size * 3);
+
+    let mut w = op.writer(&path).await?;
+    for chunk in content.chunks(size) {
+        w.write(chunk.to_vec()).await?;
+    }
+    let token = w.resume_token().expect("resume token must exist");
+    // Simulate a crash by dropping the writer without closing it.
+    drop(w);
+
+    // The token could be persisted and parsed back.
+    let token: ResumeToken = token.to_string().parse()?;
+    let offset = token.uploaded_bytes() as usize;
+    let mut w = op.writer_with(&path).resume(token).await?;
+    for chunk in content[offset..].chunks(size) {
+        w.write(chunk.to_vec()).await?;
+    }
+    w.close().await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Writer with verify_parts should upload every part along with its md5.
+pub async fn test_writer_with_verify_parts(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_can_multi {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 5 * 1024 * 1024; // write file with 5 MiB per part
+    let content = gen_fixed_bytes(size * 2);
+
+    let res = op.writer_with(&path).verify_parts(true).await;
+    if !op.info().full_capability().write_with_verify_parts {
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::Unsupported));
+        return Ok(());
+    }
+
+    let mut w = res?;
+    for chunk in content.chunks(size) {
+        w.write(chunk.to_vec()).await?;
+    }
+    w.close().await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Writer close should return the metadata of the written file.
+pub async fn test_writer_close_with_metadata(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_can_multi) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 5 * 1024 * 1024; // write file with 5 MiB
+    let content_a = gen_fixed_bytes(size);
+    let content_b = gen_fixed_bytes(size);
+
+    let mut w = op.writer(&path).await?;
+    w.write(content_a).await?;
+    w.write(content_b).await?;
+    let written = w.close().await?;
+    assert!(written.is_file());
+    assert_eq!(written.content_length(), (size * 2) as u64);
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    if written.metakey().contains(Metakey::Etag) {
+        assert_eq!(written.etag(), meta.etag(), "etag must match stat");
+    }
+    if written.metakey().contains(Metakey::Version) {
+        assert_eq!(written.version(), meta.version(), "version must match stat");
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Writer with content length should reject content that doesn't match.
+pub async fn test_writer_with_content_length(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_can_multi) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let mut w = op.writer_with(&path).content_length(size as u64).await?;
+    w.write(content[..size / 2].to_vec()).await?;
+    w.write(content[size / 2..].to_vec()).await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+    op.delete(&path).await.expect("delete must succeed");
+
+    // Close before all content has been written.
+    let mut w = op.writer_with(&path).content_length(size as u64).await?;
+    w.write(content[..size / 2].to_vec()).await?;
+    let err = w.close().await.expect_err("close must fail");
+    assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+    w.abort().await?;
+
+    // Write more content than content length.
+    let mut w = op.writer_with(&path).content_length(size as u64).await?;
+    let mut result = w.write(content.clone()).await;
+    if result.is_ok() {
+        result = w.write(content.clone()).await;
+    }
+    if result.is_ok() {
+        result = w.close().await.map(|_| ());
+    }
+    let err = result.expect_err("write more content than content length must fail");
+    assert_eq!(err.kind(), ErrorKind::ContentTruncated);
+    w.abort().await?;
+
+    Ok(())
+}
+
+/// Write parts concurrently should keep the order of content.
+pub async fn test_writer_write_with_concurrent(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_can_multi) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 5 * 1024 * 1024; // write file with 5 MiB
+    let contents: Vec<_> = (0..4).map(|_| gen_fixed_bytes(size)).collect();
+
+    let mut w = op.writer_with(&path).concurrent(4).await?;
+    for content in contents.iter() {
+        w.write(content.clone()).await?;
+    }
+    w.close().await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), (size * contents.len()) as u64);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), size * contents.len(), "read size");
+    for (idx, content) in contents.iter().enumerate() {
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs[idx * size..(idx + 1) * size])),
+            format!("{:x}", Sha256::digest(content)),
+            "read content {idx}"
+        );
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Write bytes concurrently should keep the order of content.
+pub async fn test_write_with_concurrent(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_can_multi) {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 4 * 5 * 1024 * 1024; // write file with 20 MiB
+    let content = gen_fixed_bytes(size);
+
+    op.write_with(&path, content.clone()).concurrent(4).await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), size, "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Streaming data into writer
 pub async fn test_writer_sink(op: Operator) -> Result<()> {
     let cap = op.info().full_capability();
@@ -1577,3 +3192,38 @@ pub async fn test_invalid_reader_seek(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Reader should be able to read footer first and then seek back to header.
+pub async fn test_reader_seek_footer_and_header(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+    let n = 16.min(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut r = op.reader(&path).await?;
+
+    let pos = r.seek(std::io::SeekFrom::End(-(n as i64))).await?;
+    assert_eq!(pos, (size - n) as u64);
+    let mut footer = vec![0; n];
+    r.read_exact(&mut footer).await?;
+    assert_eq!(footer, content[size - n..], "read footer");
+
+    let pos = r.seek(std::io::SeekFrom::Start(0)).await?;
+    assert_eq!(pos, 0);
+    let mut header = vec![0; n];
+    r.read_exact(&mut header).await?;
+    assert_eq!(header, content[..n], "read header");
+
+    // Seeking past EOF is allowed, and reads will return 0 like files.
+    let pos = r.seek(std::io::SeekFrom::End(1024)).await?;
+    assert_eq!(pos, size as u64 + 1024);
+    let mut buf = vec![0; n];
+    assert_eq!(r.read(&mut buf).await?, 0, "read past EOF");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}